@@ -0,0 +1,88 @@
+//! Benchmarks of the formatting fast paths
+//!
+//! Run them with `cargo bench --bench formatting`.
+
+use std::fmt::Write;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use num_ordinal::{
+    Gender, Locale, NounClass, Ordinal, OrdinalFormatter, SuffixStyle, WordStyle, O32,
+};
+
+/// Formats the same ordinal with formatters that have 0 to 6 options set. The
+/// options don't change the output `1234th`, and they are resolved when the
+/// formatter is built, so the time per call should stay the same as more options
+/// are added.
+fn formatter_options(c: &mut Criterion) {
+    let options: [fn(OrdinalFormatter) -> OrdinalFormatter; 6] = [
+        |f| f.locale(Locale::En),
+        |f| f.suffix_style(SuffixStyle::Standard),
+        |f| f.words_up_to(10),
+        |f| f.word_style(WordStyle::default()),
+        |f| f.gender(Gender::Masculine),
+        |f| f.noun_class(NounClass::MWa),
+    ];
+    let formatters: Vec<_> = (0..=options.len())
+        .map(|n| {
+            options[..n]
+                .iter()
+                .fold(OrdinalFormatter::new(), |f, opt| opt(f))
+        })
+        .collect();
+    let o = O32::from1(1234);
+    assert_eq!(formatters[options.len()].fmt(o).to_string(), "1234th");
+    let mut buf = String::with_capacity(32);
+
+    let mut group = c.benchmark_group("formatter_options");
+    for (n, formatter) in formatters.iter().enumerate() {
+        group.bench_with_input(BenchmarkId::from_parameter(n), formatter, |b, f| {
+            b.iter(|| {
+                buf.clear();
+                f.format_into(black_box(o), &mut buf).unwrap();
+                black_box(buf.len())
+            })
+        });
+    }
+    group.finish();
+
+    // one formatter reused for many values, like in a hot loop
+    c.bench_function("formatter_reused_1000", |b| {
+        let formatter = formatters[options.len()];
+        b.iter(|| {
+            buf.clear();
+            for n in 1..=1000 {
+                formatter.format_into(O32::from1(n), &mut buf).unwrap();
+            }
+            black_box(buf.len())
+        })
+    });
+}
+
+/// Compares the precomputed strings of `as_static_str` with formatting an ordinal
+/// inside and beyond the table with `Display`
+fn static_str(c: &mut Criterion) {
+    let mut buf = String::with_capacity(32);
+    let mut group = c.benchmark_group("static_str");
+    group.bench_function("as_static_str", |b| {
+        b.iter(|| black_box(O32::from1(black_box(42)).as_static_str()))
+    });
+    group.bench_function("display_42nd", |b| {
+        b.iter(|| {
+            buf.clear();
+            write!(buf, "{}", O32::from1(black_box(42))).unwrap();
+            black_box(buf.len())
+        })
+    });
+    group.bench_function("display_1042nd", |b| {
+        b.iter(|| {
+            buf.clear();
+            write!(buf, "{}", O32::from1(black_box(1042))).unwrap();
+            black_box(buf.len())
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, formatter_options, static_str);
+criterion_main!(benches);
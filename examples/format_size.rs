@@ -0,0 +1,20 @@
+//! Formats every ordinal type with `Display` and `Debug`. Used to check that the
+//! formatting impls of the ordinal types are thin shims around the shared
+//! `fmt_ordinal_u128` and `display_ordinal_u128` functions:
+//!
+//! ```text
+//! cargo build --release --example format_size
+//! nm -S --size-sort -C target/release/examples/format_size | grep num_ordinal
+//! ```
+
+use num_ordinal::{Ordinal, Osize, O128, O16, O32, O64, O8};
+
+fn main() {
+    let n = std::env::args().count() as u8;
+    println!("{} {:?}", O8::from0(n), O8::from0(n));
+    println!("{} {:?}", O16::from0(n.into()), O16::from0(n.into()));
+    println!("{} {:?}", O32::from0(n.into()), O32::from0(n.into()));
+    println!("{} {:?}", O64::from0(n.into()), O64::from0(n.into()));
+    println!("{} {:?}", O128::from0(n.into()), O128::from0(n.into()));
+    println!("{} {:?}", Osize::from0(n.into()), Osize::from0(n.into()));
+}
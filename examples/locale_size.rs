@@ -0,0 +1,19 @@
+//! Formats ordinal numbers in a locale chosen at runtime, so all compiled-in
+//! locales end up in the binary. Used to compare the binary size with one locale
+//! and with `locale-all`:
+//!
+//! ```text
+//! cargo build --release --example locale_size --features locale-de
+//! cargo build --release --example locale_size --features locale-all
+//! ```
+
+use num_ordinal::{Locale, Ordinal, O32};
+
+fn main() {
+    let tag = std::env::args().nth(1).unwrap_or_else(|| "en".to_string());
+    let locale = Locale::from_language_tag(&tag);
+    for n in [1, 2, 3, 4, 21, 100, 1234] {
+        let o = O32::from1(n);
+        println!("{} {}", o.display_in(locale), o.display_in(locale).words());
+    }
+}
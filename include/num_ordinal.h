@@ -0,0 +1,98 @@
+#ifndef NUM_ORDINAL_H
+#define NUM_ORDINAL_H
+
+/* This file is generated by cbindgen. Do not edit it manually. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Ordinal number represented by a `uint32_t` containing the 1-based number,
+ * which is never 0
+ */
+typedef uint32_t O32;
+
+/**
+ * Status code returned by fallible functions
+ */
+typedef enum NoStatus {
+  /**
+   * The function succeeded
+   */
+  NO_STATUS_OK = 0,
+  /**
+   * The number is 0, which isn't a valid 1-based ordinal
+   */
+  NO_STATUS_ZERO = 1,
+  /**
+   * The number is too big for the ordinal type
+   */
+  NO_STATUS_OVERFLOW = 2,
+  /**
+   * A pointer argument is null
+   */
+  NO_STATUS_NULL_POINTER = 3,
+} NoStatus;
+
+
+
+
+
+/**
+ * Creates an ordinal number from a 1-based number and writes it to `out`.
+ *
+ * Returns [NoStatus::Zero] if `n` is 0; `out` is only written on success.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writes.
+ */
+enum NoStatus no_ordinal_from1_u32(uint32_t n, O32 *out);
+
+/**
+ * Creates an ordinal number from a 0-based number and writes it to `out`.
+ *
+ * Returns [NoStatus::Overflow] if `n` is `UINT32_MAX`; `out` is only written on success.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writes.
+ */
+enum NoStatus no_ordinal_from0_u32(uint32_t n, O32 *out);
+
+/**
+ * Checks that `o` is a valid ordinal number and writes its 1-based number to `out`.
+ *
+ * Returns [NoStatus::Zero] if `o` is 0; `out` is only written on success.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writes.
+ */
+enum NoStatus no_ordinal_into1_u32(uint32_t o, uint32_t *out);
+
+/**
+ * Returns the English suffix of a 1-based number as a static, nul-terminated
+ * string: `"st"`, `"nd"`, `"rd"` or `"th"`
+ */
+const char *no_ordinal_suffix(uint64_t n);
+
+/**
+ * Writes an ordinal number like its `Display` impl (e.g. `"first"` or `"4th"`)
+ * into `buf`, followed by a nul terminator, and writes the length of the formatted
+ * ordinal without the nul terminator to `out_len`.
+ *
+ * Like `snprintf`, the output is truncated if it doesn't fit into `len` bytes, so
+ * truncation happened if the length is `>= len`. `buf` may be null if `len` is 0,
+ * which can be used to query the required length.
+ *
+ * Returns [NoStatus::Zero] if `o` is 0; nothing is written in that case.
+ *
+ * # Safety
+ *
+ * `buf` must be valid for writes of `len` bytes, or null if `len` is 0. `out_len`
+ * must be null or valid for writes.
+ */
+enum NoStatus no_ordinal_format(uint32_t o, char *buf, size_t len, size_t *out_len);
+
+#endif  /* NUM_ORDINAL_H */
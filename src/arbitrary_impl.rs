@@ -0,0 +1,68 @@
+use arbitrary::unstructured::Int;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{Ordinal, OrdinalInteger, O};
+
+/// Generates any ordinal number from the [first](Ordinal::first) to the
+/// [last](Ordinal::last), i.e. the highest number of the integer type is never
+/// used as the 0-based number
+impl<'a, T: OrdinalInteger + Int + Arbitrary<'a>> Arbitrary<'a> for O<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let range = O::<T>::first().into0()..=O::<T>::last().into0();
+        u.int_in_range(range).map(O::from0)
+    }
+
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        Self::arbitrary(&mut u)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use crate::*;
+
+    fn check<'a, O: Ordinal + Arbitrary<'a>>(u: &mut Unstructured<'a>) {
+        let o = O::arbitrary(u).unwrap();
+        assert!(O::try_from0(o.into0()).is_some());
+        assert_eq!(O::from1(o.into1()), o);
+    }
+
+    #[test]
+    fn fuzz() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut bytes = [0; 256];
+        for _ in 0..1000 {
+            rng.fill_bytes(&mut bytes);
+            let mut u = Unstructured::new(&bytes);
+            check::<O8>(&mut u);
+            check::<O16>(&mut u);
+            check::<O32>(&mut u);
+            check::<O64>(&mut u);
+            check::<O128>(&mut u);
+            check::<Osize>(&mut u);
+        }
+    }
+
+    #[test]
+    fn never_max() {
+        for b in 0..=255 {
+            let o = O8::arbitrary_take_rest(Unstructured::new(&[b])).unwrap();
+            assert!(O8::try_from0(o.into0()).is_some());
+        }
+        let o = O128::arbitrary_take_rest(Unstructured::new(&[0xff; 16])).unwrap();
+        assert!(O128::try_from0(o.into0()).is_some());
+        assert_eq!(
+            O32::arbitrary(&mut Unstructured::new(&[])),
+            Ok(O32::first())
+        );
+        assert_eq!(O32::size_hint(0), u32::size_hint(0));
+    }
+}
@@ -0,0 +1,237 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Add, Sub};
+use core::str::FromStr;
+
+use num_bigint::BigUint;
+
+use crate::parse::strip_suffix;
+use crate::*;
+
+/// Arbitrary-precision ordinal number represented by a [BigUint]
+///
+/// Since `BigUint` isn't `Copy`, this type can't implement the [Ordinal] trait,
+/// but it provides the same constructors and conversions as inherent methods:
+///
+/// ```
+/// use num_bigint::BigUint;
+/// use num_ordinal::OBig;
+///
+/// let o = OBig::from1(BigUint::from(u128::MAX) * 10u32 + 2u32);
+/// assert_eq!(o.to_string(), "3402823669209384634633746074317682114552nd");
+/// assert_eq!("3402823669209384634633746074317682114552nd".parse(), Ok(o));
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Default)]
+pub struct OBig(BigUint);
+
+impl OBig {
+    /// The first ordinal number
+    pub fn first() -> Self {
+        OBig(BigUint::default())
+    }
+
+    /// Computes the ordinal number that comes after this one
+    pub fn next(&self) -> Self {
+        OBig(&self.0 + 1u32)
+    }
+
+    /// Returns the equivalent integer assuming the ordinal number is 0-based
+    pub fn into0(self) -> BigUint {
+        self.0
+    }
+
+    /// Returns the equivalent integer assuming the ordinal number is 1-based
+    pub fn into1(self) -> BigUint {
+        self.0 + 1u32
+    }
+
+    /// Converts an integer to a 0-based ordinal number
+    pub fn from0(n: BigUint) -> Self {
+        OBig(n)
+    }
+
+    /// Tries to convert an integer to a 1-based ordinal number.
+    ///
+    /// It returns [None] if the provided number is 0.
+    pub fn try_from1(n: BigUint) -> Option<Self> {
+        if n == BigUint::default() {
+            None
+        } else {
+            Some(OBig(n - 1u32))
+        }
+    }
+
+    /// Converts an integer to a 1-based ordinal number.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the provided number is 0.
+    pub fn from1(n: BigUint) -> Self {
+        Self::try_from1(n).expect("0 is not a valid 1-based ordinal.")
+    }
+}
+
+impl fmt::Debug for OBig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = &self.0 + 1u32;
+        let last_two = u128::try_from(&n % 100u32).unwrap();
+        match u8::try_from(&n) {
+            Ok(1) => write!(f, "first"),
+            Ok(2) => write!(f, "second"),
+            Ok(3) => write!(f, "third"),
+            _ => write!(f, "{}{}", n, suffix(last_two)),
+        }
+    }
+}
+
+impl fmt::Display for OBig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for OBig {
+    type Err = OrdinalError;
+
+    /// Parses a 1-based ordinal number such as `4`, `4th` or `first`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let n = match s {
+            "first" => BigUint::from(1u32),
+            "second" => BigUint::from(2u32),
+            "third" => BigUint::from(3u32),
+            _ => BigUint::parse_bytes(strip_suffix(s)?.as_bytes(), 10)
+                .ok_or(OrdinalError::Invalid)?,
+        };
+        OBig::try_from1(n).ok_or(OrdinalError::Zero)
+    }
+}
+
+impl Add<BigUint> for OBig {
+    type Output = OBig;
+
+    fn add(self, rhs: BigUint) -> Self::Output {
+        OBig(self.0 + rhs)
+    }
+}
+
+impl Add<u64> for OBig {
+    type Output = OBig;
+
+    fn add(self, rhs: u64) -> Self::Output {
+        OBig(self.0 + rhs)
+    }
+}
+
+impl Sub<BigUint> for OBig {
+    type Output = OBig;
+
+    fn sub(self, rhs: BigUint) -> Self::Output {
+        OBig(self.0 - rhs)
+    }
+}
+
+impl Sub<u64> for OBig {
+    type Output = OBig;
+
+    fn sub(self, rhs: u64) -> Self::Output {
+        OBig(self.0 - rhs)
+    }
+}
+
+impl Sub<OBig> for OBig {
+    type Output = BigUint;
+
+    fn sub(self, rhs: OBig) -> Self::Output {
+        self.0 - rhs.0
+    }
+}
+
+impl<T: OrdinalInteger> From<O<T>> for OBig {
+    fn from(o: O<T>) -> Self {
+        OBig(BigUint::from(o.into0().to_u128()))
+    }
+}
+
+impl<T: OrdinalInteger> TryFrom<&OBig> for O<T> {
+    type Error = OrdinalError;
+
+    fn try_from(o: &OBig) -> Result<Self, Self::Error> {
+        u128::try_from(&o.0)
+            .ok()
+            .and_then(T::from_u128)
+            .and_then(O::try_from0)
+            .ok_or(OrdinalError::Overflow)
+    }
+}
+
+impl<T: OrdinalInteger> TryFrom<OBig> for O<T> {
+    type Error = OrdinalError;
+
+    fn try_from(o: OBig) -> Result<Self, Self::Error> {
+        O::try_from(&o)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn big(n: u128) -> BigUint {
+        BigUint::from(n)
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(OBig::first().to_string(), "first");
+        assert_eq!(OBig::from1(big(3)).to_string(), "third");
+        assert_eq!(OBig::from1(big(4)).to_string(), "4th");
+        assert_eq!(OBig::from1(big(111)).to_string(), "111th");
+        assert_eq!(OBig::from1(big(1001)).to_string(), "1001st");
+
+        let max = OBig::from1(big(u128::MAX)).next();
+        assert_eq!(max.to_string(), "340282366920938463463374607431768211456th");
+        assert_eq!(
+            max.next().to_string(),
+            "340282366920938463463374607431768211457th"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("first".parse(), Ok(OBig::first()));
+        assert_eq!("4".parse(), Ok(OBig::from1(big(4))));
+        assert_eq!("112th".parse(), Ok(OBig::from1(big(112))));
+        assert_eq!("0th".parse::<OBig>(), Err(OrdinalError::Zero));
+        assert_eq!("4st".parse::<OBig>(), Err(OrdinalError::Invalid));
+        assert_eq!("".parse::<OBig>(), Err(OrdinalError::Invalid));
+
+        let o = OBig::from1(big(u128::MAX) * 1000u32);
+        assert_eq!(o.to_string().parse(), Ok(o));
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(OBig::from(O8::from1(255)), OBig::from1(big(255)));
+        assert_eq!(O8::try_from(OBig::from1(big(255))), Ok(O8::from1(255)));
+        assert_eq!(
+            O8::try_from(OBig::from1(big(256))),
+            Err(OrdinalError::Overflow)
+        );
+
+        let max = O128::from0(u128::MAX - 1);
+        assert_eq!(O128::try_from(OBig::from(max)), Ok(max));
+        assert_eq!(
+            O128::try_from(OBig::from(max).next()),
+            Err(OrdinalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let o = OBig::from1(big(5));
+        assert_eq!(o.clone() + 3, OBig::from1(big(8)));
+        assert_eq!(o.clone() - big(4), OBig::first());
+        assert_eq!(o.clone() - OBig::first(), big(4));
+        assert_eq!(o.into1(), big(5));
+    }
+}
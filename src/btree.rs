@@ -0,0 +1,164 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::borrow::Borrow;
+use core::ops::Bound::{Excluded, Unbounded};
+
+use crate::{Ordinal, Osize};
+
+/// Extension trait for [BTreeMap], to access entries by their 1-based position
+/// in ascending key order.
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use num_ordinal::{ordinal, BTreeMapExt};
+///
+/// let map: BTreeMap<_, _> = vec![(30, 'c'), (10, 'a'), (20, 'b')].into_iter().collect();
+/// assert_eq!(map.nth_entry_ordinal(ordinal!(first)), Some((&10, &'a')));
+/// assert_eq!(map.nth_back_entry_ordinal(ordinal!(first)), Some((&30, &'c')));
+/// assert_eq!(map.ordinal_position_of(&20), Some(ordinal!(second)));
+/// ```
+pub trait BTreeMapExt<K, V> {
+    /// Returns the entry with the `o`-th smallest key
+    fn nth_entry_ordinal(&self, o: Osize) -> Option<(&K, &V)>;
+
+    /// Returns the `o`-th smallest key
+    fn nth_key_ordinal(&self, o: Osize) -> Option<&K>;
+
+    /// Returns the entry with the `o`-th largest key
+    fn nth_back_entry_ordinal(&self, o: Osize) -> Option<(&K, &V)>;
+
+    /// Returns the position of the given key in ascending order, or [None] if
+    /// the map doesn't contain the key.
+    ///
+    /// This takes O(n) time, since the entries before the key are counted.
+    fn ordinal_position_of<Q>(&self, key: &Q) -> Option<Osize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized;
+}
+
+impl<K: Ord, V> BTreeMapExt<K, V> for BTreeMap<K, V> {
+    fn nth_entry_ordinal(&self, o: Osize) -> Option<(&K, &V)> {
+        self.iter().nth(o.into0())
+    }
+
+    fn nth_key_ordinal(&self, o: Osize) -> Option<&K> {
+        self.keys().nth(o.into0())
+    }
+
+    fn nth_back_entry_ordinal(&self, o: Osize) -> Option<(&K, &V)> {
+        self.iter().nth_back(o.into0())
+    }
+
+    fn ordinal_position_of<Q>(&self, key: &Q) -> Option<Osize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        if self.contains_key(key) {
+            Some(Osize::from0(
+                self.range::<Q, _>((Unbounded, Excluded(key))).count(),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// Extension trait for [BTreeSet], to access values by their 1-based position
+/// in ascending order.
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use num_ordinal::{ordinal, BTreeSetExt};
+///
+/// let set: BTreeSet<_> = "dcab".chars().collect();
+/// assert_eq!(set.nth_ordinal(ordinal!(third)), Some(&'c'));
+/// assert_eq!(set.nth_back_ordinal(ordinal!(third)), Some(&'b'));
+/// assert_eq!(set.ordinal_position_of(&'d'), Some(ordinal!(4-th)));
+/// ```
+pub trait BTreeSetExt<T> {
+    /// Returns the `o`-th smallest value
+    fn nth_ordinal(&self, o: Osize) -> Option<&T>;
+
+    /// Returns the `o`-th largest value
+    fn nth_back_ordinal(&self, o: Osize) -> Option<&T>;
+
+    /// Returns the position of the given value in ascending order, or [None]
+    /// if the set doesn't contain the value.
+    ///
+    /// This takes O(n) time, since the values before it are counted.
+    fn ordinal_position_of<Q>(&self, value: &Q) -> Option<Osize>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized;
+}
+
+impl<T: Ord> BTreeSetExt<T> for BTreeSet<T> {
+    fn nth_ordinal(&self, o: Osize) -> Option<&T> {
+        self.iter().nth(o.into0())
+    }
+
+    fn nth_back_ordinal(&self, o: Osize) -> Option<&T> {
+        self.iter().nth_back(o.into0())
+    }
+
+    fn ordinal_position_of<Q>(&self, value: &Q) -> Option<Osize>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        if self.contains(value) {
+            Some(Osize::from0(
+                self.range::<Q, _>((Unbounded, Excluded(value))).count(),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn o(n: usize) -> Osize {
+        Osize::from1(n)
+    }
+
+    #[test]
+    fn map() {
+        let map: BTreeMap<String, usize> = ["b", "d", "a", "c"]
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.to_string(), i))
+            .collect();
+
+        assert_eq!(map.nth_key_ordinal(o(1)).map(String::as_str), Some("a"));
+        assert_eq!(map.nth_key_ordinal(o(4)).map(String::as_str), Some("d"));
+        assert_eq!(map.nth_key_ordinal(o(5)), None);
+        assert_eq!(map.nth_entry_ordinal(o(2)).map(|(_, &v)| v), Some(0));
+        assert_eq!(map.nth_back_entry_ordinal(o(1)).map(|(_, &v)| v), Some(1));
+        assert_eq!(map.nth_back_entry_ordinal(o(4)).map(|(_, &v)| v), Some(2));
+        assert_eq!(map.nth_back_entry_ordinal(o(5)), None);
+
+        for (i, key) in map.keys().enumerate() {
+            assert_eq!(map.ordinal_position_of(key.as_str()), Some(o(i + 1)));
+        }
+        assert_eq!(map.ordinal_position_of("bb"), None);
+    }
+
+    #[test]
+    fn set() {
+        let set: BTreeSet<i32> = vec![5, -3, 8].into_iter().collect();
+        assert_eq!(set.nth_ordinal(o(1)), Some(&-3));
+        assert_eq!(set.nth_back_ordinal(o(1)), Some(&8));
+        assert_eq!(set.nth_back_ordinal(o(3)), Some(&-3));
+        assert_eq!(set.nth_back_ordinal(o(4)), None);
+        assert_eq!(set.ordinal_position_of(&5), Some(o(2)));
+        assert_eq!(set.ordinal_position_of(&6), None);
+
+        let empty = BTreeSet::<i32>::new();
+        assert_eq!(empty.nth_ordinal(o(1)), None);
+        assert_eq!(empty.ordinal_position_of(&1), None);
+    }
+}
@@ -0,0 +1,163 @@
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use crate::{Ordinal, OrdinalError};
+
+/// Error returned when converting many integers to ordinal numbers at once,
+/// e.g. by [try_from1_slice](Ordinal::try_from1_slice). It contains the
+/// position of the offending element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BulkConvertError<T> {
+    /// The 0-based index of the element
+    pub index: usize,
+    /// The value of the element
+    pub value: T,
+    /// Why the element couldn't be converted
+    pub error: OrdinalError,
+}
+
+impl<T: fmt::Display> fmt::Display for BulkConvertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "element at index {} ({}) is invalid: {}",
+            self.index, self.value, self.error
+        )
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> Error for BulkConvertError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Converts all values, stopping at the first error
+pub(crate) fn convert<T, O, I, F>(values: I, f: F) -> Result<Vec<O>, BulkConvertError<T>>
+where
+    T: Copy,
+    I: IntoIterator<Item = T>,
+    F: Fn(T) -> Result<O, OrdinalError>,
+{
+    let values = values.into_iter();
+    let mut result = Vec::with_capacity(values.size_hint().0);
+    for (index, value) in values.enumerate() {
+        match f(value) {
+            Ok(o) => result.push(o),
+            Err(error) => {
+                return Err(BulkConvertError {
+                    index,
+                    value,
+                    error,
+                })
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Converts all values, and returns all errors if any value is invalid
+pub(crate) fn convert_all<T, O, I, F>(values: I, f: F) -> Result<Vec<O>, Vec<BulkConvertError<T>>>
+where
+    T: Copy,
+    I: IntoIterator<Item = T>,
+    F: Fn(T) -> Result<O, OrdinalError>,
+{
+    let values = values.into_iter();
+    let mut result = Vec::with_capacity(values.size_hint().0);
+    let mut errors = Vec::new();
+    for (index, value) in values.enumerate() {
+        match f(value) {
+            Ok(o) if errors.is_empty() => result.push(o),
+            Ok(_) => {}
+            Err(error) => errors.push(BulkConvertError {
+                index,
+                value,
+                error,
+            }),
+        }
+    }
+    if errors.is_empty() {
+        Ok(result)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Converts a 1-based integer, with the error of [from1_checked](Ordinal::from1_checked)
+pub(crate) fn from1<O: Ordinal>(n: O::IntegerType) -> Result<O, OrdinalError> {
+    O::from1_checked(n)
+}
+
+/// Converts a 0-based integer, with the error of [from0_checked](Ordinal::from0_checked)
+pub(crate) fn from0<O: Ordinal>(n: O::IntegerType) -> Result<O, OrdinalError> {
+    O::from0_checked(n)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn first_error() {
+        assert_eq!(
+            O32::try_from1_slice(&[1, 2, 3]),
+            Ok(vec![O32::from1(1), O32::from1(2), O32::from1(3)])
+        );
+        assert_eq!(O32::try_from1_slice(&[]), Ok(vec![]));
+
+        let err = O32::try_from1_slice(&[4, 0, 5, 0]).unwrap_err();
+        assert_eq!(
+            err,
+            BulkConvertError {
+                index: 1,
+                value: 0,
+                error: OrdinalError::Zero
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "element at index 1 (0) is invalid: 0 is not a valid 1-based ordinal"
+        );
+
+        assert_eq!(
+            O8::try_from0_slice(&[0, 254, 255]),
+            Err(BulkConvertError {
+                index: 2,
+                value: 255,
+                error: OrdinalError::Overflow
+            })
+        );
+        assert_eq!(O8::try_from1_slice(&[255]), Ok(vec![O8::from0(254)]));
+    }
+
+    #[test]
+    fn all_errors() {
+        let errors = O16::try_from1_slice_all_errors(&[0, 7, 0, 9]).unwrap_err();
+        let indices: Vec<_> = errors.iter().map(|e| e.index).collect();
+        assert_eq!(indices, [0, 2]);
+        assert_eq!(
+            O16::try_from0_slice_all_errors(&[1, 2]),
+            Ok(vec![O16::from0(1), O16::from0(2)])
+        );
+        assert_eq!(
+            O16::try_from0_slice_all_errors(&[u16::MAX]).unwrap_err()[0].error,
+            OrdinalError::Overflow
+        );
+    }
+
+    #[test]
+    fn iterators() {
+        let column = "3\n1\n4".lines().map(|l| l.parse::<u64>().unwrap());
+        let ordinals: Result<Vec<O64>, _> = column.try_collect_ordinals();
+        assert_eq!(ordinals.unwrap(), [3, 1, 4].map(O64::from1));
+
+        let ordinals: Result<Vec<Osize>, _> = (0..5).rev().try_collect_ordinals();
+        assert_eq!(ordinals.unwrap_err().index, 4);
+
+        let large = vec![1u32; 10_000];
+        let ordinals = O32::try_from1_slice(&large).unwrap();
+        assert!(ordinals.iter().all(|&o| o == O32::first()));
+    }
+}
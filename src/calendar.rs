@@ -0,0 +1,174 @@
+//! Centuries, millennia and decades of calendar years
+//!
+//! Years use the historical numbering of the (proleptic) Gregorian calendar:
+//! There is no year zero, positive years are CE (AD), and negative years are BCE (BC),
+//! so `-1` is the year 1 BCE, which directly precedes the year 1 CE. This is _not_ the
+//! astronomical year numbering, where the year 1 BCE is written as `0`. Since the two
+//! conventions can't be told apart, the year `0` is rejected with an error.
+//!
+//! Because there is no year zero, the 1st century spans the years 1 to 100, so the
+//! 20th century ends with the year 2000 and the 21st century starts with 2001.
+//! BCE years are counted backwards in the same way: The 1st century BCE spans the
+//! years 100 BCE to 1 BCE.
+//!
+//! ```
+//! use num_ordinal::{ordinal, calendar};
+//!
+//! assert_eq!(calendar::century_of_year(2000), Ok(ordinal!(20-th O8)));
+//! assert_eq!(calendar::century_of_year(2001), Ok(ordinal!(21-st O8)));
+//! assert_eq!(calendar::format_century(-150).unwrap(), "second century BCE");
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::{Ordinal, O8};
+
+/// Error returned by the functions in the [calendar](self) module
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalendarError {
+    /// The year `0` doesn't exist in the historical year numbering
+    YearZero,
+    /// The result doesn't fit into the returned ordinal type
+    OutOfRange,
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarError::YearZero => write!(f, "there is no year 0; use -1 for the year 1 BCE"),
+            CalendarError::OutOfRange => write!(f, "year is out of range"),
+        }
+    }
+}
+
+impl core::error::Error for CalendarError {}
+
+/// Returns the number of years since the start of the era, starting with 0.
+fn elapsed_years(year: i32) -> Result<u32, CalendarError> {
+    match year {
+        0 => Err(CalendarError::YearZero),
+        _ => Ok(year.unsigned_abs() - 1),
+    }
+}
+
+fn to_o8(n0: u32) -> Result<O8, CalendarError> {
+    u8::try_from(n0)
+        .ok()
+        .and_then(O8::try_from0)
+        .ok_or(CalendarError::OutOfRange)
+}
+
+/// Returns the century of a year. For BCE years (negative numbers), the
+/// century BCE is returned, e.g. `-1` is in the 1st century BCE.
+///
+/// See the [module-level documentation](self) for the year numbering convention.
+pub fn century_of_year(year: i32) -> Result<O8, CalendarError> {
+    to_o8(elapsed_years(year)? / 100)
+}
+
+/// Returns the millennium of a year. For BCE years (negative numbers), the
+/// millennium BCE is returned, e.g. `-1` is in the 1st millennium BCE.
+///
+/// See the [module-level documentation](self) for the year numbering convention.
+pub fn millennium_of_year(year: i32) -> Result<O8, CalendarError> {
+    to_o8(elapsed_years(year)? / 1000)
+}
+
+/// Returns the decade of a year within its century, from 1st to 10th.
+///
+/// Decades are counted like centuries, so the 1st decade of the 21st century
+/// spans the years 2001 to 2010. For BCE years, decades are counted backwards
+/// from the start of the era, e.g. the years 10 BCE to 1 BCE are the 1st decade
+/// of the 1st century BCE.
+pub fn decade_of_century(year: i32) -> Result<O8, CalendarError> {
+    to_o8(elapsed_years(year)? % 100 / 10)
+}
+
+#[cfg(feature = "alloc")]
+fn format_period(year: i32, ordinal: O8, unit: &str) -> String {
+    if year < 0 {
+        format!("{} {} BCE", ordinal, unit)
+    } else {
+        format!("{} {}", ordinal, unit)
+    }
+}
+
+/// Formats the century of a year, e.g. `"21st century"` or `"third century BCE"`.
+#[cfg(feature = "alloc")]
+pub fn format_century(year: i32) -> Result<String, CalendarError> {
+    Ok(format_period(year, century_of_year(year)?, "century"))
+}
+
+/// Formats the millennium of a year, e.g. `"third millennium"` or `"first millennium BCE"`.
+#[cfg(feature = "alloc")]
+pub fn format_millennium(year: i32) -> Result<String, CalendarError> {
+    Ok(format_period(year, millennium_of_year(year)?, "millennium"))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn century(year: i32) -> u8 {
+        century_of_year(year).unwrap().into1()
+    }
+
+    #[test]
+    fn century_boundaries() {
+        assert_eq!(century(1), 1);
+        assert_eq!(century(100), 1);
+        assert_eq!(century(101), 2);
+        assert_eq!(century(2000), 20);
+        assert_eq!(century(2001), 21);
+        assert_eq!(century(-1), 1);
+        assert_eq!(century(-100), 1);
+        assert_eq!(century(-101), 2);
+    }
+
+    #[test]
+    fn millennium_boundaries() {
+        let millennium = |year| millennium_of_year(year).unwrap().into1();
+        assert_eq!(millennium(1), 1);
+        assert_eq!(millennium(100), 1);
+        assert_eq!(millennium(101), 1);
+        assert_eq!(millennium(1000), 1);
+        assert_eq!(millennium(2000), 2);
+        assert_eq!(millennium(2001), 3);
+        assert_eq!(millennium(-1), 1);
+    }
+
+    #[test]
+    fn decade_boundaries() {
+        let decade = |year| decade_of_century(year).unwrap().into1();
+        assert_eq!(decade(1), 1);
+        assert_eq!(decade(100), 10);
+        assert_eq!(decade(101), 1);
+        assert_eq!(decade(2000), 10);
+        assert_eq!(decade(2001), 1);
+        assert_eq!(decade(2011), 2);
+        assert_eq!(decade(-1), 1);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(century_of_year(0), Err(CalendarError::YearZero));
+        assert_eq!(millennium_of_year(0), Err(CalendarError::YearZero));
+        assert_eq!(decade_of_century(0), Err(CalendarError::YearZero));
+        assert_eq!(century(25500), 255);
+        assert_eq!(century_of_year(25501), Err(CalendarError::OutOfRange));
+        assert_eq!(century_of_year(i32::MIN), Err(CalendarError::OutOfRange));
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(format_century(2001).unwrap(), "21st century");
+        assert_eq!(format_century(2000).unwrap(), "20th century");
+        assert_eq!(format_century(-2001).unwrap(), "21st century BCE");
+        assert_eq!(format_century(-1).unwrap(), "first century BCE");
+        assert_eq!(format_millennium(2001).unwrap(), "third millennium");
+        assert_eq!(format_century(0), Err(CalendarError::YearZero));
+    }
+}
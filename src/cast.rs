@@ -0,0 +1,149 @@
+//! Zero-copy casts between slices of ordinals and slices of their 1-based integers
+//!
+//! All ordinal types are `#[repr(transparent)]` wrappers around a `NonZero*` integer
+//! containing the 1-based value. Therefore they implement `bytemuck::NoUninit` and
+//! `bytemuck::CheckedBitPattern`, but not `Pod` or `Zeroable`: a zeroed ordinal
+//! would be invalid. Casting integers to ordinals checks that no integer is 0; the
+//! other direction always succeeds.
+//!
+//! There is no 0-based view of an integer slice, because the stored value is
+//! 1-based. Use [Ordinal::from0](crate::Ordinal::from0) to convert 0-based integers, which copies them.
+//!
+//! ```
+//! use num_ordinal::{cast, Ordinal, O32};
+//!
+//! let ints = [1u32, 4, 2];
+//! let ordinals: &[O32] = cast::cast_slice1(&ints).unwrap();
+//! assert_eq!(ordinals[1], O32::from1(4));
+//! assert_eq!(cast::as_int_slice1(ordinals), &ints);
+//!
+//! let err = cast::cast_slice1::<u32>(&[3, 0, 5]).unwrap_err();
+//! assert_eq!(err.index(), 1);
+//! ```
+
+use core::fmt;
+
+use bytemuck::{AnyBitPattern, CheckedBitPattern, NoUninit, Pod};
+
+use crate::{OrdinalInteger, O};
+
+// SAFETY: `O<T>` is a `repr(transparent)` wrapper around `T::NonZero`, which has the
+// same layout as `T` and has no padding
+unsafe impl<T: OrdinalInteger + NoUninit> NoUninit for O<T> {}
+
+// SAFETY: `O<T>` has the same layout as `T`, and every non-zero `T` is a valid
+// `T::NonZero`
+unsafe impl<T: OrdinalInteger + AnyBitPattern> CheckedBitPattern for O<T> {
+    type Bits = T;
+
+    fn is_valid_bit_pattern(bits: &T) -> bool {
+        bits.to_nonzero().is_some()
+    }
+}
+
+/// Error returned by [cast_slice1] and [cast_slice1_mut] when an integer is 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CastError {
+    index: usize,
+}
+
+impl CastError {
+    /// Returns the index of the first integer that is 0
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the integer at index {} is 0, which isn't a valid 1-based ordinal",
+            self.index
+        )
+    }
+}
+
+impl core::error::Error for CastError {}
+
+fn first_zero<T: OrdinalInteger>(ints: &[T]) -> CastError {
+    let index = ints
+        .iter()
+        .position(|n| n.to_nonzero().is_none())
+        .expect("a cast only fails if an integer is 0");
+    CastError { index }
+}
+
+/// Views a slice of 1-based integers as ordinals without copying. Fails if any
+/// integer is 0.
+pub fn cast_slice1<T: OrdinalInteger + Pod>(ints: &[T]) -> Result<&[O<T>], CastError> {
+    bytemuck::checked::try_cast_slice(ints).map_err(|_| first_zero(ints))
+}
+
+/// Views a mutable slice of 1-based integers as ordinals without copying. Fails if
+/// any integer is 0.
+pub fn cast_slice1_mut<T: OrdinalInteger + Pod>(ints: &mut [T]) -> Result<&mut [O<T>], CastError> {
+    if let Some(index) = ints.iter().position(|n| n.to_nonzero().is_none()) {
+        return Err(CastError { index });
+    }
+    Ok(bytemuck::checked::cast_slice_mut(ints))
+}
+
+/// Views a slice of ordinals as their 1-based integers without copying
+///
+/// There is no mutable version of this function, since it would allow writing 0.
+pub fn as_int_slice1<T: OrdinalInteger + Pod>(ordinals: &[O<T>]) -> &[T] {
+    bytemuck::cast_slice(ordinals)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn zero_is_rejected() {
+        let ints = [1u32, 2, 0, 0];
+        assert_eq!(cast_slice1(&ints), Err(CastError { index: 2 }));
+        assert_eq!(
+            CastError { index: 2 }.to_string(),
+            "the integer at index 2 is 0, which isn't a valid 1-based ordinal"
+        );
+
+        let mut ints = [0u8];
+        assert_eq!(cast_slice1_mut(&mut ints), Err(CastError { index: 0 }));
+
+        let empty: &[u64] = &[];
+        assert!(cast_slice1(empty).unwrap().is_empty());
+    }
+
+    #[test]
+    fn max_is_valid() {
+        let ints = [u32::MAX, 1];
+        assert_eq!(cast_slice1(&ints).unwrap(), [O32::last(), O32::first()]);
+        assert_eq!(cast_slice1(&[u8::MAX]).unwrap(), [O8::last()]);
+    }
+
+    #[test]
+    fn zero_copy_round_trip() {
+        let ints: Vec<u32> = (1..=4_000_000).collect();
+        let ordinals = cast_slice1(&ints).unwrap();
+        assert_eq!(ordinals.as_ptr() as *const u32, ints.as_ptr());
+        assert_eq!(ordinals.len(), ints.len());
+        assert_eq!(ordinals[0], O32::first());
+        assert_eq!(ordinals[3_999_999], O32::from1(4_000_000));
+
+        let back = as_int_slice1(ordinals);
+        assert_eq!(back.as_ptr(), ints.as_ptr());
+        assert_eq!(back, &ints[..]);
+    }
+
+    #[test]
+    fn mutable() {
+        let mut ints = [3u16, 1, 2];
+        let ordinals = cast_slice1_mut(&mut ints).unwrap();
+        ordinals.sort();
+        ordinals[2] += 7;
+        assert_eq!(ints, [1, 2, 10]);
+    }
+}
@@ -0,0 +1,357 @@
+//! A parser for ordinal strings that runs at compile time, used by [ordinal_str!]
+
+use core::marker::PhantomData;
+
+use crate::words::{ONES, ONES_ORDINAL, SCALES, TENS, TENS_ORDINAL};
+use crate::{suffix, OrdinalError, SuffixStyle};
+
+/// Parses a 1-based ordinal number, panicking with the error message if the
+/// string is invalid. This is called by [ordinal_str!] in a const context, so
+/// the panic becomes a compile error.
+#[doc(hidden)]
+pub const fn __parse_ordinal_str(s: &str) -> u128 {
+    match parse(s) {
+        Ok(n) => n,
+        Err(e) => panic!("{}", e.message()),
+    }
+}
+
+/// Checks a number literal and its suffix in [ordinal!], panicking with the error
+/// message if they aren't a valid ordinal number. This is called in a const
+/// context, so the panic becomes a compile error.
+#[doc(hidden)]
+pub const fn __ordinal_literal(n: u128, suffix: &str) -> u128 {
+    let word = Word {
+        s: suffix.as_bytes(),
+        start: 0,
+        end: suffix.len(),
+        ignore_case: false,
+    };
+    if n == 0 {
+        panic!("{}", OrdinalError::Zero.message())
+    } else if !word.eq(crate::suffix(n), "") && !word.eq(".", "") {
+        panic!("the suffix doesn't match the number")
+    }
+    n
+}
+
+/// Converts a parsed 1-based number to an ordinal type at compile time. This is
+/// implemented for the built-in ordinal types.
+#[doc(hidden)]
+pub trait __ConstFrom1<const N: u128>: Sized {
+    const VALUE: Self;
+}
+
+/// Expanded by [ordinal_str!], so the ordinal type can be inferred in a const
+/// context
+#[doc(hidden)]
+pub struct __OrdinalStr<O, const N: u128>(PhantomData<O>);
+
+impl<O: __ConstFrom1<N>, const N: u128> __OrdinalStr<O, N> {
+    pub const VALUE: O = O::VALUE;
+}
+
+/// Parses a 1-based ordinal number, written either with digits and an optional
+/// English suffix (e.g. `23rd` or `23`) or with English words (e.g.
+/// `twenty-third`)
+pub(crate) const fn parse(s: &str) -> Result<u128, OrdinalError> {
+    parse_with(s, SuffixStyle::Standard)
+}
+
+/// Like [parse], but ignores the case of ASCII letters, e.g. `Twenty-Third`
+pub(crate) const fn parse_ignore_case(s: &str) -> Result<u128, OrdinalError> {
+    parse_impl(s.as_bytes(), SuffixStyle::Standard, true)
+}
+
+/// Like [parse], but also accepts the suffixes of the given style
+pub(crate) const fn parse_with(s: &str, style: SuffixStyle) -> Result<u128, OrdinalError> {
+    parse_impl(s.as_bytes(), style, false)
+}
+
+const fn parse_impl(s: &[u8], style: SuffixStyle, ignore_case: bool) -> Result<u128, OrdinalError> {
+    if s.is_empty() {
+        Err(OrdinalError::Invalid)
+    } else if s[0].is_ascii_digit() {
+        parse_numeric(s, style, ignore_case)
+    } else {
+        parse_words(s, ignore_case)
+    }
+}
+
+const fn parse_numeric(
+    s: &[u8],
+    style: SuffixStyle,
+    ignore_case: bool,
+) -> Result<u128, OrdinalError> {
+    let mut n = 0u128;
+    let mut i = 0;
+    while i < s.len() && s[i].is_ascii_digit() {
+        n = match n.checked_mul(10) {
+            Some(n) => match n.checked_add((s[i] - b'0') as u128) {
+                Some(n) => n,
+                None => return Err(OrdinalError::Overflow),
+            },
+            None => return Err(OrdinalError::Overflow),
+        };
+        i += 1;
+    }
+    if n == 0 {
+        return Err(OrdinalError::Zero);
+    }
+    let rest = Word {
+        s,
+        start: i,
+        end: s.len(),
+        ignore_case,
+    };
+    if i < s.len() && !rest.eq(suffix(n), "") && !rest.eq(style.suffix(n), "") {
+        return Err(OrdinalError::Invalid);
+    }
+    Ok(n)
+}
+
+/// Parses English ordinal words, e.g. `one hundred twenty-third`. Words can be
+/// separated by spaces or hyphens, and `and` may appear after `hundred` or a
+/// scale word. Only the last word is an ordinal.
+const fn parse_words(s: &[u8], ignore_case: bool) -> Result<u128, OrdinalError> {
+    let mut total = 0u128;
+    let mut group = 0u128;
+    let mut last_scale = SCALES.len();
+    let mut start = 0;
+    let mut after_and = false;
+
+    while start < s.len() {
+        let mut end = start;
+        while end < s.len() && s[end] != b' ' && s[end] != b'-' {
+            end += 1;
+        }
+        if end == start || end + 1 == s.len() {
+            // empty word
+            return Err(OrdinalError::Invalid);
+        }
+        let last = end == s.len();
+        let word = Word {
+            s,
+            start,
+            end,
+            ignore_case,
+        };
+
+        if !last && word.eq("and", "") {
+            let after_hundred = group != 0 && group % 100 == 0;
+            let after_scale = group == 0 && total != 0;
+            if after_and || !(after_hundred || after_scale) {
+                return Err(OrdinalError::Invalid);
+            }
+            after_and = true;
+            start = end + 1;
+            continue;
+        }
+
+        if let Some(v) = word.find(&ONES, &ONES_ORDINAL, "", last) {
+            if v == 0 && last && start == 0 {
+                return Err(OrdinalError::Zero);
+            }
+            // a digit can only follow hundreds or tens, a teen only hundreds
+            let rest = group % 100;
+            let tens = rest >= 20 && rest % 10 == 0;
+            if v == 0 || rest != 0 && (v >= 10 || !tens) {
+                return Err(OrdinalError::Invalid);
+            }
+            group += v;
+        } else if let Some(v) = word.find(&TENS, &TENS_ORDINAL, "", last) {
+            if v < 2 || group % 100 != 0 {
+                return Err(OrdinalError::Invalid);
+            }
+            group += v * 10;
+        } else if word.eq("hundred", if last { "th" } else { "" }) {
+            if group == 0 || group > 9 {
+                return Err(OrdinalError::Invalid);
+            }
+            group *= 100;
+        } else if let Some(k) = word.find(&SCALES, &SCALES, "th", last) {
+            if k == 0 || k as usize >= last_scale || group == 0 {
+                return Err(OrdinalError::Invalid);
+            }
+            let value = match 1000u128.checked_pow(k as u32) {
+                Some(scale) => group.checked_mul(scale),
+                None => None,
+            };
+            total = match value {
+                Some(value) => match total.checked_add(value) {
+                    Some(total) => total,
+                    None => return Err(OrdinalError::Overflow),
+                },
+                None => return Err(OrdinalError::Overflow),
+            };
+            group = 0;
+            last_scale = k as usize;
+        } else {
+            return Err(OrdinalError::Invalid);
+        }
+        after_and = false;
+        start = end + 1;
+    }
+    match total.checked_add(group) {
+        Some(n) => Ok(n),
+        None => Err(OrdinalError::Overflow),
+    }
+}
+
+/// A word of the parsed string, i.e. `s[start..end]`
+#[derive(Clone, Copy)]
+struct Word<'a> {
+    s: &'a [u8],
+    start: usize,
+    end: usize,
+    /// Whether the case of ASCII letters is ignored
+    ignore_case: bool,
+}
+
+impl Word<'_> {
+    /// Returns the index of the word in `cardinal`, or in `ordinal` (followed by
+    /// `suffix`) if it is the last word
+    const fn find(
+        self,
+        cardinal: &[&str],
+        ordinal: &[&str],
+        suffix: &str,
+        last: bool,
+    ) -> Option<u128> {
+        let mut i = 0;
+        while i < cardinal.len() {
+            let found = if last {
+                self.eq(ordinal[i], suffix)
+            } else {
+                self.eq(cardinal[i], "")
+            };
+            if found && !cardinal[i].is_empty() {
+                return Some(i as u128);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns whether the word is equal to `word` followed by `suffix`, which
+    /// must be lowercase
+    const fn eq(self, word: &str, suffix: &str) -> bool {
+        let (word, suffix) = (word.as_bytes(), suffix.as_bytes());
+        if self.end - self.start != word.len() + suffix.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < word.len() + suffix.len() {
+            let b = if i < word.len() {
+                word[i]
+            } else {
+                suffix[i - word.len()]
+            };
+            let c = if self.ignore_case {
+                self.s[self.start + i].to_ascii_lowercase()
+            } else {
+                self.s[self.start + i]
+            };
+            if c != b {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{ordinal_str, Ordinal, WordStyle};
+
+    #[test]
+    fn numeric() {
+        assert_eq!(parse("1st"), Ok(1));
+        assert_eq!(parse("23rd"), Ok(23));
+        assert_eq!(parse("23"), Ok(23));
+        assert_eq!(parse("111th"), Ok(111));
+        assert_eq!(parse("0th"), Err(OrdinalError::Zero));
+        assert_eq!(parse("23th"), Err(OrdinalError::Invalid));
+        assert_eq!(parse("23rd "), Err(OrdinalError::Invalid));
+        assert_eq!(parse(""), Err(OrdinalError::Invalid));
+        assert_eq!(
+            parse("340282366920938463463374607431768211455th"),
+            Ok(u128::MAX)
+        );
+        assert_eq!(
+            parse("340282366920938463463374607431768211456th"),
+            Err(OrdinalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!(parse("first"), Ok(1));
+        assert_eq!(parse("twenty-third"), Ok(23));
+        assert_eq!(parse("one hundredth"), Ok(100));
+        assert_eq!(parse("one million two thousandth"), Ok(1_002_000));
+        assert_eq!(parse("zeroth"), Err(OrdinalError::Zero));
+
+        for &invalid in &[
+            "one",
+            "twenty",
+            "third twenty",
+            "twenty-thirteenth",
+            "three-third",
+            "one one hundredth",
+            "one thousand one millionth",
+            "one hundred hundredth",
+            "twenty  first",
+            "twenty-",
+            "and first",
+            "one hundred and and third",
+            "fifth ",
+            "Fifth",
+        ] {
+            assert_eq!(parse(invalid), Err(OrdinalError::Invalid), "{}", invalid);
+        }
+        assert_eq!(
+            parse("one thousand undecillionth"),
+            Err(OrdinalError::Invalid)
+        );
+        assert_eq!(
+            parse("one thousand two undecillionth"),
+            Err(OrdinalError::Invalid)
+        );
+        assert_eq!(
+            parse("nine hundred undecillionth"),
+            Err(OrdinalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn macro_expansion() {
+        const FIRST: crate::O8 = ordinal_str!("first");
+        assert_eq!(FIRST, crate::O8::first());
+        assert_eq!(ordinal_str!("255th", O8).into1(), 255);
+        assert_eq!(ordinal_str!("23rd", crate::O64).into1(), 23);
+        let o: crate::Osize = ordinal_str!("one thousand and second");
+        assert_eq!(o.into1(), 1002);
+        assert_eq!(
+            ordinal_str!("340282366920938463463374607431768211455th", O128).into0(),
+            u128::MAX - 1
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let styles = [
+            WordStyle::new(),
+            WordStyle::new().and(true),
+            WordStyle::new().hyphenate(false),
+        ];
+        for n in (1..2000).chain(999_990..1_000_100).chain(Some(u128::MAX)) {
+            for style in &styles {
+                assert_eq!(parse(&style.ordinal(n)), Ok(n), "{}", style.ordinal(n));
+            }
+            assert_eq!(parse(&format!("{}{}", n, suffix(n))), Ok(n));
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+use crate::{Ordinal, OrdinalError, Osize, O128, O16, O32, O64, O8};
+
+/// Error returned when an ordinal number is converted to a narrower ordinal type,
+/// but it is too big for that type
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use num_ordinal::{Ordinal, O16, O8};
+///
+/// assert_eq!(O8::try_from(O16::from1(255)), Ok(O8::from1(255)));
+/// assert!(O8::try_from(O16::from1(256)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TryFromOrdinalError(());
+
+impl fmt::Display for TryFromOrdinalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ordinal number is too big for the target type")
+    }
+}
+
+impl core::error::Error for TryFromOrdinalError {}
+
+/// Implements lossless conversions, which are the same as for the `NonZero*` types.
+/// The 1-based `NonZero*` integers are widened the same way.
+macro_rules! impl_from {
+    ($from:ident($from_nz:ident) => $($to:ident($to_nz:ident)),*) => {
+        $(
+            impl From<$from> for $to {
+                fn from(o: $from) -> Self {
+                    $to::from_nonzero1(o.into_nonzero1().into())
+                }
+            }
+
+            impl From<$from_nz> for $to {
+                fn from(n: $from_nz) -> Self {
+                    $to::from_nonzero1(n.into())
+                }
+            }
+
+            impl From<$from> for $to_nz {
+                fn from(o: $from) -> Self {
+                    o.into_nonzero1().into()
+                }
+            }
+        )*
+    };
+}
+
+/// Implements conversions that fail if the ordinal is too big for the target type
+macro_rules! impl_try_from {
+    ($from:ident => $($to:ident),*) => {
+        $(
+            impl TryFrom<$from> for $to {
+                type Error = TryFromOrdinalError;
+
+                fn try_from(o: $from) -> Result<Self, Self::Error> {
+                    o.cast().ok_or(TryFromOrdinalError(()))
+                }
+            }
+        )*
+    };
+}
+
+/// Implements the conversions between an ordinal type and its integer and
+/// `NonZero*` types, which use the 1-based integer
+macro_rules! impl_integer {
+    ($t:ident, $int:ident, $nz:ident) => {
+        /// Converts a 1-based integer to an ordinal number. Fails with
+        /// [OrdinalError::Zero] if the integer is 0.
+        impl TryFrom<$int> for $t {
+            type Error = OrdinalError;
+
+            fn try_from(n: $int) -> Result<Self, Self::Error> {
+                $t::from1_checked(n)
+            }
+        }
+
+        /// Returns the 1-based integer of an ordinal number
+        impl From<$t> for $int {
+            fn from(o: $t) -> Self {
+                o.into1()
+            }
+        }
+
+        /// Converts a 1-based `NonZero*` integer to an ordinal number
+        impl From<$nz> for $t {
+            fn from(n: $nz) -> Self {
+                $t::from_nonzero1(n)
+            }
+        }
+
+        /// Returns the 1-based `NonZero*` integer of an ordinal number
+        impl From<$t> for $nz {
+            fn from(o: $t) -> Self {
+                o.into_nonzero1()
+            }
+        }
+    };
+}
+
+impl_integer!(Osize, usize, NonZeroUsize);
+impl_integer!(O128, u128, NonZeroU128);
+impl_integer!(O64, u64, NonZeroU64);
+impl_integer!(O32, u32, NonZeroU32);
+impl_integer!(O16, u16, NonZeroU16);
+impl_integer!(O8, u8, NonZeroU8);
+
+impl_from!(O8(NonZeroU8) => O16(NonZeroU16), O32(NonZeroU32), O64(NonZeroU64), O128(NonZeroU128), Osize(NonZeroUsize));
+impl_from!(O16(NonZeroU16) => O32(NonZeroU32), O64(NonZeroU64), O128(NonZeroU128), Osize(NonZeroUsize));
+impl_from!(O32(NonZeroU32) => O64(NonZeroU64), O128(NonZeroU128));
+impl_from!(O64(NonZeroU64) => O128(NonZeroU128));
+
+impl_try_from!(O16 => O8);
+impl_try_from!(O32 => O8, O16, Osize);
+impl_try_from!(O64 => O8, O16, O32, Osize);
+impl_try_from!(O128 => O8, O16, O32, O64, Osize);
+impl_try_from!(Osize => O8, O16, O32, O64, O128);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widen() {
+        assert_eq!(Osize::from(O8::first()), Osize::first());
+        assert_eq!(Osize::from(O8::last()), Osize::from1(255));
+        assert_eq!(O16::from(O8::last()), O16::from1(255));
+        assert_eq!(O32::from(O16::last()), O32::from1(65535));
+        assert_eq!(O128::from(O64::last()), O128::from1(u64::MAX as u128));
+    }
+
+    #[test]
+    fn narrow() {
+        assert_eq!(O8::try_from(O16::from1(255)), Ok(O8::last()));
+        assert_eq!(O8::try_from(O16::from1(256)), Err(TryFromOrdinalError(())));
+        assert_eq!(O8::try_from(O16::from0(255)), Err(TryFromOrdinalError(())));
+        assert_eq!(O8::try_from(O128::first()), Ok(O8::first()));
+        assert_eq!(O64::try_from(O128::last()), Err(TryFromOrdinalError(())));
+        assert_eq!(O32::try_from(Osize::from1(7)), Ok(O32::from1(7)));
+        assert_eq!(Osize::try_from(O64::from1(7)), Ok(Osize::from1(7)));
+        assert_eq!(
+            TryFromOrdinalError(()).to_string(),
+            "ordinal number is too big for the target type"
+        );
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!(O8::try_from(1u8), Ok(O8::first()));
+        assert_eq!(O8::try_from(255u8), Ok(O8::last()));
+        assert_eq!(O8::try_from(0u8), Err(OrdinalError::Zero));
+        assert_eq!(u8::from(O8::last()), 255);
+        assert_eq!(u128::from(O128::last()), u128::MAX);
+        assert_eq!(usize::from(Osize::from0(4)), 5);
+        for n in 1..=255u8 {
+            assert_eq!(u8::from(O8::try_from(n).unwrap()), n);
+        }
+    }
+
+    #[test]
+    fn nonzero() {
+        let n = NonZeroU8::new(255).unwrap();
+        assert_eq!(O8::from(n), O8::last());
+        assert_eq!(O8::from_nonzero1(n).into1(), 255);
+        assert_eq!(NonZeroU8::from(O8::last()), n);
+        assert_eq!(O16::from(n), O16::from1(255));
+        assert_eq!(NonZeroU16::from(O8::last()).get(), 255);
+        assert_eq!(O64::from(NonZeroU32::MAX), O64::from1(u32::MAX as u64));
+        assert_eq!(NonZeroUsize::from(O16::first()), NonZeroUsize::MIN);
+        assert_eq!(
+            Osize::first().into_nonzero1(),
+            NonZeroUsize::new(1).unwrap()
+        );
+        assert_eq!(O128::from(NonZeroU128::MAX), O128::last());
+    }
+
+    #[test]
+    fn cast() {
+        assert_eq!(O16::from1(255).cast::<O8>(), Some(O8::last()));
+        assert_eq!(O16::from1(256).cast::<O8>(), None);
+        assert_eq!(O8::last().cast::<O128>(), Some(O128::from1(255)));
+        assert_eq!(O32::from1(4).cast::<O32>(), Some(O32::from1(4)));
+    }
+}
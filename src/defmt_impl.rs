@@ -0,0 +1,32 @@
+use crate::*;
+
+/// Writes a 1-based ordinal number like the `Display` impl, without allocating
+fn format_ordinal_u128(n: u128, f: defmt::Formatter<'_>) {
+    match table::get(n) {
+        Some(s) => defmt::write!(f, "{=str}", s),
+        None => defmt::write!(f, "{=u128}{=str}", n, suffix(n)),
+    }
+}
+
+impl<T: OrdinalInteger> defmt::Format for O<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        format_ordinal_u128(self.into1().to_u128(), f)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    fn assert_format<T: defmt::Format>() {}
+
+    #[test]
+    fn implemented() {
+        assert_format::<Osize>();
+        assert_format::<O128>();
+        assert_format::<O64>();
+        assert_format::<O32>();
+        assert_format::<O16>();
+        assert_format::<O8>();
+    }
+}
@@ -0,0 +1,43 @@
+use core::fmt;
+
+/// Error returned when an ordinal number can't be created or converted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrdinalError {
+    /// The value is zero, which doesn't correspond to a (1-based) ordinal number
+    Zero,
+    /// The value is negative
+    Negative,
+    /// The value is too large for the ordinal type
+    Overflow,
+    /// The value isn't a number, or it can't be parsed as one
+    Invalid,
+    /// The value has a fractional part
+    Fractional,
+    /// The value is a floating-point number greater than `2^53 - 1`, so it may
+    /// have been rounded
+    Inexact,
+}
+
+impl OrdinalError {
+    /// Returns the error message. This is a `const fn`, so it can be used in
+    /// compile-time panics.
+    pub(crate) const fn message(self) -> &'static str {
+        match self {
+            OrdinalError::Zero => "0 is not a valid 1-based ordinal",
+            OrdinalError::Negative => "negative numbers are not valid ordinals",
+            OrdinalError::Overflow => "value is too big for this ordinal type",
+            OrdinalError::Invalid => "value is not an ordinal number",
+            OrdinalError::Fractional => "value is not an integer",
+            OrdinalError::Inexact => "value is too big to be represented exactly",
+        }
+    }
+}
+
+impl fmt::Display for OrdinalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for OrdinalError {}
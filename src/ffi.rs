@@ -0,0 +1,261 @@
+//! C-compatible functions for 32-bit ordinal numbers
+//!
+//! All ordinal types are `#[repr(transparent)]` wrappers around a `NonZero*` integer
+//! containing the 1-based value. In C, an [O32] is a `uint32_t` containing `into1()`,
+//! which is never 0.
+//!
+//! **Note:** Older versions stored the 0-based value, so C code written against an
+//! older header must be updated: the first ordinal is now `1`, not `0`.
+//!
+//! Functions that accept an ordinal from C take a plain `uint32_t` and check that it
+//! isn't 0, so they are safe to call with any value. The functions in this module
+//! never panic; invalid input is reported with a [NoStatus] code instead.
+//!
+//! The C header `include/num_ordinal.h` is generated by cbindgen:
+//!
+//! ```text
+//! cbindgen --config cbindgen.toml --output include/num_ordinal.h
+//! ```
+//!
+//! The module is enabled by an inner `#![cfg]` attribute rather than a `cfg` on the
+//! `mod` item, so cbindgen includes its functions without `#if` guards.
+//!
+//! `tests/ffi/main.c` shows how the functions are used from C.
+
+#![cfg(feature = "ffi")]
+
+use core::ffi::c_char;
+use core::fmt::{self, Write};
+use core::slice;
+
+use crate::{suffix, Ordinal, O32};
+
+/// Status code returned by fallible functions
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NoStatus {
+    /// The function succeeded
+    Ok = 0,
+    /// The number is 0, which isn't a valid 1-based ordinal
+    Zero = 1,
+    /// The number is too big for the ordinal type
+    Overflow = 2,
+    /// A pointer argument is null
+    NullPointer = 3,
+}
+
+/// Creates an ordinal number from a 1-based number and writes it to `out`.
+///
+/// Returns [NoStatus::Zero] if `n` is 0; `out` is only written on success.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn no_ordinal_from1_u32(n: u32, out: *mut O32) -> NoStatus {
+    if out.is_null() {
+        return NoStatus::NullPointer;
+    }
+    match O32::try_from1(n) {
+        Some(o) => {
+            *out = o;
+            NoStatus::Ok
+        }
+        None => NoStatus::Zero,
+    }
+}
+
+/// Creates an ordinal number from a 0-based number and writes it to `out`.
+///
+/// Returns [NoStatus::Overflow] if `n` is `UINT32_MAX`; `out` is only written on success.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn no_ordinal_from0_u32(n: u32, out: *mut O32) -> NoStatus {
+    if out.is_null() {
+        return NoStatus::NullPointer;
+    }
+    match O32::try_from0(n) {
+        Some(o) => {
+            *out = o;
+            NoStatus::Ok
+        }
+        None => NoStatus::Overflow,
+    }
+}
+
+/// Checks that `o` is a valid ordinal number and writes its 1-based number to `out`.
+///
+/// Returns [NoStatus::Zero] if `o` is 0; `out` is only written on success.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn no_ordinal_into1_u32(o: u32, out: *mut u32) -> NoStatus {
+    if out.is_null() {
+        return NoStatus::NullPointer;
+    }
+    match O32::try_from1(o) {
+        Some(o) => {
+            *out = o.into1();
+            NoStatus::Ok
+        }
+        None => NoStatus::Zero,
+    }
+}
+
+/// Returns the English suffix of a 1-based number as a static, nul-terminated
+/// string: `"st"`, `"nd"`, `"rd"` or `"th"`
+#[no_mangle]
+pub extern "C" fn no_ordinal_suffix(n: u64) -> *const c_char {
+    let s: &'static [u8] = match suffix(n as u128) {
+        "st" => b"st\0",
+        "nd" => b"nd\0",
+        "rd" => b"rd\0",
+        _ => b"th\0",
+    };
+    s.as_ptr() as *const c_char
+}
+
+/// Writes an ordinal number like its `Display` impl (e.g. `"first"` or `"4th"`)
+/// into `buf`, followed by a nul terminator, and writes the length of the formatted
+/// ordinal without the nul terminator to `out_len`.
+///
+/// Like `snprintf`, the output is truncated if it doesn't fit into `len` bytes, so
+/// truncation happened if the length is `>= len`. `buf` may be null if `len` is 0,
+/// which can be used to query the required length.
+///
+/// Returns [NoStatus::Zero] if `o` is 0; nothing is written in that case.
+///
+/// # Safety
+///
+/// `buf` must be valid for writes of `len` bytes, or null if `len` is 0. `out_len`
+/// must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn no_ordinal_format(
+    o: u32,
+    buf: *mut c_char,
+    len: usize,
+    out_len: *mut usize,
+) -> NoStatus {
+    if out_len.is_null() {
+        return NoStatus::NullPointer;
+    }
+    let o = match O32::try_from1(o) {
+        Some(o) => o,
+        None => return NoStatus::Zero,
+    };
+    let buf = if buf.is_null() || len == 0 {
+        &mut [][..]
+    } else {
+        slice::from_raw_parts_mut(buf.cast::<u8>(), len)
+    };
+    let mut w = Truncating { buf, len: 0 };
+    let _ = write!(w, "{}", o);
+
+    let end = w.len.min(w.buf.len().saturating_sub(1));
+    if let Some(b) = w.buf.get_mut(end) {
+        *b = 0;
+    }
+    *out_len = w.len;
+    NoStatus::Ok
+}
+
+/// Writer that writes as much as fits into a buffer, keeping one byte for the nul
+/// terminator, and counts the total length
+struct Truncating<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for Truncating<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let capacity = self.buf.len().saturating_sub(1);
+        for (i, &b) in s.as_bytes().iter().enumerate() {
+            if let Some(dst) = self.buf[..capacity].get_mut(self.len + i) {
+                *dst = b;
+            }
+        }
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use core::ffi::CStr;
+    use core::ptr;
+
+    #[test]
+    fn constructors() {
+        let mut o = O32::first();
+        let mut n = 0;
+        unsafe {
+            assert_eq!(no_ordinal_from1_u32(4, &mut o), NoStatus::Ok);
+            assert_eq!(o, O32::from1(4));
+            assert_eq!(no_ordinal_from1_u32(0, &mut o), NoStatus::Zero);
+            assert_eq!(o, O32::from1(4));
+            assert_eq!(no_ordinal_from0_u32(u32::MAX, &mut o), NoStatus::Overflow);
+            assert_eq!(
+                no_ordinal_from1_u32(1, ptr::null_mut()),
+                NoStatus::NullPointer
+            );
+
+            assert_eq!(no_ordinal_into1_u32(o.into1(), &mut n), NoStatus::Ok);
+            assert_eq!(n, 4);
+            assert_eq!(no_ordinal_into1_u32(0, &mut n), NoStatus::Zero);
+            assert_eq!(n, 4);
+            assert_eq!(
+                no_ordinal_into1_u32(1, ptr::null_mut()),
+                NoStatus::NullPointer
+            );
+        }
+    }
+
+    #[test]
+    fn suffixes() {
+        let s = |n| {
+            unsafe { CStr::from_ptr(no_ordinal_suffix(n)) }
+                .to_str()
+                .unwrap()
+        };
+        assert_eq!(s(1), "st");
+        assert_eq!(s(12), "th");
+        assert_eq!(s(23), "rd");
+        assert_eq!(s(u64::MAX), "th");
+    }
+
+    #[test]
+    fn format() {
+        let mut buf = [0x7f as c_char; 8];
+        let mut len = 0;
+        unsafe {
+            let status = no_ordinal_format(1234, buf.as_mut_ptr(), buf.len(), &mut len);
+            assert_eq!((status, len), (NoStatus::Ok, 6));
+            assert_eq!(CStr::from_ptr(buf.as_ptr()).to_str(), Ok("1234th"));
+
+            let status = no_ordinal_format(1234, buf.as_mut_ptr(), 4, &mut len);
+            assert_eq!((status, len), (NoStatus::Ok, 6));
+            assert_eq!(CStr::from_ptr(buf.as_ptr()).to_str(), Ok("123"));
+
+            let status = no_ordinal_format(1, ptr::null_mut(), 0, &mut len);
+            assert_eq!((status, len), (NoStatus::Ok, 5));
+            let status = no_ordinal_format(1, buf.as_mut_ptr(), 1, &mut len);
+            assert_eq!((status, len), (NoStatus::Ok, 5));
+            assert_eq!(buf[0], 0);
+
+            buf[0] = 0x7f;
+            len = 42;
+            let status = no_ordinal_format(0, buf.as_mut_ptr(), buf.len(), &mut len);
+            assert_eq!((status, len, buf[0]), (NoStatus::Zero, 42, 0x7f));
+            assert_eq!(
+                no_ordinal_format(1, buf.as_mut_ptr(), buf.len(), ptr::null_mut()),
+                NoStatus::NullPointer
+            );
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{Integer, Ordinal, OrdinalError};
+
+/// Rounding mode for converting fractional numbers to ordinals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round to the nearest integer; halfway cases are rounded up,
+    /// so `0.995` is the 100th percentile
+    Nearest,
+    /// Round down, so `0.995` is the 99th percentile
+    Floor,
+    /// Round up, so `0.005` is the 1st percentile
+    Ceil,
+}
+
+/// The greatest integer `n` such that all integers up to `n` can be represented
+/// exactly as `f64` and aren't rounded to another integer, i.e. `2^53 - 1`
+pub(crate) const MAX_EXACT: f64 = 9_007_199_254_740_991.0;
+
+/// Converts a 1-based float to an ordinal, rounding it if a rounding mode is given
+pub(crate) fn from1_f64<O: Ordinal>(x: f64, rounding: Option<Rounding>) -> Result<O, OrdinalError> {
+    let n = match rounding {
+        None => x,
+        Some(Rounding::Nearest) => round(x),
+        Some(Rounding::Floor) => floor(x),
+        Some(Rounding::Ceil) => ceil(x),
+    };
+    if n.is_nan() {
+        Err(OrdinalError::Invalid)
+    } else if n < 0.0 {
+        Err(OrdinalError::Negative)
+    } else if n == 0.0 {
+        Err(OrdinalError::Zero)
+    } else if n >= u128::MAX as f64 {
+        Err(OrdinalError::Overflow)
+    } else if n != trunc(n) {
+        Err(OrdinalError::Fractional)
+    } else {
+        let ordinal = O::IntegerType::from_u128(n as u128)
+            .and_then(O::try_from1)
+            .ok_or(OrdinalError::Overflow)?;
+        if n > MAX_EXACT {
+            Err(OrdinalError::Inexact)
+        } else {
+            Ok(ordinal)
+        }
+    }
+}
+
+// `f64::round` and friends are only available with std, so they're implemented
+// here. Floats with a magnitude of at least 2^52 are integers already.
+
+/// Returns the absolute value (`f64::abs` is only available in `core` since Rust 1.85)
+pub(crate) fn abs(x: f64) -> f64 {
+    if x < 0.0 {
+        -x
+    } else {
+        x
+    }
+}
+
+/// Rounds towards zero
+pub(crate) fn trunc(x: f64) -> f64 {
+    if abs(x) < 4_503_599_627_370_496.0 {
+        x as i64 as f64
+    } else {
+        x
+    }
+}
+
+/// Rounds down
+pub(crate) fn floor(x: f64) -> f64 {
+    let t = trunc(x);
+    if t > x {
+        t - 1.0
+    } else {
+        t
+    }
+}
+
+/// Rounds up
+pub(crate) fn ceil(x: f64) -> f64 {
+    let t = trunc(x);
+    if t < x {
+        t + 1.0
+    } else {
+        t
+    }
+}
+
+/// Rounds to the nearest integer, and halfway cases away from zero
+pub(crate) fn round(x: f64) -> f64 {
+    let t = trunc(x);
+    if x - t >= 0.5 {
+        t + 1.0
+    } else if t - x >= 0.5 {
+        t - 1.0
+    } else {
+        t
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounding() {
+        let values = [
+            0.0,
+            0.4,
+            0.5,
+            0.6,
+            1.0,
+            1.5,
+            2.5,
+            -0.5,
+            -1.5,
+            -2.7,
+            99.5,
+            1e15 + 0.5,
+            1e300,
+        ];
+        for &x in values.iter() {
+            assert_eq!(trunc(x), x.trunc(), "trunc({})", x);
+            assert_eq!(floor(x), x.floor(), "floor({})", x);
+            assert_eq!(ceil(x), x.ceil(), "ceil({})", x);
+            assert_eq!(round(x), x.round(), "round({})", x);
+        }
+        assert!(round(f64::NAN).is_nan());
+        assert_eq!(floor(f64::INFINITY), f64::INFINITY);
+    }
+}
@@ -0,0 +1,194 @@
+use alloc::string::ToString;
+
+use fluent_bundle::bundle::FluentBundle;
+use fluent_bundle::types::{FluentNumber, FluentNumberOptions, FluentNumberType};
+use fluent_bundle::{FluentArgs, FluentError, FluentValue};
+
+use crate::float::MAX_EXACT;
+use crate::{const_parse, Ordinal, OrdinalInteger, O};
+
+/// Registers the [ordinal_function] as `ORDINAL` on a Fluent bundle.
+///
+/// In FTL messages, `ORDINAL($pos)` can then be used as a selector, which
+/// matches the ordinal plural categories (`one`, `two`, `few`, `many` and
+/// `other`) of the bundle's locale:
+///
+/// ```
+/// use fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+/// use num_ordinal::{add_ordinal_function, ordinal, O32};
+///
+/// let ftl = "place = You finished { ORDINAL($pos) ->
+///     [one] {$pos}st
+///     [two] {$pos}nd
+///     [few] {$pos}rd
+///    *[other] {$pos}th
+/// }!";
+/// let resource = FluentResource::try_new(ftl.to_string()).unwrap();
+/// let mut bundle = FluentBundle::new(vec!["en-US".parse().unwrap()]);
+/// bundle.set_use_isolating(false);
+/// bundle.add_resource(resource).unwrap();
+/// add_ordinal_function(&mut bundle).unwrap();
+///
+/// let mut args = FluentArgs::new();
+/// args.set("pos", ordinal!(22-nd O32));
+/// let pattern = bundle.get_message("place").unwrap().value().unwrap();
+/// let message = bundle.format_pattern(pattern, Some(&args), &mut vec![]);
+/// assert_eq!(message, "You finished 22nd!");
+/// ```
+pub fn add_ordinal_function<R, M>(bundle: &mut FluentBundle<R, M>) -> Result<(), FluentError> {
+    bundle.add_function("ORDINAL", ordinal_function)
+}
+
+/// The `ORDINAL` Fluent function, see [add_ordinal_function].
+///
+/// The argument can be a positive integer, which is interpreted as a 1-based
+/// ordinal number, or a string like `"4th"` or `"fourth"`. The result is a
+/// number with the ordinal plural type. Any other argument results in an error.
+pub fn ordinal_function<'a>(
+    positional: &[FluentValue<'a>],
+    _named: &FluentArgs,
+) -> FluentValue<'a> {
+    match positional {
+        [FluentValue::Number(n)] if n.value >= 1.0 && n.value.fract() == 0.0 => {
+            FluentValue::Number(FluentNumber::new(
+                n.value,
+                FluentNumberOptions {
+                    r#type: FluentNumberType::Ordinal,
+                    ..n.options.clone()
+                },
+            ))
+        }
+        [FluentValue::String(s)] => match const_parse::parse(s) {
+            Ok(n) => fluent_value(n),
+            Err(_) => FluentValue::Error,
+        },
+        _ => FluentValue::Error,
+    }
+}
+
+/// Converts a 1-based number to a Fluent number with the ordinal plural type.
+/// Numbers that can't be represented exactly as `f64` are converted to strings,
+/// which don't match any plural category.
+fn fluent_value(n: u128) -> FluentValue<'static> {
+    if n as f64 > MAX_EXACT {
+        return FluentValue::String(n.to_string().into());
+    }
+    FluentValue::Number(FluentNumber::new(
+        n as f64,
+        FluentNumberOptions {
+            r#type: FluentNumberType::Ordinal,
+            ..Default::default()
+        },
+    ))
+}
+
+/// Converts the ordinal to a 1-based Fluent number with the ordinal plural type,
+/// so it can be used in selectors directly
+impl<T: OrdinalInteger> From<O<T>> for FluentValue<'_> {
+    fn from(o: O<T>) -> Self {
+        fluent_value(o.into1().to_u128())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use fluent_bundle::{FluentBundle, FluentResource};
+
+    use super::*;
+    use crate::*;
+
+    const FTL: &str = "
+category = { $pos ->
+    [one] one
+    [two] two
+    [few] few
+    [many] many
+   *[other] other
+}
+ordinal = { ORDINAL($pos) ->
+    [one] one
+    [two] two
+    [few] few
+    [many] many
+   *[other] other
+}
+";
+
+    fn bundle(locale: Locale) -> FluentBundle<FluentResource> {
+        let resource = FluentResource::try_new(FTL.to_string()).unwrap();
+        let mut bundle = FluentBundle::new(vec![locale.language_tag().parse().unwrap()]);
+        bundle.add_resource(resource).unwrap();
+        add_ordinal_function(&mut bundle).unwrap();
+        bundle
+    }
+
+    fn format(bundle: &FluentBundle<FluentResource>, id: &str, pos: FluentValue) -> String {
+        let mut args = FluentArgs::new();
+        args.set("pos", pos);
+        let pattern = bundle.get_message(id).unwrap().value().unwrap();
+        let mut errors = vec![];
+        let s = bundle.format_pattern(pattern, Some(&args), &mut errors);
+        assert!(errors.is_empty(), "{:?}", errors);
+        s.into_owned()
+    }
+
+    /// Checks that the categories of this crate match the CLDR data used by Fluent
+    fn check_locale(locale: Locale) {
+        let bundle = bundle(locale);
+        for n in 1..1000 {
+            let expected = locale.ordinal_category(n as u128).as_str();
+            assert_eq!(format(&bundle, "category", O32::from1(n).into()), expected);
+            assert_eq!(format(&bundle, "ordinal", n.into()), expected);
+        }
+    }
+
+    #[test]
+    fn categories() {
+        check_locale(Locale::En);
+        #[cfg(feature = "locale-de")]
+        check_locale(Locale::De);
+        #[cfg(feature = "locale-es")]
+        check_locale(Locale::Es);
+        #[cfg(feature = "locale-fi")]
+        check_locale(Locale::Fi);
+        #[cfg(feature = "locale-fr")]
+        check_locale(Locale::Fr);
+        #[cfg(feature = "locale-fa")]
+        check_locale(Locale::Fa);
+        #[cfg(feature = "locale-id")]
+        check_locale(Locale::Id);
+        #[cfg(feature = "locale-ms")]
+        check_locale(Locale::Ms);
+        #[cfg(feature = "locale-sr")]
+        check_locale(Locale::SrCyrl);
+        #[cfg(feature = "locale-sr")]
+        check_locale(Locale::SrLatn);
+        #[cfg(feature = "locale-hr")]
+        check_locale(Locale::Hr);
+        #[cfg(feature = "locale-is")]
+        check_locale(Locale::Is);
+        #[cfg(feature = "locale-lt")]
+        check_locale(Locale::Lt);
+        #[cfg(feature = "locale-sw")]
+        check_locale(Locale::Sw);
+    }
+
+    #[test]
+    fn arguments() {
+        let bundle = bundle(Locale::En);
+        assert_eq!(format(&bundle, "ordinal", "3rd".into()), "few");
+        assert_eq!(format(&bundle, "ordinal", "twenty-second".into()), "two");
+
+        let f = |arg: FluentValue<'static>| ordinal_function(&[arg], &FluentArgs::new());
+        // `FluentValue::Error` isn't equal to itself
+        let is_error = |value| matches!(value, FluentValue::Error);
+        assert!(is_error(f(0.into())));
+        assert!(is_error(f(1.5.into())));
+        assert!(is_error(f("3th".into())));
+        assert!(is_error(ordinal_function(&[], &FluentArgs::new())));
+        assert_eq!(
+            FluentValue::from(O128::from0(u128::MAX - 1)),
+            FluentValue::String(u128::MAX.to_string().into())
+        );
+    }
+}
@@ -0,0 +1,479 @@
+use core::fmt::{self, Write};
+
+use crate::locale::{self, Form, Options};
+use crate::{Gender, Integer, Locale, NounClass, Ordinal, SuffixStyle, WordStyle};
+
+/// Letter case applied to the formatted ordinal number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+enum Casing {
+    #[default]
+    Unchanged,
+    Upper,
+    Capitalized,
+}
+
+/// A reusable configuration for formatting ordinal numbers.
+///
+/// The options are set once with the builder methods, then the formatter can
+/// format any number of ordinals, which is convenient when the configuration is
+/// passed around as a value or used in a loop:
+///
+/// ```
+/// use num_ordinal::{ordinal, Locale, OrdinalFormatter};
+///
+/// let formatter = OrdinalFormatter::new().locale(Locale::En).grouping(',');
+/// assert_eq!(formatter.fmt(ordinal!(1234-th O32)).to_string(), "1,234th");
+///
+/// let formatter = OrdinalFormatter::new().words_up_to(10).capitalize();
+/// assert_eq!(formatter.fmt(ordinal!(4-th O32)).to_string(), "Fourth");
+/// assert_eq!(formatter.fmt(ordinal!(11-th O32)).to_string(), "11th");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OrdinalFormatter {
+    locale: Locale,
+    opts: Options,
+    words_up_to: u128,
+    casing: Casing,
+}
+
+impl OrdinalFormatter {
+    /// Creates a formatter that writes English ordinals with digits, e.g. `4th`
+    pub fn new() -> Self {
+        OrdinalFormatter::default()
+    }
+
+    /// Creates a formatter following the AP Stylebook, which spells out _first_
+    /// through _ninth_ and uses figures starting with _10th_.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalFormatter};
+    ///
+    /// let ap = OrdinalFormatter::ap_style();
+    /// assert_eq!(ap.fmt(ordinal!(9-th O32)).to_string(), "ninth");
+    /// assert_eq!(ap.fmt(ordinal!(10-th O32)).to_string(), "10th");
+    /// ```
+    pub fn ap_style() -> Self {
+        OrdinalFormatter::new().words_up_to(9)
+    }
+
+    /// Creates a formatter following the Chicago Manual of Style, which spells
+    /// out _first_ through _one hundredth_, with hyphenated compounds like
+    /// _twenty-first_ and without _and_, and uses figures starting with _101st_.
+    ///
+    /// Chicago also spells out round numbers such as _two hundredth_; this rule
+    /// isn't encoded, so they are written with figures.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalFormatter};
+    ///
+    /// let chicago = OrdinalFormatter::chicago_style();
+    /// assert_eq!(chicago.fmt(ordinal!(21-st O32)).to_string(), "twenty-first");
+    /// assert_eq!(chicago.fmt(ordinal!(101-st O32)).to_string(), "101st");
+    /// ```
+    pub fn chicago_style() -> Self {
+        OrdinalFormatter::new()
+            .words_up_to(100)
+            .word_style(WordStyle::new().hyphenate(true).and(false))
+    }
+
+    /// Sets the locale. The default is [Locale::En].
+    pub fn locale(self, locale: Locale) -> Self {
+        OrdinalFormatter { locale, ..self }
+    }
+
+    /// Writes ordinal numbers with digits, e.g. `4th`. This is the default.
+    pub fn numeric(self) -> Self {
+        self.words_up_to(0)
+    }
+
+    /// Spells out ordinal numbers, e.g. `fourth`.
+    ///
+    /// Locales that don't support spelling out a number fall back to digits.
+    pub fn words(self) -> Self {
+        self.words_up_to(u128::MAX)
+    }
+
+    /// Spells out ordinal numbers up to and including the `max`-th, and writes
+    /// greater ordinals with digits.
+    pub fn words_up_to(self, max: u128) -> Self {
+        OrdinalFormatter {
+            words_up_to: max,
+            ..self
+        }
+    }
+
+    /// Sets the style of spelled-out English ordinals, e.g. whether _and_ is
+    /// inserted in _one hundred and first_. The default is [WordStyle::default].
+    ///
+    /// This has no effect on ordinals written with digits and other locales.
+    pub fn word_style(mut self, style: WordStyle) -> Self {
+        self.opts.word_style = style;
+        self
+    }
+
+    /// Separates groups of three digits with the given character, e.g. `1,234th`.
+    ///
+    /// This has no effect on spelled-out ordinal numbers.
+    pub fn grouping(mut self, separator: char) -> Self {
+        self.opts.grouping = Some(separator);
+        self
+    }
+
+    /// Sets the style of English suffixes, e.g. `2d` instead of `2nd` in
+    /// [SuffixStyle::Legal]. The default is [SuffixStyle::Standard].
+    ///
+    /// This has no effect on spelled-out ordinal numbers and other locales.
+    pub fn suffix_style(mut self, style: SuffixStyle) -> Self {
+        self.opts.suffix_style = style;
+        self
+    }
+
+    /// Converts the ordinal number to upper case, e.g. `FOURTH`
+    pub fn uppercase(self) -> Self {
+        OrdinalFormatter {
+            casing: Casing::Upper,
+            ..self
+        }
+    }
+
+    /// Converts the first character of the ordinal number to upper case, e.g. `Fourth`
+    pub fn capitalize(self) -> Self {
+        OrdinalFormatter {
+            casing: Casing::Capitalized,
+            ..self
+        }
+    }
+
+    /// Sets the grammatical gender. The default is [Gender::Masculine].
+    ///
+    /// This only applies to locales where ordinal numbers inflect for gender.
+    pub fn gender(mut self, gender: Gender) -> Self {
+        self.opts.gender = gender;
+        self
+    }
+
+    /// Sets the noun class that the ordinal number agrees with, e.g. `cha tatu`
+    /// instead of `wa tatu` in Swahili. The default is [NounClass::MWa].
+    ///
+    /// This only applies to locales where ordinal numbers agree with noun classes.
+    pub fn noun_class(mut self, class: NounClass) -> Self {
+        self.opts.noun_class = class;
+        self
+    }
+
+    /// Uses the definite form, e.g. `pirmasis` instead of `pirmas` in Lithuanian.
+    ///
+    /// This only applies to locales that distinguish definite ordinals.
+    pub fn definite(mut self) -> Self {
+        self.opts.definite = true;
+        self
+    }
+
+    /// Writes numeric ordinals with a trailing period, e.g. `4.` instead of `4-as`
+    /// in Lithuanian.
+    ///
+    /// This only applies to locales that use both conventions.
+    pub fn period(mut self) -> Self {
+        self.opts.period = true;
+        self
+    }
+
+    /// Uses the locale's native digits instead of ASCII digits, e.g. `۴` in Persian.
+    ///
+    /// This only applies to locales with their own digits.
+    pub fn native_digits(mut self) -> Self {
+        self.opts.native_digits = true;
+        self
+    }
+
+    /// Uses the adjectival form of the ordinal number, e.g. `چهارمین` instead of
+    /// `چهارم` in Persian.
+    ///
+    /// This only applies to locales that distinguish an adjectival form.
+    pub fn adjectival(mut self) -> Self {
+        self.opts.adjectival = true;
+        self
+    }
+
+    /// Returns a display adapter that formats the ordinal number with this configuration
+    pub fn fmt<O: Ordinal>(&self, o: O) -> Localized {
+        Localized {
+            n: o.into1().to_u128(),
+            formatter: *self,
+        }
+    }
+
+    /// Writes the ordinal number with this configuration
+    pub fn format_into<O: Ordinal, W: Write>(&self, o: O, w: &mut W) -> fmt::Result {
+        self.write(w, o.into1().to_u128())
+    }
+
+    fn write(&self, w: &mut dyn Write, n: u128) -> fmt::Result {
+        let opts = Options {
+            form: if n <= self.words_up_to {
+                Form::Words
+            } else {
+                Form::Numeric
+            },
+            ..self.opts
+        };
+        match self.casing {
+            Casing::Unchanged => locale::write(w, self.locale, n, &opts),
+            casing => {
+                let mut w = CaseWriter {
+                    w,
+                    upper: true,
+                    casing,
+                };
+                locale::write(&mut w, self.locale, n, &opts)
+            }
+        }
+    }
+}
+
+/// Writer that converts characters to upper case
+struct CaseWriter<'a> {
+    w: &'a mut dyn Write,
+    /// Whether the next character is converted to upper case
+    upper: bool,
+    casing: Casing,
+}
+
+impl Write for CaseWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.upper {
+                for u in c.to_uppercase() {
+                    self.w.write_char(u)?;
+                }
+            } else {
+                self.w.write_char(c)?;
+            }
+            self.upper = self.casing == Casing::Upper;
+        }
+        Ok(())
+    }
+}
+
+/// Display adapter for an ordinal number in a specific [Locale].
+///
+/// It is returned by [display_in](crate::Ordinal::display_in) and
+/// [OrdinalFormatter::fmt]. The ordinal number is written with digits by default;
+/// in contrast to the `Display` impl of ordinal numbers, this also applies to the
+/// first three ordinals:
+///
+/// ```
+/// use num_ordinal::{ordinal, Locale, Ordinal};
+///
+/// let o = ordinal!(first O32);
+/// assert_eq!(o.display_in(Locale::En).to_string(), "1st");
+/// assert_eq!(o.display_in(Locale::En).words().to_string(), "first");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Localized {
+    n: u128,
+    formatter: OrdinalFormatter,
+}
+
+impl Localized {
+    pub(crate) fn new(n: u128, locale: Locale) -> Self {
+        Localized {
+            n,
+            formatter: OrdinalFormatter::new().locale(locale),
+        }
+    }
+
+    fn map(self, f: impl FnOnce(OrdinalFormatter) -> OrdinalFormatter) -> Self {
+        Localized {
+            formatter: f(self.formatter),
+            ..self
+        }
+    }
+
+    /// Writes the ordinal number with digits, e.g. `4th`. This is the default.
+    pub fn numeric(self) -> Self {
+        self.map(OrdinalFormatter::numeric)
+    }
+
+    /// Spells out the ordinal number, e.g. `fourth`.
+    ///
+    /// Locales that don't support spelling out a number fall back to digits.
+    pub fn words(self) -> Self {
+        self.map(OrdinalFormatter::words)
+    }
+
+    /// See [OrdinalFormatter::words_up_to]
+    pub fn words_up_to(self, max: u128) -> Self {
+        self.map(|f| f.words_up_to(max))
+    }
+
+    /// See [OrdinalFormatter::grouping]
+    pub fn grouping(self, separator: char) -> Self {
+        self.map(|f| f.grouping(separator))
+    }
+
+    /// See [OrdinalFormatter::word_style]
+    pub fn word_style(self, style: WordStyle) -> Self {
+        self.map(|f| f.word_style(style))
+    }
+
+    /// See [OrdinalFormatter::suffix_style]
+    pub fn suffix_style(self, style: SuffixStyle) -> Self {
+        self.map(|f| f.suffix_style(style))
+    }
+
+    /// See [OrdinalFormatter::uppercase]
+    pub fn uppercase(self) -> Self {
+        self.map(OrdinalFormatter::uppercase)
+    }
+
+    /// See [OrdinalFormatter::capitalize]
+    pub fn capitalize(self) -> Self {
+        self.map(OrdinalFormatter::capitalize)
+    }
+
+    /// See [OrdinalFormatter::gender]
+    pub fn gender(self, gender: Gender) -> Self {
+        self.map(|f| f.gender(gender))
+    }
+
+    /// See [OrdinalFormatter::noun_class]
+    pub fn noun_class(self, class: NounClass) -> Self {
+        self.map(|f| f.noun_class(class))
+    }
+
+    /// See [OrdinalFormatter::definite]
+    pub fn definite(self) -> Self {
+        self.map(OrdinalFormatter::definite)
+    }
+
+    /// See [OrdinalFormatter::period]
+    pub fn period(self) -> Self {
+        self.map(OrdinalFormatter::period)
+    }
+
+    /// See [OrdinalFormatter::native_digits]
+    pub fn native_digits(self) -> Self {
+        self.map(OrdinalFormatter::native_digits)
+    }
+
+    /// See [OrdinalFormatter::adjectival]
+    pub fn adjectival(self) -> Self {
+        self.map(OrdinalFormatter::adjectival)
+    }
+}
+
+impl fmt::Display for Localized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.formatter.write(f, self.n)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn reuse() {
+        let formatter = OrdinalFormatter::new().words_up_to(3);
+        let s: Vec<String> = (1..=5)
+            .map(|n| formatter.fmt(O32::from1(n)).to_string())
+            .collect();
+        assert_eq!(s, ["first", "second", "third", "4th", "5th"]);
+
+        let mut s = String::new();
+        formatter.format_into(O8::from1(2), &mut s).unwrap();
+        formatter.format_into(O8::from1(200), &mut s).unwrap();
+        assert_eq!(s, "second200th");
+    }
+
+    const SNAPSHOT_VALUES: [u32; 6] = [3, 9, 10, 21, 101, 147];
+
+    fn snapshot(formatter: OrdinalFormatter) -> Vec<String> {
+        SNAPSHOT_VALUES
+            .iter()
+            .map(|&n| formatter.fmt(O32::from1(n)).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn ap_style() {
+        assert_eq!(
+            snapshot(OrdinalFormatter::ap_style()),
+            ["third", "ninth", "10th", "21st", "101st", "147th"]
+        );
+    }
+
+    #[test]
+    fn chicago_style() {
+        assert_eq!(
+            snapshot(OrdinalFormatter::chicago_style()),
+            ["third", "ninth", "tenth", "twenty-first", "101st", "147th"]
+        );
+        assert_eq!(
+            OrdinalFormatter::chicago_style()
+                .fmt(O32::from1(100))
+                .to_string(),
+            "one hundredth"
+        );
+    }
+
+    #[test]
+    fn overridden_presets() {
+        assert_eq!(
+            snapshot(
+                OrdinalFormatter::chicago_style()
+                    .words_up_to(200)
+                    .word_style(WordStyle::new().hyphenate(false).and(true))
+            ),
+            [
+                "third",
+                "ninth",
+                "tenth",
+                "twenty first",
+                "one hundred and first",
+                "one hundred and forty seventh"
+            ]
+        );
+        assert_eq!(
+            snapshot(OrdinalFormatter::ap_style().capitalize().grouping(',')),
+            ["Third", "Ninth", "10th", "21st", "101st", "147th"]
+        );
+    }
+
+    #[test]
+    fn grouping() {
+        let formatter = OrdinalFormatter::new().grouping(',');
+        assert_eq!(formatter.fmt(O32::from1(1)).to_string(), "1st");
+        assert_eq!(formatter.fmt(O32::from1(999)).to_string(), "999th");
+        assert_eq!(formatter.fmt(O32::from1(1000)).to_string(), "1,000th");
+        assert_eq!(
+            formatter.fmt(O64::from1(12_345_678)).to_string(),
+            "12,345,678th"
+        );
+        let formatter = formatter.grouping('\u{202f}').words_up_to(1000);
+        assert_eq!(
+            formatter.fmt(O32::from1(1000)).to_string(),
+            "one thousandth"
+        );
+        assert_eq!(
+            formatter.fmt(O32::from1(1001)).to_string(),
+            "1\u{202f}001st"
+        );
+    }
+
+    #[test]
+    fn casing() {
+        let o = O32::from1(21);
+        assert_eq!(o.display_in(Locale::En).uppercase().to_string(), "21ST");
+        assert_eq!(
+            o.display_in(Locale::En).words().uppercase().to_string(),
+            "TWENTY-FIRST"
+        );
+        assert_eq!(
+            o.display_in(Locale::En).words().capitalize().to_string(),
+            "Twenty-first"
+        );
+        assert_eq!(o.display_in(Locale::En).capitalize().to_string(), "21st");
+    }
+}
@@ -0,0 +1,220 @@
+use core::hash::{BuildHasher, Hash};
+use core::iter::{Enumerate, FusedIterator};
+
+use indexmap::{map, set, Equivalent, IndexMap, IndexSet};
+
+use crate::{Ordinal, Osize};
+
+/// Extension trait for [IndexMap], to access entries by their 1-based position
+/// in insertion order.
+///
+/// Note that removing an entry with [shift_remove_ordinal](Self::shift_remove_ordinal)
+/// (or `shift_remove`) moves all subsequent entries one position forward, and
+/// `swap_remove` moves the last entry to the removed position.
+///
+/// ```
+/// use indexmap::IndexMap;
+/// use num_ordinal::{ordinal, IndexMapExt};
+///
+/// let mut map = IndexMap::new();
+/// map.insert("a", 1);
+/// map.insert("b", 2);
+/// map.insert("c", 3);
+/// assert_eq!(map.get_ordinal_entry(ordinal!(second)), Some((&"b", &2)));
+/// assert_eq!(map.get_ordinal_index_of("c"), Some(ordinal!(third)));
+/// ```
+pub trait IndexMapExt<K, V> {
+    /// Returns the entry at the given position
+    fn get_ordinal_entry(&self, o: Osize) -> Option<(&K, &V)>;
+
+    /// Returns the position of the entry with the given key
+    fn get_ordinal_index_of<Q>(&self, key: &Q) -> Option<Osize>
+    where
+        Q: Hash + Equivalent<K> + ?Sized;
+
+    /// Removes the entry at the given position and returns it, moving all
+    /// subsequent entries one position forward
+    fn shift_remove_ordinal(&mut self, o: Osize) -> Option<(K, V)>;
+
+    /// Returns an iterator over the entries and their positions
+    fn iter_ordinals(&self) -> IterOrdinals<'_, K, V>;
+}
+
+impl<K, V, S> IndexMapExt<K, V> for IndexMap<K, V, S>
+where
+    S: BuildHasher,
+{
+    fn get_ordinal_entry(&self, o: Osize) -> Option<(&K, &V)> {
+        self.get_index(o.into0())
+    }
+
+    fn get_ordinal_index_of<Q>(&self, key: &Q) -> Option<Osize>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_index_of(key).map(Osize::from0)
+    }
+
+    fn shift_remove_ordinal(&mut self, o: Osize) -> Option<(K, V)> {
+        self.shift_remove_index(o.into0())
+    }
+
+    fn iter_ordinals(&self) -> IterOrdinals<'_, K, V> {
+        IterOrdinals {
+            iter: self.iter().enumerate(),
+        }
+    }
+}
+
+/// Extension trait for [IndexSet], to access values by their 1-based position
+/// in insertion order.
+///
+/// The same caveats about removal apply as for [IndexMapExt].
+pub trait IndexSetExt<T> {
+    /// Returns the value at the given position
+    fn get_ordinal(&self, o: Osize) -> Option<&T>;
+
+    /// Returns the position of the given value
+    fn get_ordinal_index_of<Q>(&self, value: &Q) -> Option<Osize>
+    where
+        Q: Hash + Equivalent<T> + ?Sized;
+
+    /// Removes the value at the given position and returns it, moving all
+    /// subsequent values one position forward
+    fn shift_remove_ordinal(&mut self, o: Osize) -> Option<T>;
+
+    /// Returns an iterator over the values and their positions
+    fn iter_ordinals(&self) -> SetIterOrdinals<'_, T>;
+}
+
+impl<T, S> IndexSetExt<T> for IndexSet<T, S>
+where
+    S: BuildHasher,
+{
+    fn get_ordinal(&self, o: Osize) -> Option<&T> {
+        self.get_index(o.into0())
+    }
+
+    fn get_ordinal_index_of<Q>(&self, value: &Q) -> Option<Osize>
+    where
+        Q: Hash + Equivalent<T> + ?Sized,
+    {
+        self.get_index_of(value).map(Osize::from0)
+    }
+
+    fn shift_remove_ordinal(&mut self, o: Osize) -> Option<T> {
+        self.shift_remove_index(o.into0())
+    }
+
+    fn iter_ordinals(&self) -> SetIterOrdinals<'_, T> {
+        SetIterOrdinals {
+            iter: self.iter().enumerate(),
+        }
+    }
+}
+
+/// Iterator returned by [IndexMapExt::iter_ordinals]
+#[derive(Debug, Clone)]
+pub struct IterOrdinals<'a, K, V> {
+    iter: Enumerate<map::Iter<'a, K, V>>,
+}
+
+impl<'a, K, V> Iterator for IterOrdinals<'a, K, V> {
+    type Item = (Osize, &'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(i, (k, v))| (Osize::from0(i), k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IterOrdinals<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|(i, (k, v))| (Osize::from0(i), k, v))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterOrdinals<'_, K, V> {}
+
+impl<K, V> FusedIterator for IterOrdinals<'_, K, V> {}
+
+/// Iterator returned by [IndexSetExt::iter_ordinals]
+#[derive(Debug, Clone)]
+pub struct SetIterOrdinals<'a, T> {
+    iter: Enumerate<set::Iter<'a, T>>,
+}
+
+impl<'a, T> Iterator for SetIterOrdinals<'a, T> {
+    type Item = (Osize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(i, t)| (Osize::from0(i), t))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for SetIterOrdinals<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(i, t)| (Osize::from0(i), t))
+    }
+}
+
+impl<T> ExactSizeIterator for SetIterOrdinals<'_, T> {}
+
+impl<T> FusedIterator for SetIterOrdinals<'_, T> {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn o(n: usize) -> Osize {
+        Osize::from1(n)
+    }
+
+    #[test]
+    fn map() {
+        let mut map: IndexMap<&str, i32> = vec![("a", 1), ("b", 2), ("c", 3)].into_iter().collect();
+        assert_eq!(map.get_ordinal_entry(o(1)), Some((&"a", &1)));
+        assert_eq!(map.get_ordinal_entry(o(4)), None);
+        assert_eq!(map.get_ordinal_index_of("b"), Some(o(2)));
+        assert_eq!(map.get_ordinal_index_of("x"), None);
+
+        let entries: Vec<_> = map.iter_ordinals().collect();
+        assert_eq!(
+            entries,
+            [(o(1), &"a", &1), (o(2), &"b", &2), (o(3), &"c", &3)]
+        );
+        assert_eq!(map.iter_ordinals().next_back(), Some((o(3), &"c", &3)));
+
+        // removing an entry shifts the positions of the subsequent entries
+        assert_eq!(map.shift_remove_ordinal(o(1)), Some(("a", 1)));
+        assert_eq!(map.get_ordinal_index_of("b"), Some(o(1)));
+        assert_eq!(map.get_ordinal_index_of("c"), Some(o(2)));
+        assert_eq!(map.shift_remove_ordinal(o(3)), None);
+
+        // swap_remove moves the last entry to the removed position
+        map.insert("d", 4);
+        map.swap_remove("b");
+        assert_eq!(map.get_ordinal_index_of("d"), Some(o(1)));
+    }
+
+    #[test]
+    fn set() {
+        let mut set: IndexSet<char> = "xyz".chars().collect();
+        assert_eq!(set.get_ordinal(o(3)), Some(&'z'));
+        assert_eq!(set.get_ordinal_index_of(&'y'), Some(o(2)));
+        assert_eq!(set.iter_ordinals().len(), 3);
+        assert_eq!(set.iter_ordinals().next_back(), Some((o(3), &'z')));
+
+        assert_eq!(set.shift_remove_ordinal(o(2)), Some('y'));
+        assert_eq!(set.get_ordinal_index_of(&'z'), Some(o(2)));
+    }
+}
@@ -0,0 +1,495 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::iter::{Enumerate, FusedIterator};
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use crate::{bulk, BulkConvertError};
+use crate::{Integer, Ordinal, Osize};
+
+/// Extension trait for iterators
+pub trait OrdinalIteratorExt: Iterator + Sized {
+    /// Returns an iterator that pairs every element with its 1-based position,
+    /// like [enumerate](Iterator::enumerate) but starting at the first ordinal.
+    ///
+    /// ```
+    /// use num_ordinal::{OrdinalIteratorExt, Osize};
+    ///
+    /// let lines = ["foo", "bar"];
+    /// let numbered: Vec<_> = lines
+    ///     .iter()
+    ///     .enumerate_ordinal::<Osize>()
+    ///     .map(|(o, line)| format!("{}: {}", o, line))
+    ///     .collect();
+    /// assert_eq!(numbered, ["first: foo", "second: bar"]);
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// The iterator panics if it yields more elements than the ordinal type can
+    /// represent, e.g. more than 255 elements with [O8](crate::O8).
+    fn enumerate_ordinal<O: Ordinal>(self) -> EnumerateOrdinal<Self, O> {
+        EnumerateOrdinal {
+            iter: self,
+            count: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the 1-based positions of the elements that
+    /// satisfy the predicate, in the order they are encountered.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalIteratorExt};
+    ///
+    /// let lines = ["foo", "bar", "baz"];
+    /// let matches: Vec<_> = lines.iter().ordinal_positions(|l| l.starts_with('b')).collect();
+    /// assert_eq!(matches, [ordinal!(second Osize), ordinal!(third)]);
+    /// ```
+    fn ordinal_positions<P>(self, predicate: P) -> OrdinalPositions<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        OrdinalPositions {
+            iter: self.enumerate(),
+            predicate,
+        }
+    }
+
+    /// Searches for an element that satisfies the predicate, and returns its
+    /// 1-based position, like [position](Iterator::position).
+    ///
+    /// Returns [None] if no element satisfies the predicate, or if the position
+    /// is too big for the ordinal type.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalIteratorExt, O32};
+    ///
+    /// let words = ["apple", "banana", "cherry"];
+    /// // "banana" is the second word
+    /// let o = words.iter().position_ordinal::<O32, _>(|w| w.starts_with('b'));
+    /// assert_eq!(o, Some(ordinal!(second)));
+    /// ```
+    fn position_ordinal<O, P>(&mut self, predicate: P) -> Option<O>
+    where
+        O: Ordinal,
+        P: FnMut(Self::Item) -> bool,
+    {
+        let i = self.position(predicate)?;
+        O::IntegerType::from_u128(i as u128).and_then(O::try_from0)
+    }
+
+    /// Searches for an element that satisfies the predicate from the back, and
+    /// returns its 1-based position from the front, like
+    /// [rposition](Iterator::rposition).
+    ///
+    /// Returns [None] if no element satisfies the predicate, or if the position
+    /// is too big for the ordinal type.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalIteratorExt, O32};
+    ///
+    /// let words = ["apple", "banana", "blueberry", "cherry"];
+    /// // the last word starting with "b" is the third word
+    /// let o = words.iter().rposition_ordinal::<O32, _>(|w| w.starts_with('b'));
+    /// assert_eq!(o, Some(ordinal!(third)));
+    /// ```
+    fn rposition_ordinal<O, P>(&mut self, predicate: P) -> Option<O>
+    where
+        Self: ExactSizeIterator + DoubleEndedIterator,
+        O: Ordinal,
+        P: FnMut(Self::Item) -> bool,
+    {
+        let i = self.rposition(predicate)?;
+        O::IntegerType::from_u128(i as u128).and_then(O::try_from0)
+    }
+
+    /// Returns the `o`-th element, like [nth](Iterator::nth) but with a 1-based
+    /// position, so `nth_ordinal(ordinal!(first))` returns the next element.
+    /// The elements before it are consumed.
+    ///
+    /// Returns [None] if the iterator has fewer elements, or if the position
+    /// is too big for a `usize`.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalIteratorExt};
+    ///
+    /// let mut words = ["apple", "banana", "cherry"].iter();
+    /// // the user asked for the 2nd word
+    /// assert_eq!(words.nth_ordinal(ordinal!(second O32)), Some(&"banana"));
+    /// // the first remaining word
+    /// assert_eq!(words.nth_ordinal(ordinal!(first O32)), Some(&"cherry"));
+    /// assert_eq!(words.nth_ordinal(ordinal!(first O32)), None);
+    /// ```
+    fn nth_ordinal<O: Ordinal>(&mut self, o: O) -> Option<Self::Item> {
+        let n = usize::try_from(o.into0().to_u128()).ok()?;
+        self.nth(n)
+    }
+
+    /// Returns an iterator over every `o`-th element, i.e. the `o`-th, the
+    /// `2o`-th, the `3o`-th and so on, paired with their 1-based positions.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalIteratorExt};
+    ///
+    /// let every_third: Vec<_> = "abcdefgh".chars().every_ordinal(ordinal!(third)).collect();
+    /// assert_eq!(every_third, [(ordinal!(third), 'c'), (ordinal!(6-th), 'f')]);
+    /// ```
+    fn every_ordinal(self, o: Osize) -> EveryOrdinal<Self> {
+        self.every_ordinal_from(o, o.into1())
+    }
+
+    /// Returns an iterator over every `step`-th element, starting at the
+    /// `start`-th element, paired with their 1-based positions.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalIteratorExt};
+    ///
+    /// let odd: Vec<_> = (10..16).every_ordinal_from(ordinal!(first), 2).collect();
+    /// assert_eq!(odd, [(ordinal!(first), 10), (ordinal!(third), 12), (ordinal!(5-th), 14)]);
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `step` is 0.
+    fn every_ordinal_from(self, start: Osize, step: usize) -> EveryOrdinal<Self> {
+        assert!(step != 0, "step must not be 0");
+        EveryOrdinal {
+            iter: self,
+            next: Some(start.into0()),
+            step,
+            started: false,
+        }
+    }
+
+    /// Converts all 1-based integers to ordinal numbers, and collects them.
+    ///
+    /// Returns an error for the first element that is 0, which contains the
+    /// index and the value of the element.
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, OrdinalIteratorExt, O16};
+    ///
+    /// let ordinals: Vec<O16> = "2,1".split(',').map(|s| s.parse().unwrap()).try_collect_ordinals()?;
+    /// assert_eq!(ordinals, [O16::from1(2), O16::from1(1)]);
+    /// # Ok::<(), num_ordinal::BulkConvertError<u16>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_collect_ordinals<O>(self) -> Result<Vec<O>, BulkConvertError<O::IntegerType>>
+    where
+        O: Ordinal<IntegerType = Self::Item>,
+        Self::Item: Copy,
+    {
+        bulk::convert(self, bulk::from1)
+    }
+}
+
+impl<I: Iterator> OrdinalIteratorExt for I {}
+
+/// Iterator returned by [OrdinalIteratorExt::enumerate_ordinal]
+#[derive(Debug, Clone)]
+pub struct EnumerateOrdinal<I, O> {
+    iter: I,
+    /// The 0-based position of the next element from the front
+    count: u128,
+    _marker: PhantomData<O>,
+}
+
+impl<I, O: Ordinal> EnumerateOrdinal<I, O> {
+    fn ordinal_at(&self, offset: usize) -> O {
+        self.count
+            .checked_add(offset as u128)
+            .and_then(O::IntegerType::from_u128)
+            .and_then(O::try_from0)
+            .expect("too many elements for this ordinal type")
+    }
+}
+
+impl<I: Iterator, O: Ordinal> Iterator for EnumerateOrdinal<I, O> {
+    type Item = (O, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let position = self.ordinal_at(0);
+        self.count += 1;
+        Some((position, item))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth(n)?;
+        let position = self.ordinal_at(n);
+        self.count += n as u128 + 1;
+        Some((position, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, O> DoubleEndedIterator for EnumerateOrdinal<I, O>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    O: Ordinal,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        Some((self.ordinal_at(self.iter.len()), item))
+    }
+}
+
+impl<I: ExactSizeIterator, O: Ordinal> ExactSizeIterator for EnumerateOrdinal<I, O> {}
+
+impl<I: FusedIterator, O: Ordinal> FusedIterator for EnumerateOrdinal<I, O> {}
+
+/// Iterator returned by [OrdinalIteratorExt::ordinal_positions]
+#[derive(Debug, Clone)]
+pub struct OrdinalPositions<I, P> {
+    iter: Enumerate<I>,
+    predicate: P,
+}
+
+impl<I, P> Iterator for OrdinalPositions<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = Osize;
+
+    fn next(&mut self) -> Option<Osize> {
+        let predicate = &mut self.predicate;
+        self.iter
+            .find(|(_, item)| predicate(item))
+            .map(|(i, _)| Osize::from0(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, P> FusedIterator for OrdinalPositions<I, P>
+where
+    I: FusedIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+}
+
+/// Iterator returned by [OrdinalIteratorExt::every_ordinal] and
+/// [OrdinalIteratorExt::every_ordinal_from]
+#[derive(Debug, Clone)]
+pub struct EveryOrdinal<I> {
+    iter: I,
+    /// The 0-based position of the next element, or [None] if it doesn't fit
+    /// into an [Osize]
+    next: Option<usize>,
+    step: usize,
+    started: bool,
+}
+
+impl<I> EveryOrdinal<I> {
+    /// The number of elements to skip before the element at `next`
+    fn to_skip(&self, next: usize) -> usize {
+        if self.started {
+            self.step - 1
+        } else {
+            next
+        }
+    }
+
+    /// The number of elements yielded from `n` remaining elements
+    fn remaining(&self, n: usize) -> usize {
+        let next = match self.next {
+            Some(next) => next,
+            None => return 0,
+        };
+        match n.checked_sub(self.to_skip(next)) {
+            Some(rest) if rest > 0 => 1 + (rest - 1) / self.step,
+            _ => 0,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for EveryOrdinal<I> {
+    type Item = (Osize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.next?;
+        let item = self.iter.nth(self.to_skip(next));
+        self.started = true;
+        self.next = next.checked_add(self.step).filter(|&n| n < usize::MAX);
+        item.map(|item| (Osize::from0(next), item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next.is_none() {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        (self.remaining(lower), upper.map(|n| self.remaining(n)))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for EveryOrdinal<I> {}
+
+impl<I: FusedIterator> FusedIterator for EveryOrdinal<I> {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn enumerate_ordinal() {
+        let items: Vec<_> = "abc".chars().enumerate_ordinal::<O32>().collect();
+        assert_eq!(
+            items,
+            [
+                (O32::from1(1), 'a'),
+                (O32::from1(2), 'b'),
+                (O32::from1(3), 'c')
+            ]
+        );
+
+        let mut iter = (10..20).enumerate_ordinal::<O8>();
+        assert_eq!(iter.len(), 10);
+        assert_eq!(iter.next_back(), Some((O8::from1(10), 19)));
+        assert_eq!(iter.nth(2), Some((O8::from1(3), 12)));
+        assert_eq!(iter.next(), Some((O8::from1(4), 13)));
+        assert_eq!(iter.next_back(), Some((O8::from1(9), 18)));
+        assert_eq!(iter.len(), 4);
+
+        let last = (0..255).enumerate_ordinal::<O8>().next_back();
+        assert_eq!(last, Some((O8::last(), 254)));
+        assert_eq!((0..0).enumerate_ordinal::<Osize>().next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "too many elements for this ordinal type")]
+    fn enumerate_ordinal_overflow() {
+        (0..256).enumerate_ordinal::<O8>().for_each(drop);
+    }
+
+    #[test]
+    fn ordinal_positions() {
+        let positions: Vec<_> = (10..20).ordinal_positions(|n| n % 3 == 0).collect();
+        assert_eq!(
+            positions,
+            [Osize::from1(3), Osize::from1(6), Osize::from1(9)]
+        );
+
+        let mut none = (0..10).ordinal_positions(|_| false);
+        assert_eq!(none.next(), None);
+
+        let first = (0..).ordinal_positions(|&n| n > 4).next();
+        assert_eq!(first, Some(Osize::from1(6)));
+    }
+
+    #[test]
+    fn position_ordinal() {
+        let mut iter = 10..20;
+        assert_eq!(iter.position_ordinal(|n| n == 10), Some(O8::first()));
+        assert_eq!(iter.position_ordinal(|n| n == 13), Some(O8::from1(3)));
+        assert_eq!(iter.position_ordinal::<O8, _>(|n| n == 10), None);
+        assert_eq!((0..0).position_ordinal::<Osize, _>(|_| true), None);
+
+        // the 256th element can't be represented as an O8
+        assert_eq!(
+            (0..300).position_ordinal::<O8, _>(|n| n == 254),
+            Some(O8::last())
+        );
+        assert_eq!((0..300).position_ordinal::<O8, _>(|n| n == 255), None);
+        assert_eq!(
+            (0..300).position_ordinal::<O16, _>(|n| n == 255),
+            Some(O16::from1(256))
+        );
+    }
+
+    #[test]
+    fn rposition_ordinal() {
+        let v = [1, 2, 1, 3];
+        assert_eq!(v.iter().rposition_ordinal(|&n| n == 1), Some(O32::from1(3)));
+        assert_eq!(v.iter().rposition_ordinal(|&n| n == 3), Some(O32::from1(4)));
+        assert_eq!(v.iter().rposition_ordinal::<O32, _>(|&n| n == 4), None);
+        assert_eq!([0; 0].iter().rposition_ordinal::<O32, _>(|_| true), None);
+        assert_eq!((0..300).rposition_ordinal::<O8, _>(|n| n == 299), None);
+        assert_eq!(
+            (0..300).rposition_ordinal::<O8, _>(|n| n == 0),
+            Some(O8::first())
+        );
+    }
+
+    #[test]
+    fn nth_ordinal() {
+        let mut iter = 10..20;
+        assert_eq!(iter.nth_ordinal(O8::first()), Some(10));
+        assert_eq!(iter.nth_ordinal(O8::from1(3)), Some(13));
+        assert_eq!(iter.nth_ordinal(O8::from1(7)), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!((0..0).nth_ordinal(Osize::first()), None);
+        assert_eq!((0..).nth_ordinal(O128::from1(1000)), Some(999));
+        assert_eq!((0..10).nth_ordinal(O128::last()), None);
+    }
+
+    #[test]
+    fn every_ordinal() {
+        let o = Osize::from1;
+        let items: Vec<_> = (1..=10).every_ordinal(o(3)).collect();
+        assert_eq!(items, [(o(3), 3), (o(6), 6), (o(9), 9)]);
+
+        let items: Vec<_> = (1..=10).every_ordinal(o(1)).map(|(_, n)| n).collect();
+        assert_eq!(items, (1..=10).collect::<Vec<_>>());
+
+        let items: Vec<_> = (1..=10).every_ordinal_from(o(2), 4).collect();
+        assert_eq!(items, [(o(2), 2), (o(6), 6), (o(10), 10)]);
+
+        assert_eq!((1..=10).every_ordinal(o(11)).next(), None);
+        assert_eq!((0..).every_ordinal(o(1000)).nth(2), Some((o(3000), 2999)));
+    }
+
+    #[test]
+    fn every_ordinal_size_hint() {
+        for len in 0..20 {
+            for start in 1..8 {
+                for step in 1..8 {
+                    let mut iter = (0..len).every_ordinal_from(Osize::from1(start), step);
+                    loop {
+                        let remaining = iter.clone().count();
+                        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+                        assert_eq!(iter.len(), remaining);
+                        if iter.next().is_none() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        let iter = (0..).every_ordinal(Osize::from1(2));
+        assert_eq!(iter.size_hint(), (usize::MAX / 2, None));
+    }
+
+    #[test]
+    fn every_ordinal_huge_step() {
+        let items: Vec<_> = (0..10)
+            .every_ordinal_from(Osize::first(), usize::MAX)
+            .collect();
+        assert_eq!(items, [(Osize::first(), 0)]);
+
+        let mut iter = (0..).every_ordinal_from(Osize::from1(3), usize::MAX - 1);
+        assert_eq!(iter.next(), Some((Osize::from1(3), 2)));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+
+        let iter = (0..10).every_ordinal_from(Osize::last(), usize::MAX);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn every_ordinal_zero_step() {
+        (0..10).every_ordinal_from(Osize::first(), 0);
+    }
+}
@@ -0,0 +1,139 @@
+use jiff::civil::{Date, Weekday};
+use jiff::Zoned;
+
+use crate::{Ordinal, O16, O8};
+
+/// Ordinal helpers for [jiff](https://docs.rs/jiff) dates
+///
+/// ```
+/// use jiff::civil::{date, Weekday};
+/// use num_ordinal::{ordinal, JiffDateExt};
+///
+/// let d = date(2024, 12, 31);
+/// assert_eq!(d.day_ordinal(), 31);
+/// assert_eq!(d.day_of_year_ordinal(), 366);
+///
+/// // the 4th Thursday of November 2024
+/// let thanksgiving = date(2024, 11, 1).ordinal_weekday_of_month(ordinal!(4-th), Weekday::Thursday);
+/// assert_eq!(thanksgiving, Some(date(2024, 11, 28)));
+/// ```
+pub trait JiffDateExt {
+    /// Returns the day of the month, from the 1st to the 31st
+    fn day_ordinal(&self) -> O8;
+
+    /// Returns the day of the year, from the 1st to the 366th
+    fn day_of_year_ordinal(&self) -> O16;
+
+    /// Returns the ISO 8601 week of the year, from the 1st to the 53rd.
+    ///
+    /// Note that the first days of January may belong to a week of the previous
+    /// year, and the last days of December to the 1st week of the next year.
+    fn iso_week_ordinal(&self) -> O8;
+
+    /// Returns the `nth` weekday in the month of this date, e.g. the 2nd Monday.
+    ///
+    /// Returns [None] if the month doesn't have that many of the weekday.
+    fn ordinal_weekday_of_month(&self, nth: O8, weekday: Weekday) -> Option<Date>;
+}
+
+impl JiffDateExt for Date {
+    fn day_ordinal(&self) -> O8 {
+        O8::from1(self.day() as u8)
+    }
+
+    fn day_of_year_ordinal(&self) -> O16 {
+        O16::from1(self.day_of_year() as u16)
+    }
+
+    fn iso_week_ordinal(&self) -> O8 {
+        O8::from1(self.iso_week_date().week() as u8)
+    }
+
+    fn ordinal_weekday_of_month(&self, nth: O8, weekday: Weekday) -> Option<Date> {
+        let first = self.first_of_month();
+        let offset = weekday.since(first.weekday()) as i16;
+        let day = 1 + offset + 7 * nth.into0() as i16;
+        if day > self.days_in_month() as i16 {
+            return None;
+        }
+        Date::new(self.year(), self.month(), day as i8).ok()
+    }
+}
+
+impl JiffDateExt for Zoned {
+    fn day_ordinal(&self) -> O8 {
+        self.date().day_ordinal()
+    }
+
+    fn day_of_year_ordinal(&self) -> O16 {
+        self.date().day_of_year_ordinal()
+    }
+
+    fn iso_week_ordinal(&self) -> O8 {
+        self.date().iso_week_ordinal()
+    }
+
+    fn ordinal_weekday_of_month(&self, nth: O8, weekday: Weekday) -> Option<Date> {
+        self.date().ordinal_weekday_of_month(nth, weekday)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use jiff::civil::date;
+
+    #[test]
+    fn days() {
+        assert_eq!(date(2023, 1, 1).day_ordinal(), O8::from1(1));
+        assert_eq!(date(2023, 1, 31).day_ordinal(), O8::from1(31));
+        assert_eq!(date(2023, 1, 1).day_of_year_ordinal(), O16::from1(1));
+        assert_eq!(date(2023, 12, 31).day_of_year_ordinal(), O16::from1(365));
+        assert_eq!(date(2024, 12, 31).day_of_year_ordinal(), O16::from1(366));
+        assert_eq!(date(2024, 3, 1).day_of_year_ordinal(), O16::from1(61));
+    }
+
+    #[test]
+    fn iso_weeks() {
+        assert_eq!(date(2024, 1, 1).iso_week_ordinal(), O8::from1(1));
+        assert_eq!(date(2021, 1, 3).iso_week_ordinal(), O8::from1(53));
+        assert_eq!(date(2020, 12, 31).iso_week_ordinal(), O8::from1(53));
+        assert_eq!(date(2024, 12, 30).iso_week_ordinal(), O8::from1(1));
+    }
+
+    #[test]
+    fn nth_weekday() {
+        let d = date(2024, 2, 15);
+        let nth = |n, weekday| d.ordinal_weekday_of_month(O8::from1(n), weekday);
+
+        // February 2024 starts on a Thursday and has 29 days
+        assert_eq!(nth(1, Weekday::Thursday), Some(date(2024, 2, 1)));
+        assert_eq!(nth(5, Weekday::Thursday), Some(date(2024, 2, 29)));
+        assert_eq!(nth(1, Weekday::Wednesday), Some(date(2024, 2, 7)));
+        assert_eq!(nth(4, Weekday::Wednesday), Some(date(2024, 2, 28)));
+        assert_eq!(nth(5, Weekday::Wednesday), None);
+        assert_eq!(nth(5, Weekday::Friday), None);
+        assert_eq!(nth(255, Weekday::Friday), None);
+
+        let d = date(2023, 2, 1);
+        assert_eq!(
+            d.ordinal_weekday_of_month(O8::from1(5), Weekday::Wednesday),
+            None
+        );
+    }
+
+    #[test]
+    fn zoned() {
+        let zdt = date(2024, 12, 31)
+            .at(23, 0, 0, 0)
+            .to_zoned(jiff::tz::TimeZone::UTC)
+            .unwrap();
+        assert_eq!(zdt.day_ordinal(), O8::from1(31));
+        assert_eq!(zdt.day_of_year_ordinal(), O16::from1(366));
+        assert_eq!(zdt.iso_week_ordinal(), O8::from1(1));
+        assert_eq!(
+            zdt.ordinal_weekday_of_month(O8::from1(1), Weekday::Tuesday),
+            Some(date(2024, 12, 3))
+        );
+    }
+}
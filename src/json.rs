@@ -0,0 +1,83 @@
+use alloc::string::ToString;
+use core::convert::TryFrom;
+
+use serde_json::{Number, Value};
+
+use crate::parse::{from1_u128, parse_numeric};
+use crate::*;
+
+/// Converts the ordinal to its 1-based number. Numbers that don't fit into a JSON
+/// number are converted to a string.
+impl<T: OrdinalInteger> From<O<T>> for Value {
+    fn from(o: O<T>) -> Self {
+        let n = o.into1().to_u128();
+        match Number::from_u128(n) {
+            Some(number) => Value::Number(number),
+            None => Value::String(n.to_string()),
+        }
+    }
+}
+
+/// Converts a 1-based JSON number, or a string such as `"4"` or `"4th"`
+impl<T: OrdinalInteger> TryFrom<&Value> for O<T> {
+    type Error = OrdinalError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Number(n) => match n.as_u128() {
+                Some(n) => from1_u128(n),
+                None if n.as_i64().is_some() => Err(OrdinalError::Negative),
+                None => Err(OrdinalError::Invalid),
+            },
+            Value::String(s) => from1_u128(parse_numeric(s)?),
+            _ => Err(OrdinalError::Invalid),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn to_json() {
+        assert_eq!(Value::from(O32::first()), json!(1));
+        assert_eq!(Value::from(O8::from1(255)), json!(255));
+        assert_eq!(Value::from(O64::from1(u64::MAX)), json!(u64::MAX));
+        assert_eq!(
+            Value::from(O128::from1(u128::MAX)),
+            json!(u128::MAX.to_string())
+        );
+    }
+
+    #[test]
+    fn from_json() {
+        assert_eq!(O32::try_from(&json!(4)), Ok(O32::from1(4)));
+        assert_eq!(O32::try_from(&json!("4")), Ok(O32::from1(4)));
+        assert_eq!(O32::try_from(&json!("4th")), Ok(O32::from1(4)));
+        assert_eq!(Osize::try_from(&json!("22nd")), Ok(Osize::from1(22)));
+
+        let big = json!(u64::MAX);
+        assert_eq!(O32::try_from(&big), Err(OrdinalError::Overflow));
+        assert_eq!(O64::try_from(&big), Ok(O64::from1(u64::MAX)));
+        assert_eq!(O128::try_from(&big), Ok(O128::from1(u64::MAX as u128)));
+
+        let big = Value::from(O128::from1(u128::MAX));
+        assert_eq!(O128::try_from(&big), Ok(O128::from1(u128::MAX)));
+    }
+
+    #[test]
+    fn invalid_json() {
+        assert_eq!(O8::try_from(&json!(null)), Err(OrdinalError::Invalid));
+        assert_eq!(O8::try_from(&json!(true)), Err(OrdinalError::Invalid));
+        assert_eq!(O8::try_from(&json!(4.5)), Err(OrdinalError::Invalid));
+        assert_eq!(O8::try_from(&json!(4.0)), Err(OrdinalError::Invalid));
+        assert_eq!(O8::try_from(&json!(-4)), Err(OrdinalError::Negative));
+        assert_eq!(O8::try_from(&json!(0)), Err(OrdinalError::Zero));
+        assert_eq!(O8::try_from(&json!("0th")), Err(OrdinalError::Zero));
+        assert_eq!(O8::try_from(&json!(256)), Err(OrdinalError::Overflow));
+        assert_eq!(O8::try_from(&json!("4rd")), Err(OrdinalError::Invalid));
+        assert_eq!(O8::try_from(&json!([4])), Err(OrdinalError::Invalid));
+    }
+}
@@ -70,52 +70,354 @@ use num_ordinal::ordinal;
 assert_eq!(ordinal!(5-th O32) - ordinal!(second O32), 3);
 ```
 
+Ordinals can also be compared with integers, which are **1-based** like in
+the conversions below. An ordinal is never equal to 0:
+
+```rust
+use num_ordinal::ordinal;
+
+assert!(ordinal!(third O32) == 3);
+assert!(ordinal!(first O32) < 2);
+assert!(ordinal!(first O32) > 0);
+```
+
 The default value is _first_.
 
+The standard conversion traits convert between ordinals and their integer types.
+They use **1-based** integers, like people write _the 3rd_; use
+[from0](Ordinal::from0) and [into0](Ordinal::into0) for 0-based integers:
+
+```rust
+use std::convert::TryFrom;
+use num_ordinal::{ordinal, O32};
+
+assert_eq!(u32::from(ordinal!(third O32)), 3);
+assert_eq!(O32::try_from(3u32), Ok(ordinal!(third)));
+assert!(O32::try_from(0u32).is_err());
+```
+
+Ordinals can be converted to wider ordinal types with `From`, e.g. `O8` to
+`Osize`, and to narrower ones with `TryFrom`, which fails with
+[TryFromOrdinalError] if the ordinal is too big.
+
+The ordinal types `O8`, `O16`, `O32`, `O64`, `O128` and `Osize` are aliases of
+the generic type [O], e.g. `O32` is `O<u32>`. Code can be generic over the
+integer type with an [OrdinalInteger] bound.
+
+Ordinals are stored as 1-based `NonZero*` integers, so an `Option` of an
+ordinal is as big as the ordinal itself:
+
+```rust
+use std::mem::size_of;
+use num_ordinal::O32;
+
+assert_eq!(size_of::<Option<O32>>(), size_of::<u32>());
+```
+
+Ordinals are formatted with a suffix, except for the first three, which are
+spelled out. The alternate flag spells out every ordinal:
+
+```rust
+use num_ordinal::ordinal;
+
+assert_eq!(format!("{}", ordinal!(21-st O32)), "21st");
+assert_eq!(format!("{:#}", ordinal!(21-st O32)), "twenty-first");
+```
+
+# Zeroth-inclusive ordinals
+
+The ordinal types above start at the _first_ position, so there is no _zeroth_.
+If you need it, for example in mathematical contexts, use the zeroth-inclusive
+types `Oz8`, `Oz16`, `Oz32`, `Oz64`, `Oz128` and `Ozsize` instead, which are aliases
+of the generic `Oz<T>`. They can be converted to the standard types and back, which
+fails for the zeroth position:
+
+```rust
+use num_ordinal::{ordinal, Oz32};
+
+let z = Oz32::zeroth();
+assert_eq!(&z.to_string(), "zeroth");
+assert_eq!(z.next(), Oz32::from(ordinal!(first O32)));
+```
+
+When in doubt, use the standard types: Most people start counting at the first.
+
+# Custom ordinal types
+
+The [Ordinal] trait can be implemented for your own types, e.g. a newtype around
+[O32]. Its [IntegerType](Ordinal::IntegerType) must implement the [Integer] trait,
+which is implemented for the unsigned integer types and can be implemented for your
+own integer types as well.
+
+**Breaking change:** In version 0.2, `IntegerType` only required `Copy` and
+`Display`. It now also requires [Integer], which the default methods of [Ordinal]
+use to compute with the 1-based number. Implementations with an unsigned integer
+type keep compiling; other integer types need an `Integer` implementation.
+
+[Integer] is public and not sealed, because implementing [Ordinal] requires naming
+an integer type that implements it. The [O] type, however, stores a `NonZero*`
+integer, which only exists for the primitive types. Its type parameter is bounded by
+[OrdinalInteger], whose supertrait `NonZeroInteger` maps an integer to its `NonZero*`
+counterpart. `NonZeroInteger` is sealed, so that no other type can back an `O`.
+
 # Features
 
-* `serde`: Implement `Serialize` and `Deserialize` for ordinals
+* `std` (enabled by default): Link the standard library. Without it, the crate is
+  `no_std`, and ordinals can still be formatted with `core::fmt`
+* `alloc` (enabled by `std`): Add the APIs that allocate, e.g. [`to_words`](Ordinal::to_words)
+  returning a `String`, and the extension traits of `Vec`, `BTreeMap` and `BTreeSet`
+* `serde`: Implement `Serialize` and `Deserialize` for ordinals, and add the
+  [`serde1`] module to serialize them as 1-based numbers
+* `schemars`: Implement `schemars::JsonSchema` for ordinals, matching their `serde`
+  representation
+* `serde-human-readable`: Serialize ordinals as strings like `"4th"` in human-readable
+  formats such as JSON. Integers are still accepted when deserializing
+* `sqlx`: Implement `sqlx::Type`, `Encode` and `Decode` for ordinals, which are stored
+  as 1-based numbers: `O8` as `SMALLINT`, `O16` and `O32` as `INTEGER`, and `O64` and
+  `Osize` as `BIGINT`
+* `bigint`: Add the arbitrary-precision `OBig` type
+* `bytemuck`: Implement `bytemuck::NoUninit` and `CheckedBitPattern` for ordinals, and
+  add zero-copy casts between ordinal slices and their 1-based integers in the `cast` module
+* `defmt`: Implement `defmt::Format` for ordinals, for logging on embedded targets
+* `ffi`: Add C-compatible functions in the `ffi` module
+* `fluent`: Add an `ORDINAL` function for [Fluent](https://projectfluent.org/) bundles
+* `json`: Implement conversions to and from `serde_json::Value`
+* `arbitrary`: Implement `arbitrary::Arbitrary` for ordinals, for fuzzing
+* `num-traits`: Implement `Bounded`, `ToPrimitive` and `FromPrimitive` for ordinals,
+  which convert 1-based numbers
+* `proptest`: Implement `proptest::arbitrary::Arbitrary` for ordinals, so `any::<O32>()` works
+* `rand`: Sample random ordinals with `rng.gen()`, or from a range with `rng.gen_range()`
+* `jiff`: Ordinal helpers for dates of the `jiff` crate
+* `indexmap`: Access entries of `IndexMap` and `IndexSet` by their ordinal position
+* `auto-locale`: Detect the locale of the operating system for [`display_localized`](Ordinal::display_localized)
+* `locale-all`: Enable all locales for [`display_in`](Ordinal::display_in)
+
+Each locale only adds its own word tables to the binary, so a build with a single
+locale is noticeably smaller than one with `locale-all`. The `Locale binary size` CI
+workflow compares the stripped size of the `locale_size` example with `locale-de` and
+with `locale-all` on every push, and reports both sizes in its job summary.
+
+# Minimum supported Rust version
+
+The minimum supported Rust version is 1.81, which is needed for `core::error::Error`
+in `no_std` builds. Raising it is considered a breaking change. Optional features
+that integrate with other crates, e.g. `sqlx` or `proptest`, may require the newer
+Rust version of those crates.
 
 # License
 
 MIT
 */
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+#[cfg(feature = "bigint")]
+mod bigint;
+#[cfg(feature = "alloc")]
+mod btree;
+#[cfg(feature = "alloc")]
+mod bulk;
+pub mod calendar;
+#[cfg(feature = "bytemuck")]
+pub mod cast;
+mod const_parse;
+mod convert;
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+mod error;
+pub mod ffi;
+mod float;
+#[cfg(feature = "fluent")]
+mod fluent_impl;
+mod formatter;
+#[cfg(feature = "indexmap")]
+mod indexmap_impl;
+mod iter;
+#[cfg(feature = "jiff")]
+mod jiff_impl;
+#[cfg(feature = "json")]
+mod json;
+mod locale;
+mod markup;
+#[cfg(feature = "alloc")]
+mod natural;
+#[cfg(feature = "num-traits")]
+mod num_traits_impl;
+mod pad;
+mod parse;
+mod percentile;
+pub mod periods;
+mod placement;
+#[cfg(feature = "proptest")]
+mod proptest_impl;
+#[cfg(feature = "rand")]
+mod rand_impl;
+mod range;
+#[cfg(feature = "alloc")]
+pub mod ranking;
+mod scan;
+#[cfg(feature = "schemars")]
+mod schemars_impl;
+#[cfg(feature = "serde")]
+pub mod serde1;
 #[cfg(feature = "serde")]
 mod serde_impl;
+mod sigil;
+mod slice;
+#[cfg(feature = "sqlx")]
+mod sqlx_impl;
+mod suffix;
+mod table;
+mod words;
+mod zeroth;
+
+#[cfg(feature = "bigint")]
+pub use bigint::OBig;
+#[cfg(feature = "alloc")]
+pub use btree::{BTreeMapExt, BTreeSetExt};
+#[cfg(feature = "alloc")]
+pub use bulk::BulkConvertError;
+#[doc(hidden)]
+pub use const_parse::{__ConstFrom1, __OrdinalStr, __ordinal_literal, __parse_ordinal_str};
+pub use convert::TryFromOrdinalError;
+pub use error::OrdinalError;
+pub use float::Rounding;
+#[cfg(feature = "fluent")]
+pub use fluent_impl::{add_ordinal_function, ordinal_function};
+pub use formatter::{Localized, OrdinalFormatter};
+#[cfg(feature = "indexmap")]
+pub use indexmap_impl::{IndexMapExt, IndexSetExt, IterOrdinals, SetIterOrdinals};
+pub use iter::{EnumerateOrdinal, EveryOrdinal, OrdinalIteratorExt, OrdinalPositions};
+#[cfg(feature = "jiff")]
+pub use jiff_impl::JiffDateExt;
+pub use locale::{Gender, Locale, NounClass, OrdinalCategory};
+pub use markup::Superscript;
+#[cfg(feature = "alloc")]
+pub use natural::{compare_ordinal_aware, ordinal_sort_key, OrdinalSortKey};
+pub use parse::ParseOrdinalError;
+pub use percentile::{PercentileError, PercentileRounding};
+pub use placement::{Placement, TieStyle};
+#[cfg(feature = "rand")]
+pub use rand_impl::UniformOrdinal;
+pub use range::{
+    range, range_inclusive, OrdinalRange, OrdinalRangeInclusive, RangeIter, SplitChunks, SplitInto,
+};
+pub use scan::{find_ordinals, find_ordinals_with, FindOptions, FindOrdinals};
+pub use sigil::{Numbered, SigilStyle};
+pub use slice::OrdinalSliceExt;
+pub use suffix::SuffixStyle;
+#[cfg(feature = "alloc")]
+pub use words::cardinal_words;
+pub use words::WordStyle;
+pub use zeroth::{Oz, Oz128, Oz16, Oz32, Oz64, Oz8, Ozsize};
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, vec::Vec};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::str::FromStr;
+
+use sealed::NonZeroInteger;
+
+/// Integer types that can be the [IntegerType](Ordinal::IntegerType) of an
+/// [Ordinal]. It is implemented for [u8], [u16], [u32], [u64], [u128] and [usize],
+/// and can be implemented for other integer types whose values fit into a [u128].
+pub trait Integer: Copy {
+    /// The highest value of this type
+    const MAX: Self;
+
+    /// The value 1
+    const ONE: Self;
+
+    /// Converts this integer to a [u128]
+    fn to_u128(self) -> u128;
+
+    /// Converts a [u128] to this type, or returns [None] if it is too big
+    fn from_u128(n: u128) -> Option<Self>;
+
+    /// Adds 1, or returns [None] if the result is too big for this type
+    fn checked_inc(self) -> Option<Self> {
+        self.to_u128().checked_add(1).and_then(Self::from_u128)
+    }
+}
 
-use std::fmt;
-use std::ops::{Add, Sub};
+mod sealed {
+    /// Integer types that have a `NonZero*` counterpart, so they can back an [O](crate::O)
+    pub trait NonZeroInteger: crate::Integer {
+        /// The `NonZero*` type with the same size, which stores the 1-based number
+        type NonZero: Copy + Eq + Ord;
 
-/// [Ordinal] number represented by [usize]
-#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default)]
+        fn to_nonzero(self) -> Option<Self::NonZero>;
+
+        fn from_nonzero(n: Self::NonZero) -> Self;
+    }
+}
+
+/// Integer types that can back an ordinal number of type [O], i.e. [u8], [u16],
+/// [u32], [u64], [u128] and [usize]. This trait is sealed, so it can't be
+/// implemented for other types.
+pub trait OrdinalInteger:
+    NonZeroInteger
+    + Eq
+    + Ord
+    + Hash
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+{
+}
+
+/// [Ordinal] number represented by the integer type `T`
+///
+/// This type is usually used through its aliases [O8], [O16], [O32], [O64],
+/// [O128] and [Osize]. Since it is generic, code can be generic over the integer
+/// type:
+///
+/// ```
+/// use num_ordinal::{ordinal, O, OrdinalInteger, OrdinalSliceExt, Ordinal};
+///
+/// fn nth<T: OrdinalInteger>(items: &[char], o: O<T>) -> Option<char> {
+///     items.get_ordinal(o.cast()?).copied()
+/// }
+///
+/// let items = ['a', 'b', 'c'];
+/// assert_eq!(nth(&items, ordinal!(second O8)), Some('b'));
+/// assert_eq!(nth(&items, ordinal!(third O64)), Some('c'));
+/// assert_eq!(nth(&items, ordinal!(4-th O128)), None);
+/// ```
+///
+/// The ordinal number is stored as a 1-based `NonZero*` integer.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
 #[repr(transparent)]
-pub struct Osize(usize);
+pub struct O<T: OrdinalInteger>(T::NonZero);
+
+/// [Ordinal] number represented by [usize]
+pub type Osize = O<usize>;
 
 /// [Ordinal] number represented by [u128]
-#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default)]
-#[repr(transparent)]
-pub struct O128(u128);
+pub type O128 = O<u128>;
 
 /// [Ordinal] number represented by [u64]
-#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default)]
-#[repr(transparent)]
-pub struct O64(u64);
+pub type O64 = O<u64>;
 
 /// [Ordinal] number represented by [u32]
-#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default)]
-#[repr(transparent)]
-pub struct O32(u32);
+pub type O32 = O<u32>;
 
 /// [Ordinal] number represented by [u16]
-#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default)]
-#[repr(transparent)]
-pub struct O16(u16);
+pub type O16 = O<u16>;
 
 /// [Ordinal] number represented by [u8]
-#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default)]
-#[repr(transparent)]
-pub struct O8(u8);
+pub type O8 = O<u8>;
 
 /// An ordinal number type
 ///
@@ -126,7 +428,7 @@ pub trait Ordinal:
     + PartialEq
     + Ord
     + PartialOrd
-    + std::hash::Hash
+    + Hash
     + Clone
     + Copy
     + Default
@@ -134,7 +436,7 @@ pub trait Ordinal:
     + fmt::Debug
 {
     /// This type by which this ordinal type is represented
-    type IntegerType: Copy + fmt::Display;
+    type IntegerType: Copy + fmt::Display + Integer;
 
     /// The first ordinal number
     fn first() -> Self;
@@ -142,6 +444,72 @@ pub trait Ordinal:
     /// Computes the ordinal number that comes after this one
     fn next(self) -> Self;
 
+    /// The last ordinal number that this type can represent. Since the highest
+    /// number of the integer type can't be converted to a 0-based ordinal, this
+    /// is e.g. the 255th for [O8].
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, O8};
+    ///
+    /// assert_eq!(O8::last().into1(), 255);
+    /// ```
+    fn last() -> Self {
+        let last = Self::IntegerType::MAX.to_u128() - 1;
+        Self::from0(Self::IntegerType::from_u128(last).expect("last ordinal is in range"))
+    }
+
+    /// Returns the range from this ordinal number to `end`, both inclusive, which
+    /// can be iterated over.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, Ordinal, Osize};
+    ///
+    /// let tenth: Osize = ordinal!(10-th);
+    /// assert_eq!(Osize::first().to(tenth).into_iter().count(), 10);
+    /// ```
+    fn to(self, end: Self) -> OrdinalRangeInclusive<Self> {
+        OrdinalRangeInclusive::new(self, end)
+    }
+
+    /// Converts this ordinal number to another ordinal type.
+    ///
+    /// Returns [None] if it is too big for the other type. Conversions between the
+    /// built-in types are also available with `From` and `TryFrom`.
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, O16, O8};
+    ///
+    /// assert_eq!(O16::from1(255).cast::<O8>(), Some(O8::from1(255)));
+    /// assert_eq!(O16::from1(256).cast::<O8>(), None);
+    /// ```
+    fn cast<O: Ordinal>(self) -> Option<O> {
+        O::IntegerType::from_u128(self.into1().to_u128()).and_then(O::try_from1)
+    }
+
+    /// Computes the ordinal number that comes before this one.
+    ///
+    /// Returns [None] if this is the [first](Ordinal::first) ordinal number.
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, O8};
+    ///
+    /// assert_eq!(O8::from1(2).try_prev(), Some(O8::first()));
+    /// assert_eq!(O8::first().try_prev(), None);
+    /// ```
+    fn try_prev(self) -> Option<Self> {
+        self.checked_sub(Self::IntegerType::from_u128(1)?)
+    }
+
+    /// Computes the ordinal number that comes before this one
+    ///
+    /// ### Panics
+    ///
+    /// Panics if this is the [first](Ordinal::first) ordinal number.
+    fn prev(self) -> Self {
+        self.try_prev()
+            .expect("the first ordinal has no previous ordinal")
+    }
+
     /// Returns the equivalent integer assuming the ordinal number is 0-based
     fn into0(self) -> Self::IntegerType;
 
@@ -177,197 +545,1362 @@ pub trait Ordinal:
     fn from1(t: Self::IntegerType) -> Self {
         Self::try_from1(t).expect("0 is not a valid 1-based ordinal.")
     }
-}
-
-macro_rules! impl_ordinal {
-    ($t:ident, $int:ident) => {
-        impl Ordinal for $t {
-            type IntegerType = $int;
-
-            fn first() -> Self {
-                Self(0)
-            }
-
-            fn next(self) -> Self {
-                Self::from0(self.0 + 1)
-            }
 
-            fn into0(self) -> Self::IntegerType {
-                self.0
-            }
-
-            fn into1(self) -> Self::IntegerType {
-                self.0 + 1
-            }
+    /// Converts an integer to a 0-based ordinal number.
+    ///
+    /// Returns [OrdinalError::Overflow] if the provided number is the highest number
+    /// of that integer type. Unlike [try_from0](Ordinal::try_from0), this can be used
+    /// with the `?` operator in functions returning a `Result`.
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, OrdinalError, O8};
+    ///
+    /// assert_eq!(O8::from0_checked(0), Ok(O8::first()));
+    /// assert_eq!(O8::from0_checked(255), Err(OrdinalError::Overflow));
+    /// ```
+    fn from0_checked(t: Self::IntegerType) -> Result<Self, OrdinalError> {
+        Self::try_from0(t).ok_or(OrdinalError::Overflow)
+    }
 
-            fn try_from0(t: Self::IntegerType) -> Option<Self> {
-                match t {
-                    $int::MAX => None,
-                    _ => Some($t(t)),
-                }
-            }
+    /// Converts an integer to a 1-based ordinal number.
+    ///
+    /// Returns [OrdinalError::Zero] if the provided number is 0. Unlike
+    /// [try_from1](Ordinal::try_from1), this can be used with the `?` operator in
+    /// functions returning a `Result`.
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, OrdinalError, O8};
+    ///
+    /// assert_eq!(O8::from1_checked(1), Ok(O8::first()));
+    /// assert_eq!(O8::from1_checked(0), Err(OrdinalError::Zero));
+    /// ```
+    fn from1_checked(t: Self::IntegerType) -> Result<Self, OrdinalError> {
+        Self::try_from1(t).ok_or(OrdinalError::Zero)
+    }
 
-            fn try_from1(t: Self::IntegerType) -> Option<Self> {
-                t.checked_sub(1).map($t)
-            }
+    /// Converts a signed integer to a 0-based ordinal number.
+    ///
+    /// Returns an error if the number is negative, or if it is too big for this
+    /// ordinal type.
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, OrdinalError, O8};
+    ///
+    /// assert_eq!(O8::try_from0_signed(0), Ok(O8::first()));
+    /// assert_eq!(O8::try_from0_signed(-1), Err(OrdinalError::Negative));
+    /// assert_eq!(O8::try_from0_signed(255), Err(OrdinalError::Overflow));
+    /// ```
+    fn try_from0_signed<I: Into<i128>>(n: I) -> Result<Self, OrdinalError> {
+        let n = n.into();
+        if n < 0 {
+            return Err(OrdinalError::Negative);
         }
+        Self::IntegerType::from_u128(n as u128)
+            .and_then(Self::try_from0)
+            .ok_or(OrdinalError::Overflow)
+    }
 
-        impl fmt::Debug for $t {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                match self.0 + 1 {
-                    1 => write!(f, "first"),
-                    2 => write!(f, "second"),
-                    3 => write!(f, "third"),
-                    n => {
-                        let two_digits = n % 100;
-                        let digit = two_digits % 10;
-                        if digit == 1 && two_digits != 11 {
-                            write!(f, "{}st", n)
-                        } else if digit == 2 && two_digits != 12 {
-                            write!(f, "{}nd", n)
-                        } else if digit == 3 && two_digits != 13 {
-                            write!(f, "{}rd", n)
-                        } else {
-                            write!(f, "{}th", n)
-                        }
-                    }
-                }
-            }
+    /// Converts a signed integer to a 1-based ordinal number.
+    ///
+    /// Returns an error if the number is negative or zero, or if it is too big for
+    /// this ordinal type.
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, OrdinalError, O8};
+    ///
+    /// assert_eq!(O8::try_from1_signed(1), Ok(O8::first()));
+    /// assert_eq!(O8::try_from1_signed(0), Err(OrdinalError::Zero));
+    /// assert_eq!(O8::try_from1_signed(-1), Err(OrdinalError::Negative));
+    /// assert_eq!(O8::try_from1_signed(256), Err(OrdinalError::Overflow));
+    /// ```
+    fn try_from1_signed<I: Into<i128>>(n: I) -> Result<Self, OrdinalError> {
+        match n.into() {
+            n if n < 0 => Err(OrdinalError::Negative),
+            0 => Err(OrdinalError::Zero),
+            n => Self::IntegerType::from_u128(n as u128)
+                .and_then(Self::try_from1)
+                .ok_or(OrdinalError::Overflow),
         }
+    }
 
-        impl fmt::Display for $t {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(f, "{:?}", self)
-            }
-        }
+    /// Converts a 1-based floating-point number to an ordinal number.
+    ///
+    /// Returns an error if the number is NaN, infinite, negative, zero, has a
+    /// fractional part, or is too big for this ordinal type. Numbers greater
+    /// than `2^53 - 1` are rejected as well, because they may have been rounded
+    /// when they were converted to `f64`, so they might not be what was intended.
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, OrdinalError, O32};
+    ///
+    /// assert_eq!(O32::try_from1_f64(4.0), Ok(O32::from1(4)));
+    /// assert_eq!(O32::try_from1_f64(4.5), Err(OrdinalError::Fractional));
+    /// assert_eq!(O32::try_from1_f64(0.0), Err(OrdinalError::Zero));
+    /// ```
+    fn try_from1_f64(x: f64) -> Result<Self, OrdinalError> {
+        float::from1_f64(x, None)
+    }
 
-        impl Add<$int> for $t {
-            type Output = $t;
+    /// Converts a 1-based floating-point number to an ordinal number, after
+    /// rounding it with the given rounding mode.
+    ///
+    /// Returns the same errors as [try_from1_f64](Ordinal::try_from1_f64), except
+    /// that numbers with a fractional part are accepted. Note that numbers that
+    /// are rounded to zero are rejected.
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, OrdinalError, Rounding, O32};
+    ///
+    /// assert_eq!(O32::try_from1_f64_rounded(4.5, Rounding::Floor), Ok(O32::from1(4)));
+    /// assert_eq!(O32::try_from1_f64_rounded(0.4, Rounding::Nearest), Err(OrdinalError::Zero));
+    /// ```
+    fn try_from1_f64_rounded(x: f64, rounding: Rounding) -> Result<Self, OrdinalError> {
+        float::from1_f64(x, Some(rounding))
+    }
 
-            fn add(self, rhs: $int) -> Self::Output {
-                Self::from0(self.0 + rhs)
-            }
-        }
+    /// Returns the equivalent 1-based floating-point number.
+    ///
+    /// Ordinals greater than `2^53` may lose precision, because `f64` can't
+    /// represent all integers in this range. They are rounded to the nearest
+    /// representable number.
+    fn to_f64_lossy(self) -> f64 {
+        self.into1().to_u128() as f64
+    }
 
-        impl Sub<$int> for $t {
-            type Output = $t;
+    /// Returns `true` if the 1-based position is a multiple of `n`, i.e. if this is
+    /// the `n`-th, `2n`-th, `3n`-th, ... ordinal number.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, Ordinal};
+    ///
+    /// assert!(ordinal!(10-th O32).is_every(5));
+    /// assert!(!ordinal!(first O32).is_every(5));
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `n` is 0.
+    fn is_every(self, n: Self::IntegerType) -> bool {
+        let n = n.to_u128();
+        assert!(n != 0, "is_every(0) is not meaningful");
+        self.into1().to_u128() % n == 0
+    }
 
-            fn sub(self, rhs: $int) -> Self::Output {
-                Self::from0(self.0 - rhs)
-            }
-        }
+    /// Returns `true` for the 1st, 3rd, 5th, ... ordinal number
+    fn is_odd_position(self) -> bool {
+        self.into1().to_u128() % 2 == 1
+    }
 
-        impl Sub<$t> for $t {
-            type Output = $int;
+    /// Returns `true` for the 2nd, 4th, 6th, ... ordinal number
+    fn is_even_position(self) -> bool {
+        self.into1().to_u128() % 2 == 0
+    }
 
-            fn sub(self, rhs: $t) -> Self::Output {
-                self.0 - rhs.0
-            }
-        }
-    };
-}
+    /// Returns the smallest ordinal number that is greater than or equal to this one
+    /// and whose 1-based position is a multiple of `n`. If this ordinal is already a
+    /// multiple of `n`, it is returned unchanged.
+    ///
+    /// Returns [None] if the result is too big for this ordinal type.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, Ordinal, O8};
+    ///
+    /// assert_eq!(ordinal!(7-th O8).next_multiple_of(5), Some(ordinal!(10-th)));
+    /// assert_eq!(ordinal!(10-th O8).next_multiple_of(5), Some(ordinal!(10-th)));
+    /// assert_eq!(ordinal!(251-st O8).next_multiple_of(10), None);
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `n` is 0.
+    fn next_multiple_of(self, n: Self::IntegerType) -> Option<Self> {
+        let n = n.to_u128();
+        assert!(n != 0, "next_multiple_of(0) is not meaningful");
+        let position = self.into1().to_u128();
+        let next = match position % n {
+            0 => position,
+            rest => position.checked_add(n - rest)?,
+        };
+        Self::IntegerType::from_u128(next).and_then(Self::try_from1)
+    }
 
-impl_ordinal!(Osize, usize);
-impl_ordinal!(O128, u128);
-impl_ordinal!(O64, u64);
-impl_ordinal!(O32, u32);
-impl_ordinal!(O16, u16);
-impl_ordinal!(O8, u8);
+    /// Adds a `usize` count to this ordinal number, e.g. the length of a collection.
+    ///
+    /// Returns [None] if the result is too big for this ordinal type, which also
+    /// happens if `n` doesn't fit into the integer type of this ordinal type.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, Ordinal, O8};
+    ///
+    /// let o = ordinal!(first O8);
+    /// assert_eq!(o.try_add_usize(3), Some(ordinal!(4-th)));
+    /// assert_eq!(o.try_add_usize(1000), None);
+    /// ```
+    fn try_add_usize(self, n: usize) -> Option<Self> {
+        let n0 = self.into0().to_u128().checked_add(n as u128)?;
+        Self::IntegerType::from_u128(n0).and_then(Self::try_from0)
+    }
 
-/// Creates a 1-based ordinal number. For example, `ordinal1(4)` is the 4th ordinal number.
-pub fn ordinal1<O: Ordinal>(n: O::IntegerType) -> O {
-    O::from1(n)
-}
+    /// Subtracts a `usize` count from this ordinal number.
+    ///
+    /// Returns [None] if the result would be before the first ordinal number.
+    fn try_sub_usize(self, n: usize) -> Option<Self> {
+        let n0 = self.into0().to_u128().checked_sub(n as u128)?;
+        Self::IntegerType::from_u128(n0).and_then(Self::try_from0)
+    }
 
-/// Creates a 0-based ordinal number. For example, `ordinal0(4)` is the 5th ordinal number.
-pub fn ordinal0<O: Ordinal>(n: O::IntegerType) -> O {
-    O::from0(n)
-}
+    /// Computes the ordinal number that comes after this one.
+    ///
+    /// Returns [None] if this is the [last](Ordinal::last) ordinal number.
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, O8};
+    ///
+    /// assert_eq!(O8::from1(254).try_next(), Some(O8::from1(255)));
+    /// assert_eq!(O8::from1(255).try_next(), None);
+    /// ```
+    fn try_next(self) -> Option<Self> {
+        self.checked_add(Self::IntegerType::from_u128(1)?)
+    }
 
-/// Creates a 1-based ordinal number. Examples:
-///
-/// ```
-/// use num_ordinal::{O32, ordinal};
-///
-/// let mut o: O32 = ordinal!(first);
-/// o = ordinal!(second);
-/// o = ordinal!(third);
-///
-/// // Other numbers must use the following syntax:
-/// o = ordinal!(4-th);
-/// // the dash can be omitted, but then a space is required to make the Rust parser happy:
-/// o = ordinal!(4 th);
-/// // alternatively, a dot can be written after the number:
-/// o = ordinal!(4 .);
-///
-/// // When necessary, the type can be ascribed:
-/// let o = ordinal!(5-th O32);
-/// ```
-///
-/// Note that only `first`, `second` and `third` can be written as a full word:
-///
-/// ```compile_fail
-/// use num_ordinal::{O32, ordinal};
-///
-/// // doesn't compile!
-/// let _: O32 = ordinal!(fifth);
-/// ```
-#[macro_export]
-macro_rules! ordinal {
-    (first $($ty:ident)?) => {
-        $crate::ordinal1 $(::<$crate::$ty>)? (1)
-    };
-    (second $($ty:ident)?) => {
-        $crate::ordinal1 $(::<$crate::$ty>)? (2)
-    };
-    (third $($ty:ident)?) => {
-        $crate::ordinal1 $(::<$crate::$ty>)? (3)
-    };
-    ($n:literal $(-)? $suffix:ident $($ty:ident)?) => {
-        $crate::ordinal1 $(::<$crate::$ty>)? ($n)
-    };
-    ($n:literal . $($ty:ident)?) => {
-        $crate::ordinal1 $(::<$crate::$ty>)? ($n)
-    };
-}
+    /// Adds an integer to this ordinal number.
+    ///
+    /// Returns [None] if the result is too big for this ordinal type. Since the
+    /// highest number of the integer type can't be converted to a 0-based ordinal,
+    /// the last ordinal number of e.g. [O8] is the 255th, so
+    /// `O8::from0(254).checked_add(1)` is [None].
+    fn checked_add(self, rhs: Self::IntegerType) -> Option<Self> {
+        let n0 = self.into0().to_u128().checked_add(rhs.to_u128())?;
+        Self::IntegerType::from_u128(n0).and_then(Self::try_from0)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Subtracts an integer from this ordinal number.
+    ///
+    /// Returns [None] if the result would be before the first ordinal number.
+    fn checked_sub(self, rhs: Self::IntegerType) -> Option<Self> {
+        let n0 = self.into0().to_u128().checked_sub(rhs.to_u128())?;
+        Self::IntegerType::from_u128(n0).and_then(Self::try_from0)
+    }
 
-    #[test]
-    fn first_from0() {
-        let first_o_from0 = Osize::from0(0);
+    /// Returns the distance from `other` to this ordinal number, i.e. `self - other`.
+    ///
+    /// Returns [None] if `other` is greater than this ordinal number.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, Ordinal};
+    ///
+    /// assert_eq!(ordinal!(5-th O32).checked_distance(ordinal!(second)), Some(3));
+    /// assert_eq!(ordinal!(second O32).checked_distance(ordinal!(5-th)), None);
+    /// ```
+    fn checked_distance(self, other: Self) -> Option<Self::IntegerType> {
+        let n = self
+            .into1()
+            .to_u128()
+            .checked_sub(other.into1().to_u128())?;
+        Self::IntegerType::from_u128(n)
+    }
 
-        assert_eq!(&first_o_from0.to_string(), "first");
+    /// Returns the absolute distance between two ordinal numbers.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, Ordinal};
+    ///
+    /// assert_eq!(ordinal!(5-th O32).abs_diff(ordinal!(second)), 3);
+    /// assert_eq!(ordinal!(second O32).abs_diff(ordinal!(5-th)), 3);
+    /// ```
+    fn abs_diff(self, other: Self) -> Self::IntegerType {
+        let n = self.into1().to_u128().abs_diff(other.into1().to_u128());
+        Self::IntegerType::from_u128(n).expect("distance is smaller than the ordinals")
     }
 
-    #[test]
-    fn first_from1() {
-        let first_o_from1 = Osize::from1(1);
-        assert_eq!(&first_o_from1.to_string(), "first");
+    /// Adds an integer to this ordinal number, returning the [last](Ordinal::last)
+    /// ordinal number if the result is too big.
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, O8};
+    ///
+    /// assert_eq!(O8::from1(250).saturating_add(3), O8::from1(253));
+    /// assert_eq!(O8::from1(250).saturating_add(100), O8::from1(255));
+    /// ```
+    fn saturating_add(self, rhs: Self::IntegerType) -> Self {
+        self.checked_add(rhs).unwrap_or_else(Self::last)
     }
 
-    #[test]
-    fn second_from0() {
-        let second_o_from0 = Osize::from0(1);
-        assert_eq!(&second_o_from0.to_string(), "second");
+    /// Subtracts an integer from this ordinal number, returning the
+    /// [first](Ordinal::first) ordinal number if the result would be before it.
+    fn saturating_sub(self, rhs: Self::IntegerType) -> Self {
+        self.checked_sub(rhs).unwrap_or_else(Self::first)
     }
 
-    #[test]
-    fn second_from1() {
-        let second_o_from1 = Osize::from1(2);
+    /// Parses a 1-based ordinal number, written with digits and an English suffix
+    /// (e.g. `21st`), with digits and a trailing period (e.g. `21.`), or with
+    /// English words (e.g. `twenty-first`). Letters are case-insensitive. This
+    /// is also available through `str::parse`.
+    ///
+    /// ```
+    /// use num_ordinal::{Ordinal, ParseOrdinalError, O32};
+    ///
+    /// assert_eq!(O32::parse_str("21st"), Ok(O32::from1(21)));
+    /// assert_eq!("Third".parse::<O32>(), Ok(O32::from1(3)));
+    /// assert_eq!("11st".parse::<O32>(), Err(ParseOrdinalError::BadSuffix));
+    /// assert_eq!("0th".parse::<O32>(), Err(ParseOrdinalError::Zero));
+    /// ```
+    fn parse_str(s: &str) -> Result<Self, ParseOrdinalError> {
+        let n = parse::parse_ordinal(s)?;
+        Self::IntegerType::from_u128(n)
+            .and_then(Self::try_from1)
+            .ok_or(ParseOrdinalError::Overflow)
+    }
 
-        assert_eq!(&second_o_from1.to_string(), "second");
+    /// Converts a slice of 1-based integers to ordinal numbers.
+    ///
+    /// Returns an error for the first element that is 0, which contains the
+    /// index and the value of the element.
+    ///
+    /// ```
+    /// use num_ordinal::{BulkConvertError, Ordinal, OrdinalError, O32};
+    ///
+    /// assert_eq!(O32::try_from1_slice(&[2, 1]), Ok(vec![O32::from1(2), O32::from1(1)]));
+    /// assert_eq!(
+    ///     O32::try_from1_slice(&[2, 0, 1]),
+    ///     Err(BulkConvertError { index: 1, value: 0, error: OrdinalError::Zero }),
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_from1_slice(
+        values: &[Self::IntegerType],
+    ) -> Result<Vec<Self>, BulkConvertError<Self::IntegerType>> {
+        bulk::convert(values.iter().copied(), bulk::from1)
     }
 
-    #[test]
-    fn third_from0() {
-        let third_o_from0 = Osize::from0(2);
-        assert_eq!(&third_o_from0.to_string(), "third");
+    /// Converts a slice of 0-based integers to ordinal numbers.
+    ///
+    /// Returns an error for the first element that is the highest number of the
+    /// integer type, which contains the index and the value of the element.
+    #[cfg(feature = "alloc")]
+    fn try_from0_slice(
+        values: &[Self::IntegerType],
+    ) -> Result<Vec<Self>, BulkConvertError<Self::IntegerType>> {
+        bulk::convert(values.iter().copied(), bulk::from0)
     }
 
-    #[test]
+    /// Converts a slice of 1-based integers to ordinal numbers like
+    /// [try_from1_slice](Ordinal::try_from1_slice), but returns the errors of
+    /// all invalid elements.
+    #[cfg(feature = "alloc")]
+    fn try_from1_slice_all_errors(
+        values: &[Self::IntegerType],
+    ) -> Result<Vec<Self>, Vec<BulkConvertError<Self::IntegerType>>> {
+        bulk::convert_all(values.iter().copied(), bulk::from1)
+    }
+
+    /// Converts a slice of 0-based integers to ordinal numbers like
+    /// [try_from0_slice](Ordinal::try_from0_slice), but returns the errors of
+    /// all invalid elements.
+    #[cfg(feature = "alloc")]
+    fn try_from0_slice_all_errors(
+        values: &[Self::IntegerType],
+    ) -> Result<Vec<Self>, Vec<BulkConvertError<Self::IntegerType>>> {
+        bulk::convert_all(values.iter().copied(), bulk::from0)
+    }
+
+    /// Returns the English words for this ordinal number, e.g. `twenty-third`
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, Ordinal};
+    ///
+    /// assert_eq!(ordinal!(23-rd O32).to_words(), "twenty-third");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_words(self) -> String {
+        self.to_words_with(WordStyle::default())
+    }
+
+    /// Returns the English words for this ordinal number in the given [WordStyle]
+    #[cfg(feature = "alloc")]
+    fn to_words_with(self, style: WordStyle) -> String {
+        style.ordinal(self.into1().to_u128())
+    }
+
+    /// Returns the English cardinal words for the 1-based position of this ordinal
+    /// number, e.g. `four` for the 4th. This is useful for phrases like _the fourth
+    /// of four attempts_:
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, Ordinal};
+    ///
+    /// let o = ordinal!(4-th O32);
+    /// let s = format!("the {} of {} attempts", o.to_words(), o.count_words());
+    /// assert_eq!(s, "the fourth of four attempts");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn count_words(self) -> String {
+        self.count_words_with(WordStyle::default())
+    }
+
+    /// Returns the English cardinal words for the 1-based position of this ordinal
+    /// number in the given [WordStyle]
+    #[cfg(feature = "alloc")]
+    fn count_words_with(self, style: WordStyle) -> String {
+        style.cardinal(self.into1().to_u128())
+    }
+
+    /// Returns the English adverb for this ordinal number, e.g. `firstly` or `fourthly`.
+    ///
+    /// Adverbs beyond `tenthly` sound unnatural, so for ordinals greater than the 10th,
+    /// a phrase like `in the 14th place` is returned instead. Use
+    /// [to_adverb_words_until](Ordinal::to_adverb_words_until) to choose a different cutoff.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, Ordinal};
+    ///
+    /// assert_eq!(ordinal!(second O32).to_adverb_words(), "secondly");
+    /// assert_eq!(ordinal!(14-th O32).to_adverb_words(), "in the 14th place");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_adverb_words(self) -> String {
+        if self.into1().to_u128() <= 10 {
+            words::adverb(self.into1().to_u128())
+        } else {
+            format!("in the {} place", self)
+        }
+    }
+
+    /// Returns the English adverb for this ordinal number, e.g. `firstly` or `fourthly`,
+    /// if it isn't greater than `cutoff`. Otherwise, a phrase like `in the 14th place`
+    /// is returned.
+    #[cfg(feature = "alloc")]
+    fn to_adverb_words_until(self, cutoff: Self) -> String {
+        if self <= cutoff {
+            words::adverb(self.into1().to_u128())
+        } else {
+            format!("in the {} place", self)
+        }
+    }
+
+    /// Returns the string that is printed by the `Display` impl, e.g. `third` or `4th`,
+    /// if this ordinal number isn't greater than the 256th. These strings are
+    /// precomputed, so no formatting is necessary.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, Ordinal};
+    ///
+    /// assert_eq!(ordinal!(third O32).as_static_str(), Some("third"));
+    /// assert_eq!(ordinal!(42-nd O32).as_static_str(), Some("42nd"));
+    /// assert_eq!(ordinal!(257-th O32).as_static_str(), None);
+    /// ```
+    fn as_static_str(self) -> Option<&'static str> {
+        table::get(self.into1().to_u128())
+    }
+
+    /// Returns a display adapter that formats this ordinal number in the given [Locale]
+    fn display_in(self, locale: Locale) -> Localized {
+        Localized::new(self.into1().to_u128(), locale)
+    }
+
+    /// Returns a display adapter that formats this ordinal number in the locale
+    /// of the operating system, see [Locale::system]
+    #[cfg(feature = "auto-locale")]
+    fn display_localized(self) -> Localized {
+        self.display_in(Locale::system())
+    }
+
+    /// Returns a display adapter that writes the suffix in an HTML `<sup>` element,
+    /// e.g. `4<sup>th</sup>`.
+    ///
+    /// The number is always written in numeric form, and the output only contains
+    /// digits, the suffix and the tags, so it never needs to be escaped.
+    fn html_sup(self) -> Superscript<'static> {
+        self.superscript("<sup>", "</sup>")
+    }
+
+    /// Returns the English suffix of this ordinal number, i.e. `st`, `nd`, `rd`
+    /// or `th`. Note that `Display` writes the first three ordinals as words, so
+    /// this is useful when laying out the number and the suffix separately.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, Ordinal};
+    ///
+    /// assert_eq!(ordinal!(first O32).suffix(), "st");
+    /// assert_eq!(ordinal!(12-th O32).suffix(), "th");
+    /// assert_eq!(ordinal!(22-nd O32).suffix(), "nd");
+    /// ```
+    fn suffix(self) -> &'static str {
+        suffix(self.into1().to_u128())
+    }
+
+    /// Returns a display adapter that writes the suffix with LaTeX's `\textsuperscript`
+    /// command, e.g. `4\textsuperscript{th}`.
+    ///
+    /// The number is always written in numeric form.
+    fn latex_sup(self) -> Superscript<'static> {
+        self.superscript("\\textsuperscript{", "}")
+    }
+
+    /// Returns a display adapter that writes the suffix between custom markup, e.g.
+    /// `4^th^` for `o.superscript("^", "^")`.
+    ///
+    /// The number is always written in numeric form.
+    fn superscript<'a>(self, open: &'a str, close: &'a str) -> Superscript<'a> {
+        Superscript::new(self.into1().to_u128(), open, close)
+    }
+
+    /// Returns a display adapter that writes this ordinal number with a prefix,
+    /// e.g. `#4` or `No. 4`. See [SigilStyle] for the supported prefixes.
+    fn numbered(self, style: SigilStyle) -> Numbered {
+        Numbered::new(self.into1().to_u128(), style)
+    }
+
+    /// Returns a display adapter that interprets this ordinal number as a quarter,
+    /// e.g. `Q3` or `3rd quarter`. See [periods] for the month-quarter arithmetic.
+    fn as_quarter(self) -> periods::Quarter {
+        periods::Quarter::new(self.into1().to_u128())
+    }
+
+    /// Returns a [Placement] that is tied with other placements, e.g. `T-3rd`
+    fn tied(self) -> Placement<Self> {
+        Placement::new(self, true)
+    }
+}
+
+/// Implements `&a op b`, `a op &b` and `&a op &b` by forwarding to `a op b`,
+/// like the operator impls of std's integers
+macro_rules! forward_ref_binop {
+    (impl$(<$g:ident: $bound:ident>)? $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl<'a $(, $g: $bound)?> $imp<$u> for &'a $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            #[track_caller]
+            fn $method(self, rhs: $u) -> Self::Output {
+                $imp::$method(*self, rhs)
+            }
+        }
+
+        impl$(<$g: $bound>)? $imp<&$u> for $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            #[track_caller]
+            fn $method(self, rhs: &$u) -> Self::Output {
+                $imp::$method(self, *rhs)
+            }
+        }
+
+        impl$(<$g: $bound>)? $imp<&$u> for &$t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            #[track_caller]
+            fn $method(self, rhs: &$u) -> Self::Output {
+                $imp::$method(*self, *rhs)
+            }
+        }
+    };
+}
+
+/// Implements `a op= &b` by forwarding to `a op= b`, like the operator impls of
+/// std's integers
+macro_rules! forward_ref_op_assign {
+    (impl$(<$g:ident: $bound:ident>)? $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl$(<$g: $bound>)? $imp<&$u> for $t {
+            #[track_caller]
+            fn $method(&mut self, rhs: &$u) {
+                $imp::$method(self, *rhs);
+            }
+        }
+    };
+}
+
+impl<T: OrdinalInteger> Ordinal for O<T> {
+    type IntegerType = T;
+
+    fn first() -> Self {
+        O(T::ONE.to_nonzero().expect("1 is not 0"))
+    }
+
+    fn next(self) -> Self {
+        Self::from0(self.into1())
+    }
+
+    fn into0(self) -> Self::IntegerType {
+        self.into1() - T::ONE
+    }
+
+    fn into1(self) -> Self::IntegerType {
+        T::from_nonzero(self.0)
+    }
+
+    fn try_from0(t: Self::IntegerType) -> Option<Self> {
+        t.checked_inc().and_then(Self::try_from1)
+    }
+
+    fn try_from1(t: Self::IntegerType) -> Option<Self> {
+        t.to_nonzero().map(O)
+    }
+}
+
+impl<T: OrdinalInteger> Default for O<T> {
+    fn default() -> Self {
+        Self::first()
+    }
+}
+
+// the 0-based value is hashed, like before the ordinals were stored as 1-based
+// `NonZero*` integers
+impl<T: OrdinalInteger> Hash for O<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.into0().hash(state)
+    }
+}
+
+impl<T: OrdinalInteger> fmt::Debug for O<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_ordinal_u128(self.into1().to_u128(), f)
+    }
+}
+
+impl<T: OrdinalInteger> fmt::Display for O<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_ordinal_u128(self.into1().to_u128(), f)
+    }
+}
+
+impl<T: OrdinalInteger> FromStr for O<T> {
+    type Err = ParseOrdinalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s)
+    }
+}
+
+/// Panics if the result is too big for this ordinal type, also in release builds
+impl<T: OrdinalInteger> Add<T> for O<T> {
+    type Output = O<T>;
+
+    #[track_caller]
+    fn add(self, rhs: T) -> Self::Output {
+        match self.checked_add(rhs) {
+            Some(o) => o,
+            None => panic!(
+                "cannot add {} to the {}, the result is too big for this ordinal type",
+                rhs, self
+            ),
+        }
+    }
+}
+
+/// Panics if the result would be before the first ordinal number, also in release
+/// builds
+impl<T: OrdinalInteger> Sub<T> for O<T> {
+    type Output = O<T>;
+
+    #[track_caller]
+    fn sub(self, rhs: T) -> Self::Output {
+        match self.checked_sub(rhs) {
+            Some(o) => o,
+            None => panic!(
+                "cannot subtract {} from the {}, the result would be before the first",
+                rhs, self
+            ),
+        }
+    }
+}
+
+impl<T: OrdinalInteger> AddAssign<T> for O<T> {
+    #[track_caller]
+    fn add_assign(&mut self, rhs: T) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: OrdinalInteger> SubAssign<T> for O<T> {
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: T) {
+        *self = *self - rhs;
+    }
+}
+
+/// Returns the distance between two ordinal numbers. Panics if `rhs` is greater
+/// than `self`, also in release builds; see [Ordinal::checked_distance] and
+/// [Ordinal::abs_diff] for alternatives that don't panic.
+impl<T: OrdinalInteger> Sub<O<T>> for O<T> {
+    type Output = T;
+
+    #[track_caller]
+    fn sub(self, rhs: O<T>) -> Self::Output {
+        match self.checked_distance(rhs) {
+            Some(n) => n,
+            None => panic!(
+                "cannot subtract the {} from the {}, the result would be negative",
+                rhs, self
+            ),
+        }
+    }
+}
+
+impl<T: OrdinalInteger> PartialEq<T> for O<T> {
+    fn eq(&self, other: &T) -> bool {
+        self.into1() == *other
+    }
+}
+
+impl<T: OrdinalInteger> PartialOrd<T> for O<T> {
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        self.into1().partial_cmp(other)
+    }
+}
+
+forward_ref_binop!(impl<T: OrdinalInteger> Add, add for O<T>, T);
+forward_ref_binop!(impl<T: OrdinalInteger> Sub, sub for O<T>, T);
+forward_ref_binop!(impl<T: OrdinalInteger> Sub, sub for O<T>, O<T>);
+forward_ref_op_assign!(impl<T: OrdinalInteger> AddAssign, add_assign for O<T>, T);
+forward_ref_op_assign!(impl<T: OrdinalInteger> SubAssign, sub_assign for O<T>, T);
+
+/// Implements the traits that can't be implemented generically for [O], because
+/// the integer type is on the left side, and the `const fn`s
+macro_rules! impl_ordinal {
+    ($t:ident, $int:ident, $nz:ident) => {
+        impl Integer for $int {
+            const MAX: Self = $int::MAX;
+
+            const ONE: Self = 1;
+
+            fn to_u128(self) -> u128 {
+                self as u128
+            }
+
+            fn from_u128(n: u128) -> Option<Self> {
+                $int::try_from(n).ok()
+            }
+
+            fn checked_inc(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+        }
+
+        impl NonZeroInteger for $int {
+            type NonZero = $nz;
+
+            fn to_nonzero(self) -> Option<$nz> {
+                $nz::new(self)
+            }
+
+            fn from_nonzero(n: $nz) -> Self {
+                n.get()
+            }
+        }
+
+        impl OrdinalInteger for $int {}
+
+        impl $t {
+            /// The first ordinal number. Unlike [Ordinal::first], this is a `const fn`.
+            pub const fn first() -> Self {
+                O($nz::MIN)
+            }
+
+            /// Converts a 1-based integer to an ordinal number, or returns [None] if
+            /// it is 0. Unlike [Ordinal::try_from1], this is a `const fn`.
+            pub const fn new1(n: $int) -> Option<Self> {
+                match $nz::new(n) {
+                    Some(n) => Some(O(n)),
+                    None => None,
+                }
+            }
+
+            /// Converts a 0-based integer to an ordinal number, or returns [None] if
+            /// it is the highest number of the integer type. Unlike
+            /// [Ordinal::try_from0], this is a `const fn`.
+            pub const fn new0(n: $int) -> Option<Self> {
+                match n.checked_add(1) {
+                    Some(n) => Self::new1(n),
+                    None => None,
+                }
+            }
+
+            #[doc = concat!("Converts a 1-based [", stringify!($nz), "] to an ordinal number. This never")]
+            /// fails, since every non-zero integer is a valid ordinal.
+            pub const fn from_nonzero1(n: $nz) -> Self {
+                O(n)
+            }
+
+            #[doc = concat!("Returns the 1-based [", stringify!($nz), "] of this ordinal number")]
+            pub const fn into_nonzero1(self) -> $nz {
+                self.0
+            }
+        }
+
+        impl Add<$t> for $int {
+            type Output = $t;
+
+            #[track_caller]
+            fn add(self, rhs: $t) -> Self::Output {
+                rhs + self
+            }
+        }
+
+        impl PartialEq<$t> for $int {
+            fn eq(&self, other: &$t) -> bool {
+                *self == other.into1()
+            }
+        }
+
+        impl PartialOrd<$t> for $int {
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                self.partial_cmp(&other.into1())
+            }
+        }
+
+        forward_ref_binop!(impl Add, add for $int, $t);
+
+        impl<const N: u128> __ConstFrom1<N> for $t {
+            const VALUE: Self = if N == 0 {
+                panic!("0 is not a valid 1-based ordinal")
+            } else if N > $int::MAX as u128 {
+                panic!("value is too big for this ordinal type")
+            } else {
+                match $nz::new(N as $int) {
+                    Some(n) => O(n),
+                    None => unreachable!(),
+                }
+            };
+        }
+    };
+}
+
+/// Returns the English suffix of a 1-based ordinal number
+const fn suffix(n: u128) -> &'static str {
+    match locale::english_category(n) {
+        OrdinalCategory::One => "st",
+        OrdinalCategory::Two => "nd",
+        OrdinalCategory::Few => "rd",
+        _ => "th",
+    }
+}
+
+/// Formats a 1-based ordinal number like the `Display` impl of the ordinal types.
+/// All ordinal types use this function, so the formatting code isn't duplicated
+/// for every integer type.
+pub(crate) fn fmt_ordinal_u128(n: u128, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match table::get(n) {
+        Some(s) => f.write_str(s),
+        None => write!(f, "{}{}", n, suffix(n)),
+    }
+}
+
+/// Like [fmt_ordinal_u128], but spells out the number with the alternate flag
+/// (`{:#}`), e.g. `twenty-first`, and respects the width, fill, alignment and
+/// precision like `str`
+fn display_ordinal_u128(n: u128, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if f.alternate() {
+        pad::pad(f, format_args!("{}", words::Words(n)))
+    } else if let Some(s) = table::get(n) {
+        f.pad(s)
+    } else {
+        pad::pad(f, format_args!("{}{}", n, suffix(n)))
+    }
+}
+
+impl_ordinal!(Osize, usize, NonZeroUsize);
+impl_ordinal!(O128, u128, NonZeroU128);
+impl_ordinal!(O64, u64, NonZeroU64);
+impl_ordinal!(O32, u32, NonZeroU32);
+impl_ordinal!(O16, u16, NonZeroU16);
+impl_ordinal!(O8, u8, NonZeroU8);
+
+/// Creates a 1-based ordinal number. For example, `ordinal1(4)` is the 4th ordinal number.
+pub fn ordinal1<O: Ordinal>(n: O::IntegerType) -> O {
+    O::from1(n)
+}
+
+/// Creates an ordinal number of any type in [ordinal!]
+#[doc(hidden)]
+pub fn __from1_u128<O: Ordinal>(n: u128) -> O {
+    O::IntegerType::from_u128(n)
+        .and_then(O::try_from1)
+        .unwrap_or_else(|| panic!("{}", OrdinalError::Overflow.message()))
+}
+
+/// Creates a 0-based ordinal number. For example, `ordinal0(4)` is the 5th ordinal number.
+pub fn ordinal0<O: Ordinal>(n: O::IntegerType) -> O {
+    O::from0(n)
+}
+
+/// Returns the English suffix of a 1-based number, i.e. `st`, `nd`, `rd` or `th`.
+/// This is the same as [Ordinal::suffix], but accepts any integer.
+///
+/// ```
+/// use num_ordinal::suffix_of;
+///
+/// assert_eq!(suffix_of(1u32), "st");
+/// assert_eq!(suffix_of(12usize), "th");
+/// assert_eq!(suffix_of(u128::MAX), "th");
+/// ```
+pub fn suffix_of<I: OrdinalInteger>(n: I) -> &'static str {
+    suffix(n.to_u128())
+}
+
+/// Returns a display adapter that writes a 1-based number with its English
+/// suffix, without converting it to an ordinal type. Unlike `Display` of the
+/// ordinal types, the first three ordinals aren't spelled out.
+///
+/// ```
+/// use num_ordinal::display1;
+///
+/// assert_eq!(display1(112u32).to_string(), "112th");
+/// assert_eq!(display1(1u8).to_string(), "1st");
+/// assert_eq!(format!("this is the {} attempt", display1(3usize)), "this is the 3rd attempt");
+/// ```
+pub fn display1<I: OrdinalInteger>(n: I) -> impl fmt::Display {
+    Suffixed(Some(n.to_u128()))
+}
+
+/// Returns a display adapter that writes a 0-based number as a 1-based number
+/// with its English suffix, e.g. `1st` for 0. This works for the highest number
+/// of every integer type.
+///
+/// ```
+/// use num_ordinal::display0;
+///
+/// assert_eq!(display0(0u32).to_string(), "1st");
+/// assert_eq!(display0(111u32).to_string(), "112th");
+/// assert_eq!(display0(u8::MAX).to_string(), "256th");
+/// ```
+pub fn display0<I: OrdinalInteger>(n: I) -> impl fmt::Display {
+    Suffixed(n.to_u128().checked_add(1))
+}
+
+/// A 1-based number with its suffix. [None] is `2^128`, which is the 1-based
+/// equivalent of `u128::MAX`.
+struct Suffixed(Option<u128>);
+
+impl fmt::Display for Suffixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(n) => pad::pad(f, format_args!("{}{}", n, suffix(n))),
+            None => f.pad("340282366920938463463374607431768211456th"),
+        }
+    }
+}
+
+/// Creates a 1-based ordinal number. Examples:
+///
+/// ```
+/// use num_ordinal::{O32, ordinal};
+///
+/// let mut o: O32 = ordinal!(first);
+/// o = ordinal!(second);
+/// o = ordinal!(third);
+///
+/// // Other numbers must use the following syntax:
+/// o = ordinal!(4-th);
+/// // the dash can be omitted, but then a space is required to make the Rust parser happy:
+/// o = ordinal!(4 th);
+/// // alternatively, a dot can be written after the number:
+/// o = ordinal!(4 .);
+///
+/// // When necessary, the type can be ascribed:
+/// let mut o = ordinal!(5-th O32);
+///
+/// // Numbers that are a single English word can be spelled out:
+/// o = ordinal!(fifth);
+/// o = ordinal!(twentieth O32);
+///
+/// // Expressions in parentheses or braces are evaluated at runtime:
+/// let count = 7;
+/// o = ordinal!((count) th);
+/// o = ordinal!({ count + 1 }-th O32);
+/// ```
+///
+/// The suffix of an expression isn't checked, since its value is only known at
+/// runtime.
+///
+/// The built-in types (`O8`, `O16`, `O32`, `O64`, `O128` and `Osize`) don't need to be
+/// imported. Any other type implementing [Ordinal] can be ascribed with a path or an alias:
+///
+/// ```
+/// use num_ordinal::{ordinal, O16 as Row};
+///
+/// let o = ordinal!(5-th Row);
+/// let o = ordinal!(5-th num_ordinal::O16);
+/// ```
+///
+/// Note that only numbers that are a single word, like `eleventh` or `fiftieth`,
+/// can be spelled out:
+///
+/// ```compile_fail
+/// use num_ordinal::{O32, ordinal};
+///
+/// // doesn't compile!
+/// let _: O32 = ordinal!(twentyfifth);
+/// ```
+///
+/// The number is checked at compile time, and the built-in types can be created in a
+/// const context:
+///
+/// ```
+/// use num_ordinal::{ordinal, O8};
+///
+/// const FIRST_RETRY: O8 = ordinal!(3-rd O8);
+/// const LAST_RETRY: O8 = ordinal!(255-th O8);
+/// assert_eq!(FIRST_RETRY, 3);
+/// ```
+///
+/// There is no 0th ordinal number:
+///
+/// ```compile_fail
+/// use num_ordinal::{O32, ordinal};
+///
+/// // doesn't compile!
+/// const ZEROTH: O32 = ordinal!(0-th O32);
+/// ```
+///
+/// The number must fit in the type:
+///
+/// ```compile_fail
+/// use num_ordinal::{O8, ordinal};
+///
+/// // doesn't compile!
+/// const O: O8 = ordinal!(256-th O8);
+/// ```
+///
+/// The suffix must match the number:
+///
+/// ```compile_fail
+/// use num_ordinal::{O32, ordinal};
+///
+/// // doesn't compile!
+/// let _: O32 = ordinal!(2-st);
+/// ```
+#[macro_export]
+macro_rules! ordinal {
+    (($e:expr) $(-)? $suffix:ident $($ty:tt)*) => {
+        $crate::ordinal1::<$crate::__ordinal_ty!($($ty)*)>($e)
+    };
+    ($e:block $(-)? $suffix:ident $($ty:tt)*) => {
+        $crate::ordinal1::<$crate::__ordinal_ty!($($ty)*)>($e)
+    };
+    ($word:ident $($ty:tt)*) => {
+        $crate::__ordinal_value!(
+            $crate::__parse_ordinal_str(::core::stringify!($word));
+            $($ty)*
+        )
+    };
+    ($n:literal $(-)? $suffix:ident $($ty:tt)*) => {
+        $crate::__ordinal_value!(
+            $crate::__ordinal_literal($n, ::core::stringify!($suffix));
+            $($ty)*
+        )
+    };
+    ($n:literal . $($ty:tt)*) => {
+        $crate::__ordinal_value!($crate::__ordinal_literal($n, "."); $($ty)*)
+    };
+}
+
+/// Creates a 0-based ordinal number, like [ordinal0]. For example, `ordinal0!(4)` is
+/// the 5th ordinal number:
+///
+/// ```
+/// use num_ordinal::{ordinal, ordinal0, O32};
+///
+/// let o: O32 = ordinal0!(4);
+/// assert_eq!(o, ordinal!(5-th O32));
+///
+/// // The type can be ascribed like in `ordinal!`:
+/// const FIRST: O32 = ordinal0!(0 O32);
+///
+/// // Expressions in parentheses or braces are evaluated at runtime:
+/// let index = 4;
+/// assert_eq!(ordinal0!((index) O32), o);
+/// assert_eq!(ordinal0!({ index - 4 } O32), FIRST);
+/// ```
+///
+/// A literal is checked at compile time, so it must fit in the type:
+///
+/// ```compile_fail
+/// use num_ordinal::{ordinal0, O8};
+///
+/// // doesn't compile!
+/// const O: O8 = ordinal0!(255 O8);
+/// ```
+#[macro_export]
+macro_rules! ordinal0 {
+    (($e:expr) $($ty:tt)*) => {
+        $crate::ordinal0::<$crate::__ordinal_ty!($($ty)*)>($e)
+    };
+    ($e:block $($ty:tt)*) => {
+        $crate::ordinal0::<$crate::__ordinal_ty!($($ty)*)>($e)
+    };
+    ($n:literal $($ty:tt)*) => {
+        $crate::__ordinal_value!($n + 1; $($ty)*)
+    };
+}
+
+/// Expands to the value of [ordinal!], where `$n` is a constant expression of the
+/// 1-based number. The built-in types are created in a const context; other types
+/// are created at runtime.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ordinal_value {
+    ($n:expr;) => {
+        $crate::__OrdinalStr::<_, { $n }>::VALUE
+    };
+    ($n:expr; O8) => {
+        $crate::__OrdinalStr::<$crate::O8, { $n }>::VALUE
+    };
+    ($n:expr; O16) => {
+        $crate::__OrdinalStr::<$crate::O16, { $n }>::VALUE
+    };
+    ($n:expr; O32) => {
+        $crate::__OrdinalStr::<$crate::O32, { $n }>::VALUE
+    };
+    ($n:expr; O64) => {
+        $crate::__OrdinalStr::<$crate::O64, { $n }>::VALUE
+    };
+    ($n:expr; O128) => {
+        $crate::__OrdinalStr::<$crate::O128, { $n }>::VALUE
+    };
+    ($n:expr; Osize) => {
+        $crate::__OrdinalStr::<$crate::Osize, { $n }>::VALUE
+    };
+    ($n:expr; $ty:ty) => {
+        $crate::__from1_u128::<$ty>({
+            const N: u128 = $n;
+            N
+        })
+    };
+}
+
+/// Creates a 1-based ordinal number from a string literal at compile time.
+///
+/// The string can contain digits with an optional English suffix (e.g. `"23rd"`
+/// or `"23"`), or English words as produced by [to_words](Ordinal::to_words)
+/// (e.g. `"twenty-third"`). The type can be specified after a comma; only the
+/// built-in ordinal types are supported.
+///
+/// ```
+/// use num_ordinal::{ordinal_str, O32, O8};
+///
+/// static DEADLINE: O32 = ordinal_str!("23rd");
+/// const ROUND: O8 = ordinal_str!("one hundred twenty-fifth");
+///
+/// assert_eq!(DEADLINE, ordinal_str!("twenty-third", O32));
+/// assert_eq!(ROUND.to_string(), "125th");
+/// ```
+///
+/// An invalid string or a number that is too big for the type fails to compile:
+///
+/// ```compile_fail
+/// use num_ordinal::{ordinal_str, O32};
+///
+/// // doesn't compile: value is not an ordinal number
+/// let _: O32 = ordinal_str!("23th");
+/// ```
+///
+/// ```compile_fail
+/// use num_ordinal::{ordinal_str, O8};
+///
+/// // doesn't compile: value is too big for this ordinal type
+/// let _: O8 = ordinal_str!("256th");
+/// ```
+#[macro_export]
+macro_rules! ordinal_str {
+    ($s:literal $(,)?) => {
+        $crate::__OrdinalStr::<_, { $crate::__parse_ordinal_str($s) }>::VALUE
+    };
+    ($s:literal, $($ty:tt)+) => {
+        $crate::__OrdinalStr::<$crate::__ordinal_ty!($($ty)+), { $crate::__parse_ordinal_str($s) }>::VALUE
+    };
+}
+
+/// Expands to the type ascribed in [ordinal!]. The names of the built-in types
+/// refer to this crate, so they work without an import.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ordinal_ty {
+    () => {
+        _
+    };
+    (O8) => {
+        $crate::O8
+    };
+    (O16) => {
+        $crate::O16
+    };
+    (O32) => {
+        $crate::O32
+    };
+    (O64) => {
+        $crate::O64
+    };
+    (O128) => {
+        $crate::O128
+    };
+    (Osize) => {
+        $crate::Osize
+    };
+    ($ty:ty) => {
+        $ty
+    };
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_with_integers() {
+        let third = O32::from1(3);
+        assert!(third == 3);
+        assert!(3 == third);
+        assert!(third != 2);
+        assert!(third < 4 && third > 2 && third <= 3 && third >= 3);
+        assert!(4 > third && 2 < third);
+
+        // ordinals are never equal to 0
+        for o in [O8::first(), O8::from1(100), O8::last()].iter() {
+            assert_ne!(*o, 0);
+            assert_ne!(0, *o);
+            assert!(*o > 0);
+            assert!(0 < *o);
+        }
+        assert!(Osize::first() == 1 && Osize::first() < 2);
+
+        // the last ordinal equals the MAX of the integer type
+        assert!(O8::last() == u8::MAX);
+        assert!(O8::last() >= u8::MAX);
+        assert!(O128::last() == u128::MAX);
+        assert!(O16::from0(u16::MAX - 2) < u16::MAX);
+    }
+
+    #[test]
+    fn niche() {
+        use std::mem::size_of;
+
+        assert_eq!(size_of::<Option<O8>>(), size_of::<O8>());
+        assert_eq!(size_of::<Option<O16>>(), size_of::<O16>());
+        assert_eq!(size_of::<Option<O32>>(), size_of::<O32>());
+        assert_eq!(size_of::<Option<O64>>(), size_of::<O64>());
+        assert_eq!(size_of::<Option<O128>>(), size_of::<O128>());
+        assert_eq!(size_of::<Option<Osize>>(), size_of::<Osize>());
+        assert_eq!(size_of::<O32>(), size_of::<u32>());
+    }
+
+    #[test]
+    fn nonzero() {
+        use std::num::NonZeroU32;
+
+        const FOURTH: O32 = O32::from_nonzero1(match NonZeroU32::new(4) {
+            Some(n) => n,
+            None => panic!(),
+        });
+        assert_eq!(FOURTH, O32::from1(4));
+        assert_eq!(FOURTH.into_nonzero1().get(), 4);
+        const LAST: O8 = O8::from_nonzero1(std::num::NonZeroU8::MAX);
+        assert_eq!(LAST, O8::last());
+        assert_eq!(LAST.into_nonzero1().get(), 255);
+        assert_eq!(O32::default(), O32::first());
+        assert_eq!(O8::try_from0(254), Some(O8::last()));
+        assert_eq!(O8::try_from0(255), None);
+        assert_eq!(O8::try_from1(255), Some(O8::last()));
+        assert_eq!(O8::try_from1(0), None);
+    }
+
+    #[test]
+    fn generic_over_integer_type() {
+        fn nth<T: OrdinalInteger>(items: &[&'static str], o: O<T>) -> Option<&'static str> {
+            items.get(o.cast::<Osize>()?.into0()).copied()
+        }
+
+        fn distance<T: OrdinalInteger>(a: O<T>, b: O<T>) -> T {
+            if a < b {
+                b - a
+            } else {
+                a - b
+            }
+        }
+
+        let items = ["a", "b", "c"];
+        assert_eq!(nth(&items, O8::from1(2)), Some("b"));
+        assert_eq!(nth(&items, O16::from1(3)), Some("c"));
+        assert_eq!(nth(&items, O128::last()), None);
+        assert_eq!(nth(&items, Osize::first()), Some("a"));
+
+        assert_eq!(distance(O8::from1(7), O8::from1(3)), 4);
+        assert_eq!(distance(O64::first(), O64::last()), u64::MAX - 1);
+        assert_eq!(std::mem::size_of::<Option<O<u16>>>(), 2);
+        assert_eq!(O::<u32>::from1(4), O32::from1(4));
+    }
+
+    #[test]
+    fn const_constructors() {
+        const FIRST: O16 = O16::first();
+        const SECOND: Option<O16> = O16::new1(2);
+        const THIRD: Option<O16> = O16::new0(2);
+        const RETRY: O8 = ordinal!(3-rd O8);
+        assert_eq!(FIRST, <O16 as Ordinal>::first());
+        assert_eq!(SECOND, Some(O16::from1(2)));
+        assert_eq!(THIRD, Some(O16::from1(3)));
+        assert_eq!(RETRY, O8::from1(3));
+        assert_eq!(O8::new1(0), None);
+        assert_eq!(O8::new0(255), None);
+        assert_eq!(O8::new0(254), Some(O8::last()));
+    }
+
+    #[test]
+    fn order_and_hash_of_0_based_values() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash<T: Hash>(t: T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            t.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        for a in 0..255u8 {
+            assert_eq!(hash(O8::from0(a)), hash(a));
+            for b in 0..255u8 {
+                assert_eq!(O8::from0(a).cmp(&O8::from0(b)), a.cmp(&b));
+            }
+        }
+        for &n in [0, 1, 1000, u64::MAX - 1].iter() {
+            assert_eq!(hash(O64::from0(n)), hash(n));
+            assert_eq!(hash(Osize::from0(n as usize)), hash(n as usize));
+        }
+        assert_eq!(hash(O128::last()), hash(u128::MAX - 1));
+    }
+
+    #[test]
+    fn first_from0() {
+        let first_o_from0 = Osize::from0(0);
+
+        assert_eq!(&first_o_from0.to_string(), "first");
+    }
+
+    #[test]
+    fn first_from1() {
+        let first_o_from1 = Osize::from1(1);
+        assert_eq!(&first_o_from1.to_string(), "first");
+    }
+
+    #[test]
+    fn second_from0() {
+        let second_o_from0 = Osize::from0(1);
+        assert_eq!(&second_o_from0.to_string(), "second");
+    }
+
+    #[test]
+    fn second_from1() {
+        let second_o_from1 = Osize::from1(2);
+
+        assert_eq!(&second_o_from1.to_string(), "second");
+    }
+
+    #[test]
+    fn third_from0() {
+        let third_o_from0 = Osize::from0(2);
+        assert_eq!(&third_o_from0.to_string(), "third");
+    }
+
+    #[test]
     fn third_from1() {
         let third_o_from1 = Osize::from1(3);
         assert_eq!(&third_o_from1.to_string(), "third");
@@ -384,4 +1917,805 @@ mod tests {
         let fourth_o_from1 = Osize::from1(4);
         assert_eq!(&fourth_o_from1.to_string(), "4th");
     }
+
+    #[test]
+    fn adverb_words() {
+        assert_eq!(Osize::from1(1).to_adverb_words(), "firstly");
+        assert_eq!(Osize::from1(10).to_adverb_words(), "tenthly");
+        assert_eq!(Osize::from1(11).to_adverb_words(), "in the 11th place");
+
+        let cutoff = Osize::from1(3);
+        assert_eq!(Osize::from1(3).to_adverb_words_until(cutoff), "thirdly");
+        assert_eq!(
+            Osize::from1(4).to_adverb_words_until(cutoff),
+            "in the 4th place"
+        );
+        assert_eq!(
+            O8::from1(255).to_adverb_words_until(O8::from1(255)),
+            "two hundred fifty-fifthly"
+        );
+    }
+
+    #[test]
+    fn from_signed() {
+        assert_eq!(O8::try_from1_signed(255i16), Ok(O8::from1(255)));
+        assert_eq!(O8::try_from0_signed(254i16), Ok(O8::from0(254)));
+        assert_eq!(O8::try_from0_signed(i64::MIN), Err(OrdinalError::Negative));
+        assert_eq!(O8::try_from1_signed(i64::MAX), Err(OrdinalError::Overflow));
+
+        assert_eq!(
+            O64::try_from1_signed(i64::MAX),
+            Ok(O64::from1(i64::MAX as u64))
+        );
+        assert_eq!(
+            O64::try_from1_signed(i128::MAX),
+            Err(OrdinalError::Overflow)
+        );
+        assert_eq!(
+            O128::try_from1_signed(i128::MAX),
+            Ok(O128::from1(i128::MAX as u128))
+        );
+        assert_eq!(
+            O128::try_from1_signed(i128::MIN),
+            Err(OrdinalError::Negative)
+        );
+        assert_eq!(Osize::try_from1_signed(-0i32), Err(OrdinalError::Zero));
+    }
+
+    #[test]
+    fn checked_constructors() {
+        fn parse_position(s: &str) -> Result<O16, Box<dyn core::error::Error>> {
+            Ok(O16::from1_checked(s.parse()?)?)
+        }
+        assert_eq!(parse_position("12").unwrap(), O16::from1(12));
+        let err = parse_position("0").unwrap_err();
+        assert_eq!(err.downcast_ref(), Some(&OrdinalError::Zero));
+
+        assert_eq!(O16::from0_checked(65_534), Ok(O16::from1(65_535)));
+        assert_eq!(O16::from0_checked(65_535), Err(OrdinalError::Overflow));
+        assert_eq!(
+            Osize::from1_checked(usize::MAX),
+            Ok(Osize::from0(usize::MAX - 1))
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        macro_rules! check {
+            ($t:ident, $int:ident) => {
+                let last = $t::from0($int::MAX - 1);
+                assert_eq!(last.try_next(), None);
+                assert_eq!(last.checked_add(0), Some(last));
+                assert_eq!(last.checked_add(1), None);
+                assert_eq!($t::first().checked_add($int::MAX - 1), Some(last));
+                assert_eq!($t::first().checked_add($int::MAX), None);
+                assert_eq!($t::from0($int::MAX - 2).try_next(), Some(last));
+                assert_eq!($t::from0($int::MAX - 2).checked_add(2), None);
+                assert_eq!(last.saturating_add($int::MAX), last);
+                assert_eq!($t::from1(3).saturating_add(4), $t::from1(7));
+
+                assert_eq!($t::from1(3).checked_sub(2), Some($t::first()));
+                assert_eq!($t::from1(3).checked_sub(3), None);
+                assert_eq!(last.checked_sub($int::MAX - 1), Some($t::first()));
+                assert_eq!(last.checked_sub($int::MAX), None);
+                assert_eq!($t::from1(3).saturating_sub(3), $t::first());
+                assert_eq!(last.saturating_sub($int::MAX), $t::first());
+                assert_eq!(last.saturating_sub(1), $t::from0($int::MAX - 2));
+            };
+        }
+        check!(O8, u8);
+        check!(O16, u16);
+        check!(O32, u32);
+        check!(O64, u64);
+        check!(O128, u128);
+        check!(Osize, usize);
+    }
+
+    #[test]
+    fn first_and_last() {
+        assert_eq!(O8::last().into1(), 255);
+        assert_eq!(O16::last().into1(), u16::MAX);
+        assert_eq!(O32::last().into1(), u32::MAX);
+        assert_eq!(O64::last().into1(), u64::MAX);
+        assert_eq!(O128::last().into1(), u128::MAX);
+        assert_eq!(Osize::last().into1(), usize::MAX);
+
+        assert_eq!(O8::first().try_prev(), None);
+        assert_eq!(O128::first().try_prev(), None);
+        assert_eq!(O8::last().prev(), O8::from1(254));
+        assert_eq!(O8::last().prev().next(), O8::last());
+
+        // the last ordinal is the only one without a successor
+        let mut o = O8::first();
+        while let Some(next) = o.try_next() {
+            assert_eq!(next.prev(), o);
+            o = next;
+        }
+        assert_eq!(o, O8::last());
+    }
+
+    #[test]
+    #[should_panic(expected = "the first ordinal has no previous ordinal")]
+    fn prev_of_first() {
+        O16::first().prev();
+    }
+
+    #[test]
+    fn usize_arithmetic() {
+        let o = O8::from1(5);
+        assert_eq!(o.try_add_usize(0), Some(o));
+        assert_eq!(o.try_add_usize(250), Some(O8::from1(255)));
+        // fits into u8, but the sum overflows
+        assert_eq!(o.try_add_usize(251), None);
+        // doesn't fit into u8
+        assert_eq!(o.try_add_usize(256), None);
+        assert_eq!(O8::first().try_add_usize(usize::MAX), None);
+
+        assert_eq!(o.try_sub_usize(4), Some(O8::first()));
+        assert_eq!(o.try_sub_usize(5), None);
+        assert_eq!(o.try_sub_usize(usize::MAX), None);
+
+        let o = O128::from1(u128::MAX - 10);
+        assert_eq!(o.try_add_usize(10), Some(O128::from1(u128::MAX)));
+        assert_eq!(o.try_add_usize(11), None);
+        assert_eq!(
+            O16::from1(1000).try_add_usize(64_000),
+            Some(O16::from1(65_000))
+        );
+    }
+
+    #[test]
+    fn every_nth() {
+        for n in 1..=20u8 {
+            for position in 1..=100u8 {
+                let o = O8::from1(position);
+                assert_eq!(o.is_every(n), position % n == 0);
+
+                let next = o.next_multiple_of(n).unwrap();
+                assert!(next >= o && next.is_every(n));
+                assert!(next.into1() - position < n);
+            }
+        }
+        for position in 1..=10 {
+            let o = Osize::from1(position);
+            assert_eq!(o.is_odd_position(), position % 2 == 1);
+            assert_eq!(o.is_even_position(), position % 2 == 0);
+        }
+        assert!(O8::first().is_odd_position());
+        assert!(O8::first().is_every(1));
+    }
+
+    #[test]
+    fn next_multiple_overflow() {
+        assert_eq!(O8::from1(250).next_multiple_of(50), Some(O8::from1(250)));
+        assert_eq!(O8::from1(251).next_multiple_of(50), None);
+        assert_eq!(O8::from1(200).next_multiple_of(255), Some(O8::from1(255)));
+        assert_eq!(
+            O128::from1(u128::MAX - 1).next_multiple_of(u128::MAX),
+            Some(O128::from1(u128::MAX))
+        );
+        assert_eq!(
+            O128::from1(3).next_multiple_of(u128::MAX - 1),
+            Some(O128::from1(u128::MAX - 1))
+        );
+        assert_eq!(O128::from1(u128::MAX).next_multiple_of(2), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn is_every_zero() {
+        O32::first().is_every(0);
+    }
+
+    #[test]
+    fn from_f64() {
+        assert_eq!(O8::try_from1_f64(255.0), Ok(O8::from1(255)));
+        assert_eq!(O8::try_from1_f64(256.0), Err(OrdinalError::Overflow));
+        assert_eq!(O8::try_from1_f64(f64::NAN), Err(OrdinalError::Invalid));
+        assert_eq!(
+            O8::try_from1_f64(f64::INFINITY),
+            Err(OrdinalError::Overflow)
+        );
+        assert_eq!(
+            O8::try_from1_f64(f64::NEG_INFINITY),
+            Err(OrdinalError::Negative)
+        );
+        assert_eq!(O8::try_from1_f64(-1.0), Err(OrdinalError::Negative));
+        assert_eq!(O8::try_from1_f64(-0.0), Err(OrdinalError::Zero));
+        assert_eq!(O8::try_from1_f64(1.5), Err(OrdinalError::Fractional));
+        assert_eq!(
+            O8::try_from1_f64(f64::EPSILON),
+            Err(OrdinalError::Fractional)
+        );
+
+        let r = Rounding::Nearest;
+        assert_eq!(O8::try_from1_f64_rounded(1.5, r), Ok(O8::from1(2)));
+        assert_eq!(O8::try_from1_f64_rounded(255.4, r), Ok(O8::from1(255)));
+        assert_eq!(
+            O8::try_from1_f64_rounded(255.5, r),
+            Err(OrdinalError::Overflow)
+        );
+        assert_eq!(O8::try_from1_f64_rounded(-0.4, r), Err(OrdinalError::Zero));
+        assert_eq!(
+            O8::try_from1_f64_rounded(0.1, Rounding::Ceil),
+            Ok(O8::first())
+        );
+        assert_eq!(
+            O8::try_from1_f64_rounded(f64::NAN, r),
+            Err(OrdinalError::Invalid)
+        );
+    }
+
+    #[test]
+    fn from_f64_precision() {
+        let max_exact = (1u64 << 53) - 1;
+        assert_eq!(
+            O64::try_from1_f64(max_exact as f64),
+            Ok(O64::from1(max_exact))
+        );
+        assert_eq!(
+            O64::try_from1_f64((1u64 << 53) as f64),
+            Err(OrdinalError::Inexact)
+        );
+        assert_eq!(O128::try_from1_f64(1e30), Err(OrdinalError::Inexact));
+        assert_eq!(O128::try_from1_f64(1e39), Err(OrdinalError::Overflow));
+        assert_eq!(O64::try_from1_f64(1e30), Err(OrdinalError::Overflow));
+        assert_eq!(O32::try_from1_f64(1e10), Err(OrdinalError::Overflow));
+        assert_eq!(
+            Osize::try_from1_f64_rounded((1u64 << 52) as f64 - 0.5, Rounding::Ceil),
+            Ok(Osize::from1(1 << 52))
+        );
+    }
+
+    #[test]
+    fn to_f64() {
+        assert_eq!(O8::from1(255).to_f64_lossy(), 255.0);
+        assert_eq!(O64::from1(1 << 53).to_f64_lossy(), 9_007_199_254_740_992.0);
+        assert_eq!(
+            O64::from1((1 << 53) + 1).to_f64_lossy(),
+            9_007_199_254_740_992.0
+        );
+        assert_eq!(O128::from1(u128::MAX).to_f64_lossy(), u128::MAX as f64);
+    }
+
+    #[test]
+    fn display_small_numbers() {
+        let expected = [
+            "first", "second", "third", "4th", "5th", "6th", "7th", "8th", "9th", "10th", "11th",
+            "12th", "13th", "14th", "15th", "16th", "17th", "18th", "19th", "20th", "21st", "22nd",
+            "23rd", "24th", "25th",
+        ];
+        for (n, &s) in (1..=25).zip(expected.iter()) {
+            assert_eq!(O32::from1(n).to_string(), s);
+            assert_eq!(ordinal1::<O8>(n as u8).to_string(), s);
+        }
+
+        let expected = [
+            "100th", "101st", "102nd", "103rd", "104th", "105th", "106th", "107th", "108th",
+            "109th", "110th", "111th", "112th", "113th", "114th",
+        ];
+        for (n, &s) in (100..=114).zip(expected.iter()) {
+            assert_eq!(O16::from1(n).to_string(), s);
+        }
+        assert_eq!(O64::from1(121).to_string(), "121st");
+        assert_eq!(O64::from1(122).to_string(), "122nd");
+        assert_eq!(O64::from1(123).to_string(), "123rd");
+    }
+
+    #[test]
+    fn suffixes() {
+        let suffix = |n| O128::from1(n).suffix();
+        let expected = [
+            "st", "nd", "rd", "th", "th", "th", "th", "th", "th", "th", "th", "th", "th", "th",
+            "th", "th", "th", "th", "th", "th", "st", "nd", "rd", "th", "th",
+        ];
+        for (n, &s) in (1..=25).zip(expected.iter()) {
+            assert_eq!(suffix(n), s, "{}", n);
+        }
+        for n in 111..=113 {
+            assert_eq!(suffix(n), "th");
+        }
+        assert_eq!(suffix(101), "st");
+        assert_eq!(suffix(1002), "nd");
+        assert_eq!(suffix(u128::MAX), "th");
+    }
+
+    #[test]
+    fn display_alternate() {
+        let cases = [
+            (1, "first"),
+            (4, "fourth"),
+            (12, "twelfth"),
+            (20, "twentieth"),
+            (21, "twenty-first"),
+            (40, "fortieth"),
+            (90, "ninetieth"),
+            (99, "ninety-ninth"),
+            (100, "one hundredth"),
+            (123, "one hundred twenty-third"),
+            (999, "nine hundred ninety-ninth"),
+            (1000, "one thousandth"),
+        ];
+        for &(n, words) in &cases {
+            assert_eq!(format!("{:#}", O32::from1(n)), words);
+            assert_eq!(O32::from1(n).to_words(), words);
+        }
+        assert_eq!(format!("{}", O32::from1(21)), "21st");
+        assert_eq!(format!("{:#?}", O32::from1(21)), "21st");
+        assert_eq!(format!("{:#}", O128::last()), O128::last().to_words());
+    }
+
+    #[test]
+    fn display_padding() {
+        let o = O32::from1(4);
+        assert_eq!(format!("{:>8}", o), "     4th");
+        assert_eq!(format!("{:<8}|", o), "4th     |");
+        assert_eq!(format!("{:^8}", o), "  4th   ");
+        assert_eq!(format!("{:*^9}", o), "***4th***");
+        assert_eq!(format!("{:.2}", o), "4t");
+        assert_eq!(format!("{:>6.2}", o), "    4t");
+        assert_eq!(format!("{:2}", o), "4th");
+
+        let first = O32::first();
+        assert_eq!(format!("{:>8}", first), "   first");
+        assert_eq!(format!("{:.3}", first), "fir");
+        assert_eq!(format!("{:<#14}|", O32::from1(21)), "twenty-first  |");
+        assert_eq!(format!("{:>8}", O128::from1(1000)), "  1000th");
+        // Debug ignores the flags
+        assert_eq!(format!("{:>8?}", o), "4th");
+    }
+
+    #[test]
+    fn plain_integers() {
+        for n in 1..=1000u32 {
+            assert_eq!(suffix_of(n), O32::from1(n).suffix());
+            assert_eq!(display1(n).to_string(), format!("{}{}", n, suffix_of(n)));
+            assert_eq!(display0(n - 1).to_string(), display1(n).to_string());
+        }
+        assert_eq!(display1(0u8).to_string(), "0th");
+        assert_eq!(display1(11u64).to_string(), "11th");
+        assert_eq!(display1(112usize).to_string(), "112th");
+        assert_eq!(display1(u128::MAX).to_string(), format!("{}th", u128::MAX));
+        assert_eq!(
+            display0(u128::MAX).to_string(),
+            "340282366920938463463374607431768211456th"
+        );
+        assert_eq!(display0(u64::MAX).to_string(), "18446744073709551616th");
+        assert_eq!(format!("{:>6}", display1(2u8)), "   2nd");
+    }
+
+    #[test]
+    fn display_all_widths() {
+        fn check<O: Ordinal>(n: O::IntegerType, expected: &str) {
+            let o = O::from1(n);
+            assert_eq!(o.to_string(), expected);
+            assert_eq!(format!("{:?}", o), expected);
+        }
+        check::<O8>(1, "first");
+        check::<O8>(255, "255th");
+        check::<O16>(65_535, "65535th");
+        check::<O32>(4_294_967_292, "4294967292nd");
+        check::<O64>(u64::MAX, "18446744073709551615th");
+        check::<Osize>(1011, "1011th");
+        check::<O128>(u128::MAX - 1, "340282366920938463463374607431768211454th");
+        check::<O128>(u128::MAX, "340282366920938463463374607431768211455th");
+    }
+
+    #[test]
+    fn static_str_matches_display() {
+        for n in 1..=255 {
+            let o = O8::from1(n);
+            assert_eq!(o.as_static_str(), Some(o.to_string().as_str()));
+        }
+        for n in 1..=300 {
+            let o = O128::from1(n);
+            let expected = format!("{}{}", n, suffix(n));
+            assert_eq!(o.to_string(), o.as_static_str().unwrap_or(&expected));
+        }
+        assert_eq!(O16::from1(256).as_static_str(), Some("256th"));
+        assert_eq!(O16::from1(257).as_static_str(), None);
+    }
+
+    #[test]
+    fn reference_operands() {
+        let (o, n, first) = (O32::from1(5), 3, O32::first());
+        let (o_ref, n_ref, first_ref) = (&o, &n, &first);
+        assert_eq!(o_ref + 3, O32::from1(8));
+        assert_eq!(o + n_ref, O32::from1(8));
+        assert_eq!(o_ref + n_ref, O32::from1(8));
+        assert_eq!(o_ref - 3, O32::from1(2));
+        assert_eq!(o - n_ref, O32::from1(2));
+        assert_eq!(o_ref - n_ref, O32::from1(2));
+        assert_eq!(o_ref - first, 4);
+        assert_eq!(o - first_ref, 4);
+        assert_eq!(o_ref - first_ref, 4);
+
+        let v = [1u8, 2, 3];
+        let sum = v.iter().fold(O8::first(), |acc, n| acc + n);
+        assert_eq!(sum, O8::from1(7));
+    }
+
+    #[test]
+    fn distance() {
+        let (second, fifth) = (O32::from1(2), O32::from1(5));
+        assert_eq!(fifth - second, 3);
+        assert_eq!(fifth.checked_distance(second), Some(3));
+        assert_eq!(second.checked_distance(fifth), None);
+        assert_eq!(second.checked_distance(second), Some(0));
+        assert_eq!(second.abs_diff(fifth), 3);
+        assert_eq!(fifth.abs_diff(second), 3);
+        assert_eq!(O8::first().abs_diff(O8::last()), 254);
+        assert_eq!(
+            O128::last().checked_distance(O128::first()),
+            Some(u128::MAX - 1)
+        );
+    }
+
+    // these panic in release builds as well, instead of wrapping around to a
+    // valid-looking ordinal
+    #[test]
+    #[should_panic(expected = "cannot subtract the 5th from the second")]
+    fn sub_ordinal_underflow() {
+        let _ = O32::from1(2) - O32::from1(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot subtract 2 from the second")]
+    fn sub_integer_underflow() {
+        let _ = O32::from1(2) - 2;
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add 1 to the 255th")]
+    fn add_integer_overflow() {
+        let _ = O8::last() + 1;
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add 255 to the first")]
+    fn add_integer_wrap_around() {
+        let _ = O8::first() + 255;
+    }
+
+    #[test]
+    fn assign_and_commutative_operators() {
+        let mut o = O32::from1(5);
+        o += 3;
+        assert_eq!(o, O32::from1(8));
+        o -= &2;
+        assert_eq!(o, O32::from1(6));
+
+        let r = &mut o;
+        *r += 1;
+        *r -= 5;
+        *r += &10;
+        assert_eq!(o, O32::from1(12));
+
+        assert_eq!(2 + ordinal!(first O32), ordinal!(third O32));
+        let (n, first) = (2u8, O8::first());
+        let (n_ref, first_ref) = (&n, &first);
+        assert_eq!(n + first_ref, O8::from1(3));
+        assert_eq!(n_ref + first, O8::from1(3));
+        assert_eq!(n_ref + first_ref, O8::from1(3));
+
+        let v = [1u8, 2, 3];
+        let shifted: Vec<O8> = v.iter().map(|n| n + O8::first()).collect();
+        assert_eq!(shifted, [O8::from1(2), O8::from1(3), O8::from1(4)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_assign_overflow() {
+        let mut o = O8::last();
+        o += 1;
+    }
+
+    #[test]
+    fn operator_panics_at_caller() {
+        use std::panic;
+        use std::sync::Mutex;
+
+        static LOCATIONS: Mutex<Vec<(String, u32)>> = Mutex::new(Vec::new());
+
+        let prev_hook = panic::take_hook();
+        panic::set_hook(Box::new(|info| {
+            if let Some(loc) = info.location() {
+                let mut locations = LOCATIONS.lock().unwrap_or_else(|e| e.into_inner());
+                locations.push((loc.file().to_string(), loc.line()));
+            }
+        }));
+        let start = line!();
+        let results = [
+            panic::catch_unwind(|| O8::last() + 1),
+            panic::catch_unwind(|| 1 + O8::last()),
+            panic::catch_unwind(|| {
+                let (o, n) = (&O8::last(), &1);
+                o + n
+            }),
+            panic::catch_unwind(|| {
+                let mut o = O8::last();
+                o += 1;
+                o
+            }),
+            panic::catch_unwind(|| {
+                let mut o = O8::first();
+                o -= &1;
+                o
+            }),
+        ];
+        let end = line!();
+        panic::set_hook(prev_hook);
+
+        assert!(results.iter().all(|r| r.is_err()));
+        let locations = LOCATIONS.lock().unwrap();
+        let in_test = locations
+            .iter()
+            .filter(|(file, line)| file == file!() && (start..end).contains(line))
+            .count();
+        assert_eq!(in_test, results.len(), "{:?}", locations);
+    }
+
+    mod rows {
+        use crate::{Ordinal, O32};
+        use core::fmt;
+
+        #[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+        pub struct RowOrdinal(O32);
+
+        impl fmt::Display for RowOrdinal {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "row {}", self.0.into1())
+            }
+        }
+
+        impl Ordinal for RowOrdinal {
+            type IntegerType = u32;
+
+            fn first() -> Self {
+                RowOrdinal(O32::first())
+            }
+
+            fn next(self) -> Self {
+                RowOrdinal(self.0.next())
+            }
+
+            fn into0(self) -> u32 {
+                self.0.into0()
+            }
+
+            fn into1(self) -> u32 {
+                self.0.into1()
+            }
+
+            fn try_from0(t: u32) -> Option<Self> {
+                O32::try_from0(t).map(RowOrdinal)
+            }
+
+            fn try_from1(t: u32) -> Option<Self> {
+                O32::try_from1(t).map(RowOrdinal)
+            }
+        }
+    }
+
+    mod seats {
+        use crate::{Integer, Ordinal, O8};
+        use core::fmt;
+
+        /// Custom integer type, which can be the `IntegerType` of an ordinal
+        #[derive(Eq, PartialEq, Clone, Copy, Debug)]
+        pub struct Seat(pub u8);
+
+        impl fmt::Display for Seat {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "seat {}", self.0)
+            }
+        }
+
+        impl Integer for Seat {
+            const MAX: Self = Seat(100);
+
+            const ONE: Self = Seat(1);
+
+            fn to_u128(self) -> u128 {
+                self.0 as u128
+            }
+
+            fn from_u128(n: u128) -> Option<Self> {
+                if n <= 100 {
+                    Some(Seat(n as u8))
+                } else {
+                    None
+                }
+            }
+        }
+
+        #[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+        pub struct SeatOrdinal(O8);
+
+        impl fmt::Display for SeatOrdinal {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl Ordinal for SeatOrdinal {
+            type IntegerType = Seat;
+
+            fn first() -> Self {
+                SeatOrdinal(O8::first())
+            }
+
+            fn next(self) -> Self {
+                SeatOrdinal::from1(Seat(self.0.into1() + 1))
+            }
+
+            fn into0(self) -> Seat {
+                Seat(self.0.into0())
+            }
+
+            fn into1(self) -> Seat {
+                Seat(self.0.into1())
+            }
+
+            fn try_from0(t: Seat) -> Option<Self> {
+                Self::try_from1(t.checked_inc()?)
+            }
+
+            fn try_from1(t: Seat) -> Option<Self> {
+                match t.0 {
+                    1..=100 => Some(SeatOrdinal(O8::from1(t.0))),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn custom_integer_type() {
+        use seats::{Seat, SeatOrdinal};
+
+        assert_eq!(SeatOrdinal::last().into1(), Seat(100));
+        assert_eq!(SeatOrdinal::from1(Seat(2)).to_adverb_words(), "secondly");
+        assert_eq!(
+            SeatOrdinal::first().checked_add(Seat(99)),
+            Some(SeatOrdinal::last())
+        );
+        assert_eq!(SeatOrdinal::last().try_next(), None);
+        assert_eq!(SeatOrdinal::try_from0(Seat(100)), None);
+    }
+
+    #[test]
+    fn macro_type_paths() {
+        use crate::O16 as Short;
+
+        assert_eq!(ordinal!(first Short), O16::from1(1));
+        assert_eq!(ordinal!(4-th Short), O16::from1(4));
+        assert_eq!(ordinal!(4-th crate::O16), O16::from1(4));
+        assert_eq!(ordinal!(5.O8), O8::from1(5));
+        assert_eq!(ordinal!(third rows::RowOrdinal).to_string(), "row 3");
+        assert_eq!(ordinal!(12-th self::rows::RowOrdinal).to_string(), "row 12");
+
+        let o: O64 = ordinal!(second);
+        assert_eq!(o, O64::from1(2));
+    }
+
+    #[test]
+    fn macro_words_and_expressions() {
+        use crate::O16 as Short;
+
+        assert_eq!(ordinal!(fifth O8), O8::from1(5));
+        assert_eq!(ordinal!(twelfth Short), O16::from1(12));
+        assert_eq!(ordinal!(twentieth crate::O16), O16::from1(20));
+        assert_eq!(ordinal!(ninetieth rows::RowOrdinal).to_string(), "row 90");
+
+        let n = 7;
+        assert_eq!(ordinal!((n) th O8), O8::from1(7));
+        assert_eq!(ordinal!((n as u16 + 1)-th Short), O16::from1(8));
+        assert_eq!(
+            ordinal!({ u32::from(n) * 2 } th rows::RowOrdinal).to_string(),
+            "row 14"
+        );
+        let o: O64 = ordinal!((n.into()) th);
+        assert_eq!(o, O64::from1(7));
+
+        assert_eq!(ordinal0!(0 O8), O8::first());
+        assert_eq!(ordinal0!(4 Short), O16::from1(5));
+        assert_eq!(ordinal0!((n.into()) crate::O16), O16::from1(8));
+        assert_eq!(
+            ordinal0!({ u32::from(n) } rows::RowOrdinal).to_string(),
+            "row 8"
+        );
+        let o: O64 = ordinal0!(2);
+        assert_eq!(o, O64::from1(3));
+    }
+}
+
+/// These tests only use `core`, so they also run without the `std` feature
+#[cfg(test)]
+mod no_std_tests {
+    use core::fmt::{self, Write};
+
+    use crate::*;
+
+    /// A fixed-size buffer, like the ones of `heapless`
+    struct Buf {
+        bytes: [u8; 64],
+        len: usize,
+    }
+
+    impl Buf {
+        fn new() -> Self {
+            Buf {
+                bytes: [0; 64],
+                len: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl Write for Buf {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    fn write(args: fmt::Arguments<'_>) -> Buf {
+        let mut buf = Buf::new();
+        buf.write_fmt(args).unwrap();
+        buf
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(write(format_args!("{}", O8::first())).as_str(), "first");
+        assert_eq!(write(format_args!("{}", O32::from1(23))).as_str(), "23rd");
+        assert_eq!(
+            write(format_args!("{:#}", O32::from1(23))).as_str(),
+            "twenty-third"
+        );
+        assert_eq!(
+            write(format_args!("{:>6}", O32::from1(4))).as_str(),
+            "   4th"
+        );
+        assert_eq!(
+            write(format_args!("{:-^7.3}", O32::from1(1000))).as_str(),
+            "--100--"
+        );
+        assert_eq!(
+            write(format_args!("{:?}", O128::last())).as_str(),
+            "340282366920938463463374607431768211455th"
+        );
+        assert_eq!(
+            write(format_args!("{}", display0(u8::MAX))).as_str(),
+            "256th"
+        );
+        assert_eq!(
+            write(format_args!("{}", O16::from1(3).tied())).as_str(),
+            "T-third"
+        );
+        assert_eq!(
+            write(format_args!(
+                "{}",
+                O32::from1(2).display_in(Locale::En).words()
+            ))
+            .as_str(),
+            "second"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("Twenty-Third".parse(), Ok(O32::from1(23)));
+        assert_eq!("23RD".parse(), Ok(O32::from1(23)));
+        assert_eq!(O8::parse_str("256th"), Err(ParseOrdinalError::Overflow));
+    }
 }
@@ -0,0 +1,34 @@
+//! German, which writes ordinals with a trailing period, e.g. "4."
+
+use core::fmt;
+
+use super::{write_number, Options};
+
+pub(super) fn write(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    write_number(w, n, opts)?;
+    w.write_char('.')
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    fn de(n: u32) -> String {
+        O32::from1(n).display_in(Locale::De).to_string()
+    }
+
+    #[test]
+    fn numeric() {
+        assert_eq!(de(1), "1.");
+        assert_eq!(de(2), "2.");
+        assert_eq!(de(3), "3.");
+        assert_eq!(de(4), "4.");
+        assert_eq!(de(121), "121.");
+        assert_eq!(
+            O32::from1(4).display_in(Locale::De).words().to_string(),
+            "4."
+        );
+        let grouped = O32::from1(1234).display_in(Locale::De).grouping('.');
+        assert_eq!(grouped.to_string(), "1.234.");
+    }
+}
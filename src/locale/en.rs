@@ -0,0 +1,37 @@
+use core::fmt;
+
+use super::{write_number, Form, Options};
+use crate::words;
+
+pub(super) fn write(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    match opts.form {
+        Form::Numeric => {
+            write_number(w, n, opts)?;
+            w.write_str(opts.suffix_style.suffix(n))
+        }
+        Form::Words => words::write_words(w, n, words::Form::Ordinal, opts.word_style),
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn numeric() {
+        let en = |n| O32::from1(n).display_in(Locale::En).to_string();
+        assert_eq!(en(1), "1st");
+        assert_eq!(en(2), "2nd");
+        assert_eq!(en(3), "3rd");
+        assert_eq!(en(4), "4th");
+        assert_eq!(en(121), "121st");
+    }
+
+    #[test]
+    fn words() {
+        let en = |n| O32::from1(n).display_in(Locale::En).words().to_string();
+        assert_eq!(en(1), "first");
+        assert_eq!(en(4), "fourth");
+        assert_eq!(en(121), "one hundred twenty-first");
+    }
+}
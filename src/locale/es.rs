@@ -0,0 +1,55 @@
+//! Spanish, which abbreviates ordinals with a period and a superscript ending,
+//! e.g. "4.º" (cuarto) or "4.ª" (cuarta)
+
+use core::fmt;
+
+use super::{write_number, Gender, Options};
+
+pub(super) fn write(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    write_number(w, n, opts)?;
+    w.write_str(match opts.gender {
+        Gender::Feminine => ".ª",
+        // "primero" and "tercero" lose their ending before a noun, e.g. "primer piso"
+        _ if opts.adjectival && apocopated(n) => ".er",
+        _ => ".º",
+    })
+}
+
+/// Returns `true` if the last word of the ordinal is "primero" or "tercero",
+/// e.g. "vigésimo primero". The 11th is "undécimo".
+fn apocopated(n: u128) -> bool {
+    matches!(n % 10, 1 | 3) && n % 100 != 11
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    fn es(n: u32) -> Localized {
+        O32::from1(n).display_in(Locale::Es)
+    }
+
+    #[test]
+    fn numeric() {
+        assert_eq!(es(1).to_string(), "1.º");
+        assert_eq!(es(1).gender(Gender::Feminine).to_string(), "1.ª");
+        assert_eq!(es(2).to_string(), "2.º");
+        assert_eq!(es(3).to_string(), "3.º");
+        assert_eq!(es(4).to_string(), "4.º");
+        assert_eq!(es(4).gender(Gender::Feminine).to_string(), "4.ª");
+        assert_eq!(es(121).to_string(), "121.º");
+        assert_eq!(es(121).gender(Gender::Feminine).to_string(), "121.ª");
+    }
+
+    #[test]
+    fn adjectival() {
+        assert_eq!(es(1).adjectival().to_string(), "1.er");
+        assert_eq!(es(2).adjectival().to_string(), "2.º");
+        assert_eq!(es(3).adjectival().to_string(), "3.er");
+        assert_eq!(es(11).adjectival().to_string(), "11.º");
+        assert_eq!(es(13).adjectival().to_string(), "13.er");
+        assert_eq!(es(21).adjectival().to_string(), "21.er");
+        let fem = es(1).adjectival().gender(Gender::Feminine);
+        assert_eq!(fem.to_string(), "1.ª");
+    }
+}
@@ -0,0 +1,179 @@
+use core::fmt;
+
+use super::{write_digits, write_number, Form, Options};
+
+const DIGITS: [char; 10] = ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹'];
+
+const ONES: [&str; 20] = [
+    "",
+    "یک",
+    "دو",
+    "سه",
+    "چهار",
+    "پنج",
+    "شش",
+    "هفت",
+    "هشت",
+    "نه",
+    "ده",
+    "یازده",
+    "دوازده",
+    "سیزده",
+    "چهارده",
+    "پانزده",
+    "شانزده",
+    "هفده",
+    "هجده",
+    "نوزده",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "بیست",
+    "سی",
+    "چهل",
+    "پنجاه",
+    "شصت",
+    "هفتاد",
+    "هشتاد",
+    "نود",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "صد",
+    "دویست",
+    "سیصد",
+    "چهارصد",
+    "پانصد",
+    "ششصد",
+    "هفتصد",
+    "هشتصد",
+    "نهصد",
+];
+
+pub(super) fn write(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    let suffix = if opts.adjectival { "امین" } else { "ام" };
+    match opts.form {
+        Form::Words if n < 1000 => write_words(w, n as usize, opts.adjectival),
+        _ if opts.native_digits => {
+            write_digits(w, n, opts, &DIGITS)?;
+            w.write_str(suffix)
+        }
+        _ => {
+            write_number(w, n, opts)?;
+            w.write_str(suffix)
+        }
+    }
+}
+
+/// Writes a number from 1 to 999 in words. The parts of the number are joined
+/// with "و" (and), and only the last part takes the ordinal suffix.
+fn write_words(w: &mut dyn fmt::Write, n: usize, adjectival: bool) -> fmt::Result {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let mut parts = [HUNDREDS[hundreds], "", ""];
+    if rest < 20 {
+        parts[1] = ONES[rest];
+    } else {
+        parts[1] = TENS[rest / 10];
+        parts[2] = ONES[rest % 10];
+    }
+    let mut parts = parts.iter().filter(|p| !p.is_empty()).peekable();
+
+    while let Some(part) = parts.next() {
+        if parts.peek().is_some() {
+            write!(w, "{} و ", part)?;
+        } else if n == 1 {
+            w.write_str("اول")?;
+        } else {
+            match *part {
+                "سه" => w.write_str("سوم")?,
+                // ZWNJ before the suffix, since "سی" ends with a vowel
+                "سی" => w.write_str("سی\u{200C}ام")?,
+                _ => write!(w, "{}م", part)?,
+            }
+        }
+    }
+    if adjectival {
+        w.write_str("ین")?;
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    fn words(n: u32) -> String {
+        O32::from1(n).display_in(Locale::Fa).words().to_string()
+    }
+
+    #[test]
+    fn words_irregular() {
+        assert_eq!(words(1), "اول");
+        assert_eq!(words(2), "دوم");
+        assert_eq!(words(3), "سوم");
+        assert_eq!(words(4), "چهارم");
+        assert_eq!(words(13), "سیزدهم");
+        assert_eq!(words(21), "بیست و یکم");
+        assert_eq!(words(23), "بیست و سوم");
+        assert_eq!(words(30), "سی\u{200C}ام");
+        assert_eq!(words(33), "سی و سوم");
+        assert_eq!(words(100), "صدم");
+        assert_eq!(words(103), "صد و سوم");
+        assert_eq!(words(999), "نهصد و نود و نهم");
+    }
+
+    #[test]
+    fn words_through_100() {
+        for n in 1..=100 {
+            let s = words(n);
+            assert!(s.ends_with('م') || s == "اول", "{}: {}", n, s);
+        }
+    }
+
+    #[test]
+    fn adjectival() {
+        let adj = |n| {
+            O32::from1(n)
+                .display_in(Locale::Fa)
+                .words()
+                .adjectival()
+                .to_string()
+        };
+        assert_eq!(adj(1), "اولین");
+        assert_eq!(adj(3), "سومین");
+        assert_eq!(adj(4), "چهارمین");
+        assert_eq!(adj(21), "بیست و یکمین");
+        assert_eq!(adj(30), "سی\u{200C}امین");
+    }
+
+    #[test]
+    fn numeric() {
+        let o = O32::from1(4).display_in(Locale::Fa);
+        assert_eq!(o.to_string(), "4ام");
+        assert_eq!(o.native_digits().to_string(), "۴ام");
+        assert_eq!(o.native_digits().adjectival().to_string(), "۴امین");
+        assert_eq!(
+            O32::from1(1056)
+                .display_in(Locale::Fa)
+                .native_digits()
+                .to_string(),
+            "۱۰۵۶ام"
+        );
+        assert_eq!(
+            O32::from1(1056)
+                .display_in(Locale::Fa)
+                .native_digits()
+                .grouping('٬')
+                .to_string(),
+            "۱٬۰۵۶ام"
+        );
+        // beyond the supported range, words fall back to digits
+        assert_eq!(
+            O32::from1(1000).display_in(Locale::Fa).words().to_string(),
+            "1000ام"
+        );
+    }
+}
@@ -0,0 +1,29 @@
+//! Finnish, which writes ordinals with a trailing period, e.g. "4."
+
+use core::fmt;
+
+use super::{write_number, Options};
+
+pub(super) fn write(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    write_number(w, n, opts)?;
+    w.write_char('.')
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    fn fi(n: u32) -> String {
+        O32::from1(n).display_in(Locale::Fi).to_string()
+    }
+
+    #[test]
+    fn numeric() {
+        assert_eq!(fi(1), "1.");
+        assert_eq!(fi(2), "2.");
+        assert_eq!(fi(3), "3.");
+        assert_eq!(fi(121), "121.");
+        let grouped = O32::from1(1234).display_in(Locale::Fi).grouping('\u{a0}');
+        assert_eq!(grouped.to_string(), "1\u{a0}234.");
+    }
+}
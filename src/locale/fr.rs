@@ -0,0 +1,37 @@
+//! French, which abbreviates ordinals with "e", except for the first ("1er" or "1re")
+
+use core::fmt;
+
+use super::{write_number, Gender, Options};
+
+pub(super) fn write(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    write_number(w, n, opts)?;
+    w.write_str(match (n, opts.gender) {
+        // premier, première
+        (1, Gender::Feminine) => "re",
+        (1, _) => "er",
+        // deuxième, vingt et unième
+        _ => "e",
+    })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    fn fr(n: u32) -> Localized {
+        O32::from1(n).display_in(Locale::Fr)
+    }
+
+    #[test]
+    fn numeric() {
+        assert_eq!(fr(1).to_string(), "1er");
+        assert_eq!(fr(1).gender(Gender::Feminine).to_string(), "1re");
+        assert_eq!(fr(2).to_string(), "2e");
+        assert_eq!(fr(3).to_string(), "3e");
+        assert_eq!(fr(4).gender(Gender::Feminine).to_string(), "4e");
+        assert_eq!(fr(11).to_string(), "11e");
+        assert_eq!(fr(21).to_string(), "21e");
+        assert_eq!(fr(121).to_string(), "121e");
+    }
+}
@@ -0,0 +1,108 @@
+//! Indonesian and Malay, which form ordinals by prefixing the cardinal number with "ke"
+
+use core::fmt;
+
+use super::{write_number, Form, Options};
+
+#[cfg(feature = "locale-id")]
+const ONES_ID: [&str; 10] = [
+    "", "satu", "dua", "tiga", "empat", "lima", "enam", "tujuh", "delapan", "sembilan",
+];
+
+#[cfg(feature = "locale-ms")]
+const ONES_MS: [&str; 10] = [
+    "", "satu", "dua", "tiga", "empat", "lima", "enam", "tujuh", "lapan", "sembilan",
+];
+
+#[cfg(feature = "locale-id")]
+pub(super) fn write_id(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    write(w, n, opts, &ONES_ID)
+}
+
+#[cfg(feature = "locale-ms")]
+pub(super) fn write_ms(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    write(w, n, opts, &ONES_MS)
+}
+
+fn write(w: &mut dyn fmt::Write, n: u128, opts: &Options, ones: &[&str; 10]) -> fmt::Result {
+    match opts.form {
+        Form::Words if n == 1 => w.write_str("pertama"),
+        Form::Words if n < 1_000_000 => {
+            w.write_str("ke")?;
+            write_cardinal(w, n as usize, ones)
+        }
+        _ => {
+            w.write_str("ke-")?;
+            write_number(w, n, opts)
+        }
+    }
+}
+
+/// Writes a number from 1 to 999,999 in words. Powers of ten with the
+/// multiplier "one" are written with the prefix "se", e.g. "seratus" (100).
+fn write_cardinal(w: &mut dyn fmt::Write, n: usize, ones: &[&str; 10]) -> fmt::Result {
+    let scales = [(1000, "ribu"), (100, "ratus"), (10, "puluh")];
+    for &(scale, name) in &scales {
+        if n >= scale {
+            let (count, rest) = (n / scale, n % scale);
+            if scale == 10 && count == 1 {
+                // 10 to 19
+                return match rest {
+                    0 => w.write_str("sepuluh"),
+                    1 => w.write_str("sebelas"),
+                    _ => write!(w, "{} belas", ones[rest]),
+                };
+            }
+            if count == 1 {
+                write!(w, "se{}", name)?;
+            } else {
+                write_cardinal(w, count, ones)?;
+                write!(w, " {}", name)?;
+            }
+            if rest > 0 {
+                w.write_char(' ')?;
+                write_cardinal(w, rest, ones)?;
+            }
+            return Ok(());
+        }
+    }
+    w.write_str(ones[n])
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    #[cfg(feature = "locale-id")]
+    #[test]
+    fn indonesian() {
+        let words = |n| O32::from1(n).display_in(Locale::Id).words().to_string();
+        assert_eq!(words(1), "pertama");
+        assert_eq!(words(2), "kedua");
+        assert_eq!(words(4), "keempat");
+        assert_eq!(words(8), "kedelapan");
+        assert_eq!(words(10), "kesepuluh");
+        assert_eq!(words(11), "kesebelas");
+        assert_eq!(words(12), "kedua belas");
+        assert_eq!(words(21), "kedua puluh satu");
+        assert_eq!(words(100), "keseratus");
+        assert_eq!(words(118), "keseratus delapan belas");
+        assert_eq!(words(1000), "keseribu");
+        assert_eq!(words(2345), "kedua ribu tiga ratus empat puluh lima");
+        assert_eq!(words(1_000_000), "ke-1000000");
+
+        let numeric = |n| O32::from1(n).display_in(Locale::Id).to_string();
+        assert_eq!(numeric(1), "ke-1");
+        assert_eq!(numeric(4), "ke-4");
+    }
+
+    #[cfg(feature = "locale-ms")]
+    #[test]
+    fn malay() {
+        let words = |n| O32::from1(n).display_in(Locale::Ms).words().to_string();
+        assert_eq!(words(1), "pertama");
+        assert_eq!(words(8), "kelapan");
+        assert_eq!(words(88), "kelapan puluh lapan");
+        assert_eq!(O32::from1(4).display_in(Locale::Ms).to_string(), "ke-4");
+    }
+}
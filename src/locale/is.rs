@@ -0,0 +1,136 @@
+use core::fmt;
+
+use super::{write_number, Form, Gender, Options};
+
+/// Masculine nominative forms. All except "annar" are weak adjectives ending with -i.
+const ONES: [&str; 20] = [
+    "",
+    "fyrsti",
+    "annar",
+    "þriðji",
+    "fjórði",
+    "fimmti",
+    "sjötti",
+    "sjöundi",
+    "áttundi",
+    "níundi",
+    "tíundi",
+    "ellefti",
+    "tólfti",
+    "þrettándi",
+    "fjórtándi",
+    "fimmtándi",
+    "sextándi",
+    "sautjándi",
+    "átjándi",
+    "nítjándi",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "tuttugasti",
+    "þrítugasti",
+    "fertugasti",
+    "fimmtugasti",
+    "sextugasti",
+    "sjötugasti",
+    "áttugasti",
+    "nítugasti",
+];
+
+pub(super) fn write(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    match opts.form {
+        Form::Words if n <= 100 => write_words(w, n as usize, opts.gender),
+        _ => {
+            write_number(w, n, opts)?;
+            w.write_char('.')
+        }
+    }
+}
+
+/// Writes a number from 1 to 100 in words. In compound numbers, both parts
+/// are ordinals and inflect for gender, e.g. "tuttugasti og fyrsti".
+fn write_words(w: &mut dyn fmt::Write, n: usize, gender: Gender) -> fmt::Result {
+    let (tens, ones) = (n / 10, n % 10);
+    match n {
+        100 => write_gendered(w, "hundraðasti", gender),
+        1..=19 => write_gendered(w, ONES[n], gender),
+        _ => {
+            write_gendered(w, TENS[tens], gender)?;
+            if ones > 0 {
+                w.write_str(" og ")?;
+                write_gendered(w, ONES[ones], gender)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn write_gendered(w: &mut dyn fmt::Write, masculine: &str, gender: Gender) -> fmt::Result {
+    if masculine == "annar" {
+        return w.write_str(match gender {
+            Gender::Masculine => "annar",
+            Gender::Feminine => "önnur",
+            Gender::Neuter => "annað",
+        });
+    }
+    let stem = &masculine[..masculine.len() - 1];
+    match gender {
+        Gender::Masculine => write!(w, "{}i", stem),
+        Gender::Feminine | Gender::Neuter => write!(w, "{}a", stem),
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    fn words(n: u32, gender: Gender) -> String {
+        O32::from1(n)
+            .display_in(Locale::Is)
+            .words()
+            .gender(gender)
+            .to_string()
+    }
+
+    #[test]
+    fn irregulars() {
+        use Gender::*;
+
+        assert_eq!(words(1, Masculine), "fyrsti");
+        assert_eq!(words(1, Feminine), "fyrsta");
+        assert_eq!(words(1, Neuter), "fyrsta");
+        assert_eq!(words(2, Masculine), "annar");
+        assert_eq!(words(2, Feminine), "önnur");
+        assert_eq!(words(2, Neuter), "annað");
+        assert_eq!(words(3, Masculine), "þriðji");
+        assert_eq!(words(3, Feminine), "þriðja");
+        assert_eq!(words(4, Masculine), "fjórði");
+    }
+
+    #[test]
+    fn teens_and_tens() {
+        use Gender::*;
+
+        assert_eq!(words(11, Masculine), "ellefti");
+        assert_eq!(words(12, Masculine), "tólfti");
+        assert_eq!(words(13, Masculine), "þrettándi");
+        assert_eq!(words(17, Feminine), "sautjánda");
+        assert_eq!(words(20, Masculine), "tuttugasti");
+        assert_eq!(words(21, Masculine), "tuttugasti og fyrsti");
+        assert_eq!(words(22, Feminine), "tuttugasta og önnur");
+        assert_eq!(words(22, Neuter), "tuttugasta og annað");
+        assert_eq!(words(30, Masculine), "þrítugasti");
+        assert_eq!(words(100, Neuter), "hundraðasta");
+    }
+
+    #[test]
+    fn numeric() {
+        assert_eq!(O32::from1(4).display_in(Locale::Is).to_string(), "4.");
+        assert_eq!(
+            O32::from1(101).display_in(Locale::Is).words().to_string(),
+            "101."
+        );
+    }
+}
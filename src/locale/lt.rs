@@ -0,0 +1,139 @@
+use core::fmt;
+
+use super::{write_compound_prefix, write_number, Compound, Form, Gender, Options};
+
+/// Masculine indefinite forms, which all end with -as
+const ONES: [&str; 20] = [
+    "",
+    "pirmas",
+    "antras",
+    "trečias",
+    "ketvirtas",
+    "penktas",
+    "šeštas",
+    "septintas",
+    "aštuntas",
+    "devintas",
+    "dešimtas",
+    "vienuoliktas",
+    "dvyliktas",
+    "tryliktas",
+    "keturioliktas",
+    "penkioliktas",
+    "šešioliktas",
+    "septynioliktas",
+    "aštuonioliktas",
+    "devynioliktas",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "dvidešimt",
+    "trisdešimt",
+    "keturiasdešimt",
+    "penkiasdešimt",
+    "šešiasdešimt",
+    "septyniasdešimt",
+    "aštuoniasdešimt",
+    "devyniasdešimt",
+];
+
+const TENS_ORDINAL: [&str; 10] = [
+    "",
+    "",
+    "dvidešimtas",
+    "trisdešimtas",
+    "keturiasdešimtas",
+    "penkiasdešimtas",
+    "šešiasdešimtas",
+    "septyniasdešimtas",
+    "aštuoniasdešimtas",
+    "devyniasdešimtas",
+];
+
+const COMPOUND: Compound<'static> = Compound {
+    ones: &ONES,
+    tens: &TENS,
+    tens_ordinal: &TENS_ORDINAL,
+};
+
+pub(super) fn write(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    match opts.form {
+        Form::Words if n <= 100 => {
+            let ordinal = match n {
+                100 => "šimtas",
+                _ => write_compound_prefix(w, n as usize, &COMPOUND)?,
+            };
+            let stem = &ordinal[..ordinal.len() - 2];
+            write!(w, "{}{}", stem, ending(opts))
+        }
+        _ => {
+            write_number(w, n, opts)?;
+            if opts.period {
+                w.write_char('.')
+            } else {
+                write!(w, "-{}", ending(opts))
+            }
+        }
+    }
+}
+
+/// Returns the ending for the gender and definiteness. Lithuanian has no
+/// neuter ordinals, so the neuter gender uses the masculine forms.
+fn ending(opts: &Options) -> &'static str {
+    match (opts.gender, opts.definite) {
+        (Gender::Feminine, false) => "a",
+        (Gender::Feminine, true) => "oji",
+        (_, false) => "as",
+        (_, true) => "asis",
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    fn lt(n: u32) -> Localized {
+        O32::from1(n).display_in(Locale::Lt)
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!(lt(1).words().to_string(), "pirmas");
+        assert_eq!(lt(1).words().gender(Gender::Feminine).to_string(), "pirma");
+        assert_eq!(lt(1).words().definite().to_string(), "pirmasis");
+        let fem = lt(1).words().gender(Gender::Feminine).definite();
+        assert_eq!(fem.to_string(), "pirmoji");
+
+        assert_eq!(lt(2).words().to_string(), "antras");
+        assert_eq!(lt(3).words().to_string(), "trečias");
+        assert_eq!(lt(3).words().gender(Gender::Feminine).to_string(), "trečia");
+        assert_eq!(lt(3).words().definite().to_string(), "trečiasis");
+        let fem = lt(3).words().gender(Gender::Feminine).definite();
+        assert_eq!(fem.to_string(), "trečioji");
+    }
+
+    #[test]
+    fn compound_words() {
+        assert_eq!(lt(12).words().to_string(), "dvyliktas");
+        assert_eq!(lt(20).words().to_string(), "dvidešimtas");
+        assert_eq!(lt(21).words().to_string(), "dvidešimt pirmas");
+        let fem = lt(43).words().gender(Gender::Feminine).definite();
+        assert_eq!(fem.to_string(), "keturiasdešimt trečioji");
+        assert_eq!(lt(100).words().definite().to_string(), "šimtasis");
+        assert_eq!(lt(101).words().to_string(), "101-as");
+    }
+
+    #[test]
+    fn numeric() {
+        assert_eq!(lt(4).to_string(), "4-as");
+        assert_eq!(lt(4).gender(Gender::Feminine).to_string(), "4-a");
+        assert_eq!(lt(4).definite().to_string(), "4-asis");
+        assert_eq!(
+            lt(4).gender(Gender::Feminine).definite().to_string(),
+            "4-oji"
+        );
+        assert_eq!(lt(4).period().to_string(), "4.");
+    }
+}
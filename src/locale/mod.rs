@@ -0,0 +1,288 @@
+use core::fmt;
+#[cfg(feature = "auto-locale")]
+use std::sync::OnceLock;
+
+use crate::{SuffixStyle, WordStyle};
+
+#[cfg(feature = "locale-de")]
+mod de;
+mod en;
+#[cfg(feature = "locale-es")]
+mod es;
+#[cfg(feature = "locale-fa")]
+mod fa;
+#[cfg(feature = "locale-fi")]
+mod fi;
+#[cfg(feature = "locale-fr")]
+mod fr;
+#[cfg(any(feature = "locale-id", feature = "locale-ms"))]
+mod id;
+#[cfg(feature = "locale-is")]
+mod is;
+#[cfg(feature = "locale-lt")]
+mod lt;
+mod plural;
+#[cfg(any(feature = "locale-sr", feature = "locale-hr"))]
+mod sr;
+#[cfg(feature = "locale-sw")]
+mod sw;
+mod tag;
+
+pub(crate) use plural::english_category;
+pub use plural::OrdinalCategory;
+
+/// A language in which ordinal numbers can be formatted.
+///
+/// English is always available. All other languages are behind cargo features
+/// named `locale-<language code>`, and the `locale-all` feature enables all of
+/// them. Variants of languages whose feature isn't enabled don't exist, so using
+/// a language that wasn't compiled in is a compile-time error rather than a
+/// runtime error. This keeps the word tables of unused languages out of the binary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Locale {
+    /// English, e.g. `4th` or `fourth`
+    #[default]
+    En,
+    /// German, e.g. `4.`. Ordinals are always written with digits.
+    #[cfg(feature = "locale-de")]
+    De,
+    /// Spanish, e.g. `4.º` or `4.ª`, depending on the gender. Ordinals are
+    /// always written with digits.
+    #[cfg(feature = "locale-es")]
+    Es,
+    /// Finnish, e.g. `4.`. Ordinals are always written with digits.
+    #[cfg(feature = "locale-fi")]
+    Fi,
+    /// French, e.g. `1er`, `1re` or `4e`. Ordinals are always written with digits.
+    #[cfg(feature = "locale-fr")]
+    Fr,
+    /// Persian, e.g. `4ام` or `چهارم`
+    #[cfg(feature = "locale-fa")]
+    Fa,
+    /// Indonesian, e.g. `ke-4` or `keempat`
+    #[cfg(feature = "locale-id")]
+    Id,
+    /// Malay, e.g. `ke-4` or `keempat`
+    #[cfg(feature = "locale-ms")]
+    Ms,
+    /// Serbian in Cyrillic script, e.g. `4.` or `четврти`
+    #[cfg(feature = "locale-sr")]
+    SrCyrl,
+    /// Serbian in Latin script, e.g. `4.` or `četvrti`
+    #[cfg(feature = "locale-sr")]
+    SrLatn,
+    /// Croatian, e.g. `4.` or `četvrti`
+    #[cfg(feature = "locale-hr")]
+    Hr,
+    /// Icelandic, e.g. `4.` or `fjórði`
+    #[cfg(feature = "locale-is")]
+    Is,
+    /// Lithuanian, e.g. `4-as` or `ketvirtas`
+    #[cfg(feature = "locale-lt")]
+    Lt,
+    /// Swahili, e.g. `wa 4` or `wa nne`
+    #[cfg(feature = "locale-sw")]
+    Sw,
+}
+
+impl Locale {
+    /// Returns the locale of the operating system, which is detected with the
+    /// `sys-locale` crate and mapped with [from_language_tag](Locale::from_language_tag).
+    ///
+    /// The locale is only detected once and then cached. If it can't be
+    /// detected, [Locale::En] is returned.
+    #[cfg(feature = "auto-locale")]
+    pub fn system() -> Locale {
+        static SYSTEM: OnceLock<Locale> = OnceLock::new();
+        *SYSTEM.get_or_init(|| {
+            sys_locale::get_locale().map_or(Locale::En, |tag| Locale::from_language_tag(&tag))
+        })
+    }
+}
+
+/// Grammatical gender of a spelled-out ordinal number, in locales where
+/// ordinals inflect for gender
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Gender {
+    /// Masculine gender
+    #[default]
+    Masculine,
+    /// Feminine gender
+    Feminine,
+    /// Neuter gender
+    Neuter,
+}
+
+/// Noun class of a spelled-out ordinal number, in locales where ordinals agree
+/// with the noun class of the noun they modify.
+///
+/// In Swahili, the connective "-a" before the number agrees with the noun, e.g.
+/// "mtu wa kwanza" (the first person), "kitabu cha kwanza" (the first book),
+/// "nyumba ya kwanza" (the first house). The classes are named after their
+/// singular and plural prefixes, as in most Swahili grammars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NounClass {
+    /// M-Wa class, singular (class 1), e.g. `mtu wa kwanza`
+    #[default]
+    MWa,
+    /// M-Wa class, plural (class 2), e.g. `watu wa kwanza`
+    MWaPlural,
+    /// M-Mi class, singular (class 3), e.g. `mti wa kwanza`
+    MMi,
+    /// M-Mi class, plural (class 4), e.g. `miti ya kwanza`
+    MMiPlural,
+    /// Ji-Ma class, singular (class 5), e.g. `gari la kwanza`
+    JiMa,
+    /// Ji-Ma class, plural (class 6), e.g. `magari ya kwanza`
+    JiMaPlural,
+    /// Ki-Vi class, singular (class 7), e.g. `kitabu cha kwanza`
+    KiVi,
+    /// Ki-Vi class, plural (class 8), e.g. `vitabu vya kwanza`
+    KiViPlural,
+    /// N class, singular (class 9), e.g. `nyumba ya kwanza`
+    N,
+    /// N class, plural (class 10), e.g. `nyumba za kwanza`
+    NPlural,
+    /// U class (class 11), e.g. `ukuta wa kwanza`. Its plural is [NounClass::NPlural].
+    U,
+    /// Ku class of verbal nouns and the locative of general place (classes 15 and 17),
+    /// e.g. `kusoma kwa kwanza`
+    Ku,
+    /// Locative of definite place (class 16), e.g. `mahali pa kwanza`
+    Pa,
+    /// Locative of inside (class 18), e.g. `mwa kwanza`
+    Mu,
+}
+
+/// Whether an ordinal number is written with digits or spelled out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(crate) enum Form {
+    #[default]
+    Numeric,
+    Words,
+}
+
+/// Options shared by all locales. Options that don't apply to a locale are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(crate) struct Options {
+    pub(crate) form: Form,
+    pub(crate) native_digits: bool,
+    pub(crate) adjectival: bool,
+    pub(crate) gender: Gender,
+    pub(crate) noun_class: NounClass,
+    pub(crate) definite: bool,
+    pub(crate) period: bool,
+    pub(crate) grouping: Option<char>,
+    pub(crate) suffix_style: SuffixStyle,
+    pub(crate) word_style: WordStyle,
+}
+
+/// Writes a 1-based ordinal number in the given locale
+pub(crate) fn write(
+    w: &mut dyn fmt::Write,
+    locale: Locale,
+    n: u128,
+    opts: &Options,
+) -> fmt::Result {
+    match locale {
+        Locale::En => en::write(w, n, opts),
+        #[cfg(feature = "locale-de")]
+        Locale::De => de::write(w, n, opts),
+        #[cfg(feature = "locale-es")]
+        Locale::Es => es::write(w, n, opts),
+        #[cfg(feature = "locale-fi")]
+        Locale::Fi => fi::write(w, n, opts),
+        #[cfg(feature = "locale-fr")]
+        Locale::Fr => fr::write(w, n, opts),
+        #[cfg(feature = "locale-fa")]
+        Locale::Fa => fa::write(w, n, opts),
+        #[cfg(feature = "locale-id")]
+        Locale::Id => id::write_id(w, n, opts),
+        #[cfg(feature = "locale-ms")]
+        Locale::Ms => id::write_ms(w, n, opts),
+        #[cfg(feature = "locale-sr")]
+        Locale::SrCyrl => sr::write_cyrillic(w, n, opts),
+        #[cfg(feature = "locale-sr")]
+        Locale::SrLatn => sr::write_latin(w, n, opts),
+        #[cfg(feature = "locale-hr")]
+        Locale::Hr => sr::write_latin(w, n, opts),
+        #[cfg(feature = "locale-is")]
+        Locale::Is => is::write(w, n, opts),
+        #[cfg(feature = "locale-lt")]
+        Locale::Lt => lt::write(w, n, opts),
+        #[cfg(feature = "locale-sw")]
+        Locale::Sw => sw::write(w, n, opts),
+    }
+}
+
+/// Word tables for languages where only the last word of a compound number is
+/// an ordinal, and the preceding words are cardinals, e.g. "dvadeset prvi".
+#[cfg(any(feature = "locale-sr", feature = "locale-hr", feature = "locale-lt"))]
+pub(crate) struct Compound<'a> {
+    /// Ordinals from 1 to 19
+    pub(crate) ones: &'a [&'a str; 20],
+    /// Cardinal tens (20, 30, ...), used before the last word
+    pub(crate) tens: &'a [&'a str; 10],
+    /// Ordinal tens (20th, 30th, ...)
+    pub(crate) tens_ordinal: &'a [&'a str; 10],
+}
+
+/// Writes the cardinal words of a number from 1 to 99, and returns the last
+/// word in its ordinal form, so it can be inflected before it is written.
+#[cfg(any(feature = "locale-sr", feature = "locale-hr", feature = "locale-lt"))]
+pub(crate) fn write_compound_prefix<'a>(
+    w: &mut dyn fmt::Write,
+    n: usize,
+    tables: &Compound<'a>,
+) -> Result<&'a str, fmt::Error> {
+    let (tens, ones) = (n / 10, n % 10);
+    Ok(match n {
+        1..=19 => tables.ones[n],
+        _ if ones == 0 => tables.tens_ordinal[tens],
+        _ => {
+            write!(w, "{} ", tables.tens[tens])?;
+            tables.ones[ones]
+        }
+    })
+}
+
+const ASCII_DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+/// Writes a number with ASCII digits
+pub(crate) fn write_number(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    write_digits(w, n, opts, &ASCII_DIGITS)
+}
+
+/// Writes a number with the given decimal digits, separating groups of three
+/// digits if grouping is enabled
+pub(crate) fn write_digits(
+    w: &mut dyn fmt::Write,
+    n: u128,
+    opts: &Options,
+    digits: &[char; 10],
+) -> fmt::Result {
+    if opts.grouping.is_none() && digits == &ASCII_DIGITS {
+        return write!(w, "{}", n);
+    }
+
+    let mut buf = [0; 39];
+    let mut len = 0;
+    let mut rest = n;
+    loop {
+        buf[len] = (rest % 10) as usize;
+        len += 1;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    for i in (0..len).rev() {
+        w.write_char(digits[buf[i]])?;
+        match opts.grouping {
+            Some(separator) if i > 0 && i % 3 == 0 => w.write_char(separator)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use super::Locale;
+
+/// The plural category of an ordinal number, as defined by the Unicode CLDR.
+///
+/// Languages use these categories to select the form of an ordinal number. In
+/// English, they correspond to the suffixes `st`, `nd`, `rd` and `th`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OrdinalCategory {
+    /// The `one` category, e.g. `1st` and `21st` in English
+    One,
+    /// The `two` category, e.g. `2nd` and `22nd` in English
+    Two,
+    /// The `few` category, e.g. `3rd` and `23rd` in English
+    Few,
+    /// The `many` category, which isn't used by any supported locale yet
+    Many,
+    /// The `other` category, e.g. `4th` and `11th` in English
+    Other,
+}
+
+impl OrdinalCategory {
+    /// Returns the CLDR keyword of the category, e.g. `"one"`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OrdinalCategory::One => "one",
+            OrdinalCategory::Two => "two",
+            OrdinalCategory::Few => "few",
+            OrdinalCategory::Many => "many",
+            OrdinalCategory::Other => "other",
+        }
+    }
+}
+
+impl Locale {
+    /// Returns the plural category of a 1-based ordinal number in this locale
+    ///
+    /// ```
+    /// use num_ordinal::{Locale, OrdinalCategory};
+    ///
+    /// assert_eq!(Locale::En.ordinal_category(22), OrdinalCategory::Two);
+    /// assert_eq!(Locale::En.ordinal_category(12), OrdinalCategory::Other);
+    /// ```
+    pub fn ordinal_category(self, n: u128) -> OrdinalCategory {
+        match self {
+            Locale::En => english_category(n),
+            #[cfg(feature = "locale-fr")]
+            Locale::Fr if n == 1 => OrdinalCategory::One,
+            #[cfg(feature = "locale-ms")]
+            Locale::Ms if n == 1 => OrdinalCategory::One,
+            #[allow(unreachable_patterns)]
+            _ => OrdinalCategory::Other,
+        }
+    }
+}
+
+/// Returns the plural category of a 1-based ordinal number in English. The
+/// English suffixes are derived from it.
+pub(crate) const fn english_category(n: u128) -> OrdinalCategory {
+    match (n % 10, n % 100) {
+        (1, t) if t != 11 => OrdinalCategory::One,
+        (2, t) if t != 12 => OrdinalCategory::Two,
+        (3, t) if t != 13 => OrdinalCategory::Few,
+        _ => OrdinalCategory::Other,
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn english() {
+        let category = |n| Locale::En.ordinal_category(n).as_str();
+        assert_eq!(category(1), "one");
+        assert_eq!(category(2), "two");
+        assert_eq!(category(3), "few");
+        assert_eq!(category(4), "other");
+        assert_eq!(category(11), "other");
+        assert_eq!(category(12), "other");
+        assert_eq!(category(13), "other");
+        assert_eq!(category(101), "one");
+        assert_eq!(category(112), "other");
+    }
+
+    #[test]
+    #[cfg(feature = "locale-fr")]
+    fn french() {
+        assert_eq!(Locale::Fr.ordinal_category(1), OrdinalCategory::One);
+        assert_eq!(Locale::Fr.ordinal_category(2), OrdinalCategory::Other);
+        assert_eq!(Locale::Fr.ordinal_category(21), OrdinalCategory::Other);
+    }
+
+    #[test]
+    #[cfg(feature = "locale-ms")]
+    fn malay() {
+        assert_eq!(Locale::Ms.ordinal_category(1), OrdinalCategory::One);
+        assert_eq!(Locale::Ms.ordinal_category(21), OrdinalCategory::Other);
+    }
+}
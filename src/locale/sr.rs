@@ -0,0 +1,214 @@
+//! Serbian and Croatian. The tables are identical for numbers up to 100;
+//! Serbian in Cyrillic script is transliterated from the Latin tables.
+
+use core::fmt;
+
+use super::{write_compound_prefix, write_number, Compound, Form, Gender, Options};
+
+const ONES: [&str; 20] = [
+    "",
+    "prvi",
+    "drugi",
+    "treći",
+    "četvrti",
+    "peti",
+    "šesti",
+    "sedmi",
+    "osmi",
+    "deveti",
+    "deseti",
+    "jedanaesti",
+    "dvanaesti",
+    "trinaesti",
+    "četrnaesti",
+    "petnaesti",
+    "šesnaesti",
+    "sedamnaesti",
+    "osamnaesti",
+    "devetnaesti",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "dvadeset",
+    "trideset",
+    "četrdeset",
+    "pedeset",
+    "šezdeset",
+    "sedamdeset",
+    "osamdeset",
+    "devedeset",
+];
+
+const TENS_ORDINAL: [&str; 10] = [
+    "",
+    "",
+    "dvadeseti",
+    "trideseti",
+    "četrdeseti",
+    "pedeseti",
+    "šezdeseti",
+    "sedamdeseti",
+    "osamdeseti",
+    "devedeseti",
+];
+
+const COMPOUND: Compound<'static> = Compound {
+    ones: &ONES,
+    tens: &TENS,
+    tens_ordinal: &TENS_ORDINAL,
+};
+
+pub(super) fn write_latin(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    write(w, n, opts)
+}
+
+#[cfg(feature = "locale-sr")]
+pub(super) fn write_cyrillic(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    write(&mut Cyrillic(w), n, opts)
+}
+
+fn write(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    match opts.form {
+        Form::Words if n <= 100 => write_words(w, n as usize, opts.gender),
+        _ => {
+            write_number(w, n, opts)?;
+            w.write_char('.')
+        }
+    }
+}
+
+/// Writes a number from 1 to 100 in words
+fn write_words(w: &mut dyn fmt::Write, n: usize, gender: Gender) -> fmt::Result {
+    let ordinal = match n {
+        100 => "stoti",
+        _ => write_compound_prefix(w, n, &COMPOUND)?,
+    };
+    write_gendered(w, ordinal, gender)
+}
+
+/// Writes an ordinal adjective, which is given in its masculine form ending with -i
+fn write_gendered(w: &mut dyn fmt::Write, masculine: &str, gender: Gender) -> fmt::Result {
+    let stem = &masculine[..masculine.len() - 1];
+    let ending = match gender {
+        Gender::Masculine => "i",
+        Gender::Feminine => "a",
+        // soft stems take -e instead of -o
+        Gender::Neuter if stem.ends_with('ć') => "e",
+        Gender::Neuter => "o",
+    };
+    write!(w, "{}{}", stem, ending)
+}
+
+/// Writer that transliterates Serbian Latin script to Cyrillic script. The
+/// digraphs lj, nj and dž don't appear in the tables, so they aren't handled.
+#[cfg(feature = "locale-sr")]
+struct Cyrillic<'a>(&'a mut dyn fmt::Write);
+
+#[cfg(feature = "locale-sr")]
+impl fmt::Write for Cyrillic<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let c = match c {
+                'a' => 'а',
+                'b' => 'б',
+                'c' => 'ц',
+                'č' => 'ч',
+                'ć' => 'ћ',
+                'd' => 'д',
+                'đ' => 'ђ',
+                'e' => 'е',
+                'f' => 'ф',
+                'g' => 'г',
+                'h' => 'х',
+                'i' => 'и',
+                'j' => 'ј',
+                'k' => 'к',
+                'l' => 'л',
+                'm' => 'м',
+                'n' => 'н',
+                'o' => 'о',
+                'p' => 'п',
+                'r' => 'р',
+                's' => 'с',
+                'š' => 'ш',
+                't' => 'т',
+                'u' => 'у',
+                'v' => 'в',
+                'z' => 'з',
+                'ž' => 'ж',
+                c => c,
+            };
+            self.0.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    #[cfg(feature = "locale-sr")]
+    #[test]
+    fn serbian() {
+        let latn = |n, g| {
+            O32::from1(n)
+                .display_in(Locale::SrLatn)
+                .words()
+                .gender(g)
+                .to_string()
+        };
+        let cyrl = |n, g| {
+            O32::from1(n)
+                .display_in(Locale::SrCyrl)
+                .words()
+                .gender(g)
+                .to_string()
+        };
+
+        assert_eq!(latn(1, Gender::Masculine), "prvi");
+        assert_eq!(latn(2, Gender::Feminine), "druga");
+        assert_eq!(latn(3, Gender::Neuter), "treće");
+        assert_eq!(latn(4, Gender::Masculine), "četvrti");
+        assert_eq!(latn(4, Gender::Feminine), "četvrta");
+        assert_eq!(latn(4, Gender::Neuter), "četvrto");
+        assert_eq!(latn(21, Gender::Masculine), "dvadeset prvi");
+        assert_eq!(latn(40, Gender::Feminine), "četrdeseta");
+        assert_eq!(latn(100, Gender::Neuter), "stoto");
+
+        assert_eq!(cyrl(1, Gender::Masculine), "први");
+        assert_eq!(cyrl(3, Gender::Feminine), "трећа");
+        assert_eq!(cyrl(4, Gender::Masculine), "четврти");
+        assert_eq!(cyrl(4, Gender::Feminine), "четврта");
+        assert_eq!(cyrl(4, Gender::Neuter), "четврто");
+        assert_eq!(cyrl(23, Gender::Masculine), "двадесет трећи");
+
+        assert_eq!(O32::from1(4).display_in(Locale::SrCyrl).to_string(), "4.");
+        assert_eq!(
+            O32::from1(101)
+                .display_in(Locale::SrLatn)
+                .words()
+                .to_string(),
+            "101."
+        );
+    }
+
+    #[cfg(feature = "locale-hr")]
+    #[test]
+    fn croatian() {
+        let hr = |n, g| {
+            O32::from1(n)
+                .display_in(Locale::Hr)
+                .words()
+                .gender(g)
+                .to_string()
+        };
+        assert_eq!(hr(2, Gender::Masculine), "drugi");
+        assert_eq!(hr(3, Gender::Neuter), "treće");
+        assert_eq!(hr(11, Gender::Feminine), "jedanaesta");
+        assert_eq!(hr(99, Gender::Masculine), "devedeset deveti");
+        assert_eq!(O32::from1(121).display_in(Locale::Hr).to_string(), "121.");
+    }
+}
@@ -0,0 +1,118 @@
+//! Swahili, which forms ordinals with the connective "-a" and the cardinal
+//! number, e.g. "wa nne" (fourth)
+
+use core::fmt;
+
+use super::{write_number, Form, NounClass, Options};
+
+const ONES: [&str; 10] = [
+    "", "moja", "mbili", "tatu", "nne", "tano", "sita", "saba", "nane", "tisa",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "kumi",
+    "ishirini",
+    "thelathini",
+    "arobaini",
+    "hamsini",
+    "sitini",
+    "sabini",
+    "themanini",
+    "tisini",
+];
+
+pub(super) fn write(w: &mut dyn fmt::Write, n: u128, opts: &Options) -> fmt::Result {
+    write!(w, "{} ", connective(opts.noun_class))?;
+    match opts.form {
+        Form::Words if n <= 100 => w.write_str(match n {
+            // only first and second are irregular; 21st is "ishirini na moja"
+            1 => "kwanza",
+            2 => "pili",
+            100 => "mia moja",
+            _ => return write_cardinal(w, n as usize),
+        }),
+        _ => write_number(w, n, opts),
+    }
+}
+
+/// Writes a number from 3 to 99 in words
+fn write_cardinal(w: &mut dyn fmt::Write, n: usize) -> fmt::Result {
+    let (tens, ones) = (n / 10, n % 10);
+    match (tens, ones) {
+        (0, _) => w.write_str(ONES[ones]),
+        (_, 0) => w.write_str(TENS[tens]),
+        _ => write!(w, "{} na {}", TENS[tens], ONES[ones]),
+    }
+}
+
+/// Returns the form of the connective "-a" that agrees with the noun class
+fn connective(class: NounClass) -> &'static str {
+    match class {
+        NounClass::MWa | NounClass::MWaPlural | NounClass::MMi | NounClass::U => "wa",
+        NounClass::MMiPlural | NounClass::JiMaPlural | NounClass::N => "ya",
+        NounClass::JiMa => "la",
+        NounClass::KiVi => "cha",
+        NounClass::KiViPlural => "vya",
+        NounClass::NPlural => "za",
+        NounClass::Ku => "kwa",
+        NounClass::Pa => "pa",
+        NounClass::Mu => "mwa",
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    fn sw(n: u32) -> Localized {
+        O32::from1(n).display_in(Locale::Sw)
+    }
+
+    #[test]
+    fn words() {
+        let words = |n| sw(n).words().to_string();
+        assert_eq!(words(1), "wa kwanza");
+        assert_eq!(words(2), "wa pili");
+        assert_eq!(words(3), "wa tatu");
+        assert_eq!(words(8), "wa nane");
+        assert_eq!(words(10), "wa kumi");
+        assert_eq!(words(11), "wa kumi na moja");
+        assert_eq!(words(12), "wa kumi na mbili");
+        assert_eq!(words(21), "wa ishirini na moja");
+        assert_eq!(words(40), "wa arobaini");
+        assert_eq!(words(99), "wa tisini na tisa");
+        assert_eq!(words(100), "wa mia moja");
+        assert_eq!(words(101), "wa 101");
+    }
+
+    #[test]
+    fn noun_classes() {
+        let first = |class| sw(1).words().noun_class(class).to_string();
+        assert_eq!(first(NounClass::MWa), "wa kwanza");
+        assert_eq!(first(NounClass::MWaPlural), "wa kwanza");
+        assert_eq!(first(NounClass::MMi), "wa kwanza");
+        assert_eq!(first(NounClass::MMiPlural), "ya kwanza");
+        assert_eq!(first(NounClass::JiMa), "la kwanza");
+        assert_eq!(first(NounClass::JiMaPlural), "ya kwanza");
+        assert_eq!(first(NounClass::KiVi), "cha kwanza");
+        assert_eq!(first(NounClass::KiViPlural), "vya kwanza");
+        assert_eq!(first(NounClass::N), "ya kwanza");
+        assert_eq!(first(NounClass::NPlural), "za kwanza");
+        assert_eq!(first(NounClass::U), "wa kwanza");
+        assert_eq!(first(NounClass::Ku), "kwa kwanza");
+        assert_eq!(first(NounClass::Pa), "pa kwanza");
+        assert_eq!(first(NounClass::Mu), "mwa kwanza");
+        assert_eq!(
+            sw(3).words().noun_class(NounClass::KiVi).to_string(),
+            "cha tatu"
+        );
+    }
+
+    #[test]
+    fn numeric() {
+        assert_eq!(sw(4).to_string(), "wa 4");
+        assert_eq!(sw(4).noun_class(NounClass::N).to_string(), "ya 4");
+        assert_eq!(sw(1234).grouping(',').to_string(), "wa 1,234");
+    }
+}
@@ -0,0 +1,256 @@
+use super::Locale;
+
+impl Locale {
+    /// Returns the BCP 47 language tag of this locale, e.g. `sr-Latn`
+    pub fn language_tag(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            #[cfg(feature = "locale-de")]
+            Locale::De => "de",
+            #[cfg(feature = "locale-es")]
+            Locale::Es => "es",
+            #[cfg(feature = "locale-fi")]
+            Locale::Fi => "fi",
+            #[cfg(feature = "locale-fr")]
+            Locale::Fr => "fr",
+            #[cfg(feature = "locale-fa")]
+            Locale::Fa => "fa",
+            #[cfg(feature = "locale-id")]
+            Locale::Id => "id",
+            #[cfg(feature = "locale-ms")]
+            Locale::Ms => "ms",
+            #[cfg(feature = "locale-sr")]
+            Locale::SrCyrl => "sr-Cyrl",
+            #[cfg(feature = "locale-sr")]
+            Locale::SrLatn => "sr-Latn",
+            #[cfg(feature = "locale-hr")]
+            Locale::Hr => "hr",
+            #[cfg(feature = "locale-is")]
+            Locale::Is => "is",
+            #[cfg(feature = "locale-lt")]
+            Locale::Lt => "lt",
+            #[cfg(feature = "locale-sw")]
+            Locale::Sw => "sw",
+        }
+    }
+
+    /// Returns the supported locale that best matches a BCP 47 language tag,
+    /// e.g. `sr-Latn-RS`. POSIX locale names like `sr_RS.UTF-8@latin` are
+    /// accepted as well. Matching is case-insensitive.
+    ///
+    /// The language and script of the tag are mapped to a chain of locales, and
+    /// the first locale in the chain whose feature is enabled is returned:
+    ///
+    /// * Indonesian → Malay, and vice versa
+    /// * Croatian → Serbian (Latin)
+    /// * Serbian → Serbian in the other script. Serbian is written in Cyrillic
+    ///   unless the tag specifies the Latin script or the region is Montenegro.
+    /// * Bosnian → Croatian → Serbian (Latin) → Serbian (Cyrillic)
+    /// * Serbo-Croatian and Montenegrin → Serbian (Latin) → Croatian → Serbian (Cyrillic)
+    /// * Dari → Persian
+    ///
+    /// Bosnian, Serbo-Croatian and Montenegrin written in Cyrillic start with
+    /// Serbian (Cyrillic) instead. If no locale in the chain is enabled, or the
+    /// language isn't supported at all, the result is [Locale::En].
+    ///
+    /// ```
+    /// use num_ordinal::Locale;
+    ///
+    /// assert_eq!(Locale::from_language_tag("en-GB"), Locale::En);
+    /// // unsupported languages fall back to English
+    /// assert_eq!(Locale::from_language_tag("pt-BR"), Locale::En);
+    /// ```
+    pub fn from_language_tag(tag: &str) -> Locale {
+        // strip the POSIX encoding and modifier, e.g. `.UTF-8@latin`
+        let (tag, modifier) = match tag.find('@') {
+            Some(i) => (&tag[..i], &tag[i + 1..]),
+            None => (tag, ""),
+        };
+        let tag = tag.split('.').next().unwrap_or_default();
+
+        let mut subtags = tag.split(['-', '_']);
+        let language = subtags.next().unwrap_or_default();
+        // language subtags have at most 8 letters, so they're lowercased on the stack
+        let mut buf = [0u8; 8];
+        let language = match buf.get_mut(..language.len()) {
+            Some(buf) => {
+                buf.copy_from_slice(language.as_bytes());
+                buf.make_ascii_lowercase();
+                core::str::from_utf8(buf).unwrap_or_default()
+            }
+            None => "",
+        };
+        let (mut latin, mut cyrillic, mut montenegro) = (false, false, false);
+        for subtag in subtags {
+            latin |= subtag.eq_ignore_ascii_case("Latn");
+            cyrillic |= subtag.eq_ignore_ascii_case("Cyrl");
+            montenegro |= subtag.eq_ignore_ascii_case("ME");
+        }
+        latin |= modifier.eq_ignore_ascii_case("latin");
+        cyrillic |= modifier.eq_ignore_ascii_case("cyrillic");
+
+        let chain: &[&str] = match language {
+            "en" => &["en"],
+            "de" => &["de"],
+            "es" => &["es"],
+            "fi" => &["fi"],
+            "fr" => &["fr"],
+            "fa" | "prs" => &["fa"],
+            "id" | "in" => &["id", "ms"],
+            "ms" => &["ms", "id"],
+            "is" => &["is"],
+            "lt" => &["lt"],
+            "sw" | "swh" | "swc" => &["sw"],
+            "hr" if !cyrillic => &["hr", "sr-Latn"],
+            "sr" if latin || montenegro && !cyrillic => &["sr-Latn", "sr-Cyrl"],
+            "sr" => &["sr-Cyrl", "sr-Latn"],
+            "hr" | "bs" | "sh" | "cnr" if cyrillic => &["sr-Cyrl", "sr-Latn", "hr"],
+            "bs" => &["hr", "sr-Latn", "sr-Cyrl"],
+            "sh" | "cnr" => &["sr-Latn", "hr", "sr-Cyrl"],
+            _ => &[],
+        };
+        chain
+            .iter()
+            .find_map(|&id| enabled(id))
+            .unwrap_or(Locale::En)
+    }
+}
+
+/// Returns the locale with the given language tag, if its feature is enabled
+fn enabled(id: &str) -> Option<Locale> {
+    match id {
+        "en" => Some(Locale::En),
+        #[cfg(feature = "locale-de")]
+        "de" => Some(Locale::De),
+        #[cfg(feature = "locale-es")]
+        "es" => Some(Locale::Es),
+        #[cfg(feature = "locale-fi")]
+        "fi" => Some(Locale::Fi),
+        #[cfg(feature = "locale-fr")]
+        "fr" => Some(Locale::Fr),
+        #[cfg(feature = "locale-fa")]
+        "fa" => Some(Locale::Fa),
+        #[cfg(feature = "locale-id")]
+        "id" => Some(Locale::Id),
+        #[cfg(feature = "locale-ms")]
+        "ms" => Some(Locale::Ms),
+        #[cfg(feature = "locale-sr")]
+        "sr-Cyrl" => Some(Locale::SrCyrl),
+        #[cfg(feature = "locale-sr")]
+        "sr-Latn" => Some(Locale::SrLatn),
+        #[cfg(feature = "locale-hr")]
+        "hr" => Some(Locale::Hr),
+        #[cfg(feature = "locale-is")]
+        "is" => Some(Locale::Is),
+        #[cfg(feature = "locale-lt")]
+        "lt" => Some(Locale::Lt),
+        #[cfg(feature = "locale-sw")]
+        "sw" => Some(Locale::Sw),
+        _ => None,
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    fn tag(tag: &str) -> Locale {
+        Locale::from_language_tag(tag)
+    }
+
+    #[test]
+    fn english() {
+        assert_eq!(tag("en"), Locale::En);
+        assert_eq!(tag("en-US"), Locale::En);
+        assert_eq!(tag("EN_gb"), Locale::En);
+        assert_eq!(tag("en_US.UTF-8"), Locale::En);
+        assert_eq!(tag("C"), Locale::En);
+        assert_eq!(tag(""), Locale::En);
+        assert_eq!(tag("-"), Locale::En);
+        assert_eq!(tag("pt-BR"), Locale::En);
+        assert_eq!(tag("zh-Hant-TW"), Locale::En);
+    }
+
+    #[test]
+    #[cfg(feature = "locale-all")]
+    fn round_trip() {
+        let locales = [
+            Locale::En,
+            Locale::De,
+            Locale::Es,
+            Locale::Fi,
+            Locale::Fr,
+            Locale::Fa,
+            Locale::Id,
+            Locale::Ms,
+            Locale::SrCyrl,
+            Locale::SrLatn,
+            Locale::Hr,
+            Locale::Is,
+            Locale::Lt,
+            Locale::Sw,
+        ];
+        for &locale in &locales {
+            assert_eq!(tag(locale.language_tag()), locale);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "locale-id", feature = "locale-ms"))]
+    fn malay_and_indonesian() {
+        assert_eq!(tag("id-ID"), Locale::Id);
+        assert_eq!(tag("in"), Locale::Id);
+        assert_eq!(tag("ms_MY.UTF-8"), Locale::Ms);
+    }
+
+    #[test]
+    #[cfg(all(feature = "locale-id", not(feature = "locale-ms")))]
+    fn malay_falls_back_to_indonesian() {
+        assert_eq!(tag("ms-MY"), Locale::Id);
+    }
+
+    #[test]
+    #[cfg(feature = "locale-sr")]
+    fn serbian() {
+        assert_eq!(tag("sr"), Locale::SrCyrl);
+        assert_eq!(tag("sr-RS"), Locale::SrCyrl);
+        assert_eq!(tag("sr-Latn-RS"), Locale::SrLatn);
+        assert_eq!(tag("sr-latn"), Locale::SrLatn);
+        assert_eq!(tag("sr_RS.UTF-8@latin"), Locale::SrLatn);
+        assert_eq!(tag("sr-ME"), Locale::SrLatn);
+        assert_eq!(tag("sr-Cyrl-ME"), Locale::SrCyrl);
+        assert_eq!(tag("sh"), Locale::SrLatn);
+        assert_eq!(tag("bs-Cyrl"), Locale::SrCyrl);
+    }
+
+    #[test]
+    #[cfg(all(feature = "locale-hr", feature = "locale-sr"))]
+    fn croatian() {
+        assert_eq!(tag("hr-HR"), Locale::Hr);
+        assert_eq!(tag("bs-BA"), Locale::Hr);
+        assert_eq!(tag("cnr"), Locale::SrLatn);
+    }
+
+    #[test]
+    #[cfg(all(feature = "locale-sr", not(feature = "locale-hr")))]
+    fn croatian_falls_back_to_serbian() {
+        assert_eq!(tag("hr-HR"), Locale::SrLatn);
+        assert_eq!(tag("bs"), Locale::SrLatn);
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "locale-hr", feature = "locale-sr")))]
+    fn croatian_falls_back_to_english() {
+        assert_eq!(tag("hr-HR"), Locale::En);
+        assert_eq!(tag("sr-Latn"), Locale::En);
+    }
+
+    #[test]
+    #[cfg(all(feature = "locale-fa", feature = "locale-is", feature = "locale-lt"))]
+    fn other_languages() {
+        assert_eq!(tag("fa-IR"), Locale::Fa);
+        assert_eq!(tag("prs-AF"), Locale::Fa);
+        assert_eq!(tag("is-IS"), Locale::Is);
+        assert_eq!(tag("lt-LT"), Locale::Lt);
+    }
+}
@@ -0,0 +1,75 @@
+use core::fmt;
+
+/// Display adapter that writes the suffix of an ordinal number between markup tags.
+///
+/// It is returned by [html_sup](crate::Ordinal::html_sup),
+/// [latex_sup](crate::Ordinal::latex_sup) and [superscript](crate::Ordinal::superscript):
+///
+/// ```
+/// use num_ordinal::{ordinal, Ordinal};
+///
+/// assert_eq!(ordinal!(4-th O32).html_sup().to_string(), "4<sup>th</sup>");
+/// assert_eq!(ordinal!(first O32).latex_sup().to_string(), "1\\textsuperscript{st}");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Superscript<'a> {
+    n: u128,
+    open: &'a str,
+    close: &'a str,
+}
+
+impl<'a> Superscript<'a> {
+    pub(crate) fn new(n: u128, open: &'a str, close: &'a str) -> Self {
+        Superscript { n, open, close }
+    }
+}
+
+impl fmt::Display for Superscript<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let suffix = crate::suffix(self.n);
+        write!(f, "{}{}{}{}", self.n, self.open, suffix, self.close)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn html() {
+        assert_eq!(O32::from1(1).html_sup().to_string(), "1<sup>st</sup>");
+        assert_eq!(O32::from1(2).html_sup().to_string(), "2<sup>nd</sup>");
+        assert_eq!(O32::from1(3).html_sup().to_string(), "3<sup>rd</sup>");
+        assert_eq!(O32::from1(13).html_sup().to_string(), "13<sup>th</sup>");
+        assert_eq!(
+            O128::from0(u128::MAX - 1).html_sup().to_string(),
+            format!("{}<sup>th</sup>", u128::MAX)
+        );
+    }
+
+    #[test]
+    fn latex() {
+        assert_eq!(
+            O8::from1(22).latex_sup().to_string(),
+            r"22\textsuperscript{nd}"
+        );
+        assert_eq!(
+            O8::from1(111).latex_sup().to_string(),
+            r"111\textsuperscript{th}"
+        );
+    }
+
+    #[test]
+    fn custom_markup() {
+        assert_eq!(O16::from1(4).superscript("^", "^").to_string(), "4^th^");
+        assert_eq!(O16::from1(21).superscript("", "").to_string(), "21st");
+    }
+
+    #[test]
+    fn composes_with_placement() {
+        assert_eq!(
+            Placement::new(O16::from1(3).html_sup(), true).to_string(),
+            "T-3<sup>rd</sup>"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::scan::{digits_len, numeric_at, words_at};
+
+/// A part of a string, as compared by [compare_ordinal_aware]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Segment {
+    /// A number, e.g. `10`, `10th` or `tenth`
+    Number(u128),
+    /// Text between numbers
+    Text(String),
+}
+
+/// Sort key returned by [ordinal_sort_key]. Comparing two keys is equivalent to
+/// comparing the strings with [compare_ordinal_aware].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OrdinalSortKey {
+    segments: Vec<Segment>,
+    /// The original string, to break ties
+    original: String,
+}
+
+/// Compares two strings, treating embedded numbers and ordinal numbers
+/// numerically, so that `2nd draft` is sorted before `10th draft`.
+///
+/// Numbers are written with digits and an optional English suffix (e.g. `10`
+/// or `10th`), or as English ordinal words (e.g. `tenth` or `Twenty-Third`),
+/// which compare equal to the same number written with digits. Numbers are
+/// sorted before text, and the text between numbers is compared
+/// lexicographically. Strings that are equal in this way are compared
+/// lexicographically as a whole.
+///
+/// ```
+/// use num_ordinal::compare_ordinal_aware;
+///
+/// let mut drafts = vec!["10th draft", "2nd draft", "third draft", "1st draft"];
+/// drafts.sort_by(|a, b| compare_ordinal_aware(a, b));
+/// assert_eq!(drafts, ["1st draft", "2nd draft", "third draft", "10th draft"]);
+/// ```
+pub fn compare_ordinal_aware(a: &str, b: &str) -> Ordering {
+    segments(a).cmp(&segments(b)).then_with(|| a.cmp(b))
+}
+
+/// Returns a key for sorting strings like [compare_ordinal_aware]. This is
+/// faster when the same string is compared many times, e.g. with
+/// [sort_by_cached_key](slice::sort_by_cached_key).
+///
+/// ```
+/// use num_ordinal::ordinal_sort_key;
+///
+/// let mut sections = vec!["Section 10", "Section 9", "Section 10th"];
+/// sections.sort_by_cached_key(|s| ordinal_sort_key(s));
+/// assert_eq!(sections, ["Section 9", "Section 10", "Section 10th"]);
+/// ```
+pub fn ordinal_sort_key(s: &str) -> OrdinalSortKey {
+    OrdinalSortKey {
+        segments: segments(s),
+        original: s.to_string(),
+    }
+}
+
+fn segments(s: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut text_start = 0;
+    let mut i = 0;
+    while let Some(c) = s[i..].chars().next() {
+        let number = if c.is_ascii_digit() {
+            numeric_at(s, i)
+        } else if c.is_ascii_alphabetic() && !s[..i].ends_with(|c: char| c.is_alphabetic()) {
+            words_at(s, i)
+        } else {
+            None
+        };
+
+        match number {
+            Some((n, end)) => {
+                if text_start < i {
+                    segments.push(Segment::Text(s[text_start..i].to_string()));
+                }
+                segments.push(Segment::Number(n));
+                i = end;
+                text_start = end;
+            }
+            // a number that is too big is compared as text
+            None if c.is_ascii_digit() => i += digits_len(&s[i..]),
+            None => i += c.len_utf8(),
+        }
+    }
+    if text_start < s.len() {
+        segments.push(Segment::Text(s[text_start..].to_string()));
+    }
+    segments
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn sorted(strings: &[&str]) -> Vec<String> {
+        let mut v: Vec<_> = strings.iter().rev().map(|s| s.to_string()).collect();
+        v.sort_by(|a, b| compare_ordinal_aware(a, b));
+
+        let mut by_key = v.clone();
+        by_key.reverse();
+        by_key.sort_by_key(|s| ordinal_sort_key(s));
+        assert_eq!(v, by_key);
+        v
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(compare_ordinal_aware("2", "10"), Ordering::Less);
+        assert_eq!(compare_ordinal_aware("draft 2", "draft 10"), Ordering::Less);
+        assert_eq!(
+            compare_ordinal_aware("2nd draft", "10th draft"),
+            Ordering::Less
+        );
+        assert_eq!(compare_ordinal_aware("v2.10", "v2.9"), Ordering::Greater);
+        assert_eq!(
+            sorted(&["file10.txt", "file2.txt", "file1.txt", "file02.txt"]),
+            ["file1.txt", "file02.txt", "file2.txt", "file10.txt"]
+        );
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!(
+            sorted(&["tenth draft", "Second draft", "3rd draft", "first draft"]),
+            ["first draft", "Second draft", "3rd draft", "tenth draft"]
+        );
+        assert_eq!(
+            sorted(&["twenty-first", "twenty second", "twentieth"]),
+            ["twentieth", "twenty-first", "twenty second"]
+        );
+        // "second" in "seconds" isn't a number
+        assert_eq!(segments("seconds"), [Segment::Text("seconds".to_string())]);
+        assert_eq!(
+            segments("the one hundred twenty-third time"),
+            [
+                Segment::Text("the ".to_string()),
+                Segment::Number(123),
+                Segment::Text(" time".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn ties() {
+        assert_eq!(segments("3rd draft"), segments("third draft"));
+        assert_eq!(
+            compare_ordinal_aware("3rd draft", "third draft"),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_ordinal_aware("third draft", "3rd draft"),
+            Ordering::Greater
+        );
+        assert_eq!(compare_ordinal_aware("3rd", "3rd"), Ordering::Equal);
+        assert_eq!(compare_ordinal_aware("3rd", "3"), Ordering::Greater);
+        // an invalid suffix is text
+        assert_eq!(compare_ordinal_aware("3th", "3rd"), Ordering::Greater);
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(compare_ordinal_aware("a", "b"), Ordering::Less);
+        assert_eq!(compare_ordinal_aware("1 b", "1 a"), Ordering::Greater);
+        assert_eq!(compare_ordinal_aware("2", "a"), Ordering::Less);
+        assert_eq!(compare_ordinal_aware("", "1"), Ordering::Less);
+
+        let huge = "340282366920938463463374607431768211456";
+        assert_eq!(segments(huge), [Segment::Text(huge.to_string())]);
+        assert_eq!(
+            segments("ünd 2"),
+            [Segment::Text("ünd ".to_string()), Segment::Number(2)]
+        );
+    }
+}
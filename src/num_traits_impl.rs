@@ -0,0 +1,121 @@
+use core::convert::TryFrom;
+
+use num_traits::{Bounded, FromPrimitive, ToPrimitive};
+
+use crate::{Ordinal, OrdinalInteger, O};
+
+// `CheckedAdd`, `CheckedSub`, `SaturatingAdd` and `SaturatingSub` aren't
+// implemented, because they require adding two ordinals, which isn't meaningful.
+// `Ordinal::checked_add` and `Ordinal::saturating_add` add an integer instead.
+
+/// The bounds are the [first](Ordinal::first) and the [last](Ordinal::last)
+/// ordinal number
+impl<T: OrdinalInteger> Bounded for O<T> {
+    fn min_value() -> Self {
+        Self::first()
+    }
+
+    fn max_value() -> Self {
+        Self::last()
+    }
+}
+
+/// Converts the 1-based number of the ordinal, like the `From` conversion from
+/// ordinals to integers. For example, `O8::last().to_u8()` is `Some(255)`.
+impl<T: OrdinalInteger> ToPrimitive for O<T> {
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self.into1().to_u128()).ok()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(self.into1().to_u128()).ok()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self.into1().to_u128()).ok()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        Some(self.into1().to_u128())
+    }
+
+    /// Returns the 1-based number like [Ordinal::to_f64_lossy]
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.to_f64_lossy())
+    }
+}
+
+/// Converts a 1-based number to an ordinal, like the `TryFrom` conversion from
+/// integers to ordinals. For example, `O8::from_u64(1)` is the first ordinal, and
+/// 0 and negative numbers return [None].
+impl<T: OrdinalInteger> FromPrimitive for O<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        O::try_from1_signed(n).ok()
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::from_u128(n.into())
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        O::try_from1_signed(n).ok()
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        T::from_u128(n).and_then(O::try_from1)
+    }
+
+    /// Converts a 1-based number like [Ordinal::try_from1_f64], so numbers with a
+    /// fractional part return [None] instead of being truncated
+    fn from_f64(n: f64) -> Option<Self> {
+        O::try_from1_f64(n).ok()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use num_traits::{Bounded, FromPrimitive, ToPrimitive};
+
+    use crate::*;
+
+    #[test]
+    fn bounded() {
+        assert_eq!(O8::min_value(), O8::first());
+        assert_eq!(O8::max_value(), O8::last());
+        assert_eq!(O8::max_value().to_u8(), Some(255));
+        assert_eq!(O128::max_value().to_u128(), Some(u128::MAX));
+    }
+
+    #[test]
+    fn to_primitive() {
+        assert_eq!(O8::first().to_u64(), Some(1));
+        assert_eq!(O8::first().to_i8(), Some(1));
+        assert_eq!(O8::last().to_i8(), None);
+        assert_eq!(O64::last().to_u64(), Some(u64::MAX));
+        assert_eq!(O64::last().to_i64(), None);
+        assert_eq!(O128::last().to_u64(), None);
+        assert_eq!(O32::from1(4).to_f64(), Some(4.0));
+        assert_eq!(O128::last().to_f32(), Some(u128::MAX as f32));
+    }
+
+    #[test]
+    fn from_primitive() {
+        assert_eq!(O8::from_u64(1), Some(O8::first()));
+        assert_eq!(O8::from_u64(0), None);
+        assert_eq!(O8::from_i64(0), None);
+        assert_eq!(O8::from_i64(-1), None);
+        assert_eq!(O8::from_i64(255), Some(O8::last()));
+        assert_eq!(O8::from_i64(256), None);
+        assert_eq!(O8::from_u16(256), None);
+        assert_eq!(O128::from_u128(u128::MAX), Some(O128::last()));
+        assert_eq!(O64::from_i128(-5), None);
+        assert_eq!(O32::from_f64(4.0), Some(O32::from1(4)));
+        assert_eq!(O32::from_f64(4.5), None);
+        assert_eq!(O32::from_f32(0.0), None);
+
+        for n in 1..=255u8 {
+            let o = O8::from_u8(n).unwrap();
+            assert_eq!(o.to_u8(), Some(n));
+        }
+    }
+}
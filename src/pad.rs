@@ -0,0 +1,90 @@
+use core::fmt::{self, Alignment, Write};
+
+/// Writes formatted output like [fmt::Formatter::pad] writes a string, i.e.
+/// respecting the width, fill, alignment and precision of `f`, but without
+/// allocating a `String` first
+pub(crate) fn pad(f: &mut fmt::Formatter<'_>, args: fmt::Arguments<'_>) -> fmt::Result {
+    if f.width().is_none() && f.precision().is_none() {
+        return f.write_fmt(args);
+    }
+
+    let mut counter = CharCounter(0);
+    counter.write_fmt(args)?;
+    let len = match f.precision() {
+        Some(precision) => counter.0.min(precision),
+        None => counter.0,
+    };
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+    let (before, after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Left) | None => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    Truncate { f, remaining: len }.write_fmt(args)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Counts the written `char`s
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Forwards the first `remaining` `char`s and discards the rest
+struct Truncate<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    remaining: usize,
+}
+
+impl Write for Truncate<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = s
+            .char_indices()
+            .nth(self.remaining)
+            .map_or(s.len(), |(i, _)| i);
+        self.remaining -= s[..end].chars().count();
+        self.f.write_str(&s[..end])
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use core::fmt;
+
+    use super::pad;
+
+    struct Padded(&'static str);
+
+    impl fmt::Display for Padded {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            pad(f, format_args!("{}{}", self.0, "th"))
+        }
+    }
+
+    #[test]
+    fn same_as_str() {
+        for s in ["4", "", "ñ", "1000"].iter() {
+            let full = format!("{}th", s);
+            let full = full.as_str();
+            assert_eq!(format!("{}", Padded(s)), format!("{}", full));
+            assert_eq!(format!("{:8}", Padded(s)), format!("{:8}", full));
+            assert_eq!(format!("{:>8}", Padded(s)), format!("{:>8}", full));
+            assert_eq!(format!("{:*^7}", Padded(s)), format!("{:*^7}", full));
+            assert_eq!(format!("{:.2}", Padded(s)), format!("{:.2}", full));
+            assert_eq!(format!("{:>6.3}", Padded(s)), format!("{:>6.3}", full));
+            assert_eq!(format!("{:1}", Padded(s)), format!("{:1}", full));
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use core::fmt;
+
+use crate::{const_parse, suffix, OrdinalError};
+#[cfg(feature = "json")]
+use crate::{Integer, Ordinal};
+
+/// Error returned when a string can't be parsed as an ordinal number, e.g. by
+/// [parse_str](crate::Ordinal::parse_str) or `str::parse`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseOrdinalError {
+    /// The string isn't an ordinal number
+    Invalid,
+    /// The suffix doesn't match the number, e.g. `2st` or `11st`
+    BadSuffix,
+    /// The number is zero, e.g. `0th`
+    Zero,
+    /// The number is too large for the ordinal type
+    Overflow,
+}
+
+impl fmt::Display for ParseOrdinalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseOrdinalError::Invalid => write!(f, "string is not an ordinal number"),
+            ParseOrdinalError::BadSuffix => write!(f, "suffix doesn't match the number"),
+            ParseOrdinalError::Zero => f.write_str(OrdinalError::Zero.message()),
+            ParseOrdinalError::Overflow => f.write_str(OrdinalError::Overflow.message()),
+        }
+    }
+}
+
+impl core::error::Error for ParseOrdinalError {}
+
+impl From<ParseOrdinalError> for OrdinalError {
+    fn from(e: ParseOrdinalError) -> Self {
+        match e {
+            ParseOrdinalError::Zero => OrdinalError::Zero,
+            ParseOrdinalError::Overflow => OrdinalError::Overflow,
+            _ => OrdinalError::Invalid,
+        }
+    }
+}
+
+/// Parses a 1-based ordinal number written with digits and an English suffix
+/// (e.g. `4th`), with digits and a trailing period (e.g. `4.`), or with English
+/// words (e.g. `fourth`). Letters are case-insensitive.
+pub(crate) fn parse_ordinal(s: &str) -> Result<u128, ParseOrdinalError> {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (digits, rest) = s.split_at(end);
+    if digits.is_empty() {
+        return const_parse::parse_ignore_case(s).map_err(|e| match e {
+            OrdinalError::Zero => ParseOrdinalError::Zero,
+            OrdinalError::Overflow => ParseOrdinalError::Overflow,
+            _ => ParseOrdinalError::Invalid,
+        });
+    }
+
+    let n = digits.bytes().try_fold(0u128, |n, d| {
+        n.checked_mul(10)
+            .and_then(|n| n.checked_add((d - b'0') as u128))
+            .ok_or(ParseOrdinalError::Overflow)
+    })?;
+    match rest {
+        "" | "." => {}
+        _ if rest.eq_ignore_ascii_case(suffix(n)) => {}
+        _ if ["st", "nd", "rd", "th"]
+            .iter()
+            .any(|suffix| rest.eq_ignore_ascii_case(suffix)) =>
+        {
+            return Err(ParseOrdinalError::BadSuffix)
+        }
+        _ => return Err(ParseOrdinalError::Invalid),
+    }
+    match n {
+        0 => Err(ParseOrdinalError::Zero),
+        _ => Ok(n),
+    }
+}
+
+/// Splits a number written with digits and an optional English suffix, e.g. `4th`,
+/// and returns the digits. The suffix must match the number.
+#[cfg(any(feature = "bigint", feature = "json"))]
+pub(crate) fn strip_suffix(s: &str) -> Result<&str, OrdinalError> {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (digits, rest) = s.split_at(end);
+    if digits.is_empty() {
+        return Err(OrdinalError::Invalid);
+    }
+
+    // the suffix only depends on the last two digits
+    let last_two = digits[digits.len().saturating_sub(2)..]
+        .bytes()
+        .fold(0, |n, d| n * 10 + (d - b'0') as u128);
+    if !rest.is_empty() && rest != suffix(last_two) {
+        return Err(OrdinalError::Invalid);
+    }
+    Ok(digits)
+}
+
+/// Parses a 1-based ordinal number written with digits and an optional English
+/// suffix, e.g. `4` or `4th`
+#[cfg(feature = "json")]
+pub(crate) fn parse_numeric(s: &str) -> Result<u128, OrdinalError> {
+    strip_suffix(s)?.bytes().try_fold(0u128, |n, d| {
+        n.checked_mul(10)
+            .and_then(|n| n.checked_add((d - b'0') as u128))
+            .ok_or(OrdinalError::Overflow)
+    })
+}
+
+/// Converts a 1-based number to an ordinal number
+#[cfg(feature = "json")]
+pub(crate) fn from1_u128<O: Ordinal>(n: u128) -> Result<O, OrdinalError> {
+    match n {
+        0 => Err(OrdinalError::Zero),
+        _ => O::IntegerType::from_u128(n)
+            .and_then(O::try_from1)
+            .ok_or(OrdinalError::Overflow),
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Ordinal, Osize, O128, O16, O8};
+
+    #[test]
+    #[cfg(any(feature = "bigint", feature = "json"))]
+    fn suffixes() {
+        assert_eq!(strip_suffix("4"), Ok("4"));
+        assert_eq!(strip_suffix("4th"), Ok("4"));
+        assert_eq!(strip_suffix("112th"), Ok("112"));
+        assert_eq!(
+            strip_suffix("1000000000000000000000000000000000000000001st"),
+            Ok("1000000000000000000000000000000000000000001")
+        );
+        assert_eq!(strip_suffix("112nd"), Err(OrdinalError::Invalid));
+        assert_eq!(strip_suffix("4 th"), Err(OrdinalError::Invalid));
+        assert_eq!(strip_suffix("th"), Err(OrdinalError::Invalid));
+    }
+
+    #[test]
+    fn ordinals() {
+        assert_eq!(parse_ordinal("4th"), Ok(4));
+        assert_eq!(parse_ordinal("4TH"), Ok(4));
+        assert_eq!(parse_ordinal("4."), Ok(4));
+        assert_eq!(parse_ordinal("4"), Ok(4));
+        assert_eq!(parse_ordinal("First"), Ok(1));
+        assert_eq!(parse_ordinal("twenty-THIRD"), Ok(23));
+        assert_eq!(parse_ordinal("2st"), Err(ParseOrdinalError::BadSuffix));
+        assert_eq!(parse_ordinal("4x"), Err(ParseOrdinalError::Invalid));
+        assert_eq!(parse_ordinal("4.th"), Err(ParseOrdinalError::Invalid));
+        assert_eq!(parse_ordinal(""), Err(ParseOrdinalError::Invalid));
+        assert_eq!(parse_ordinal(" 4th"), Err(ParseOrdinalError::Invalid));
+        assert_eq!(parse_ordinal("0th"), Err(ParseOrdinalError::Zero));
+        assert_eq!(parse_ordinal("zeroth"), Err(ParseOrdinalError::Zero));
+        assert_eq!(
+            parse_ordinal("340282366920938463463374607431768211456th"),
+            Err(ParseOrdinalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn special_cases() {
+        for n in [11, 12, 13, 111, 112, 113, 1011].iter() {
+            assert_eq!(parse_ordinal(&format!("{}th", n)), Ok(*n));
+        }
+        assert_eq!(parse_ordinal("11st"), Err(ParseOrdinalError::BadSuffix));
+        assert_eq!(parse_ordinal("12nd"), Err(ParseOrdinalError::BadSuffix));
+        assert_eq!(parse_ordinal("113rd"), Err(ParseOrdinalError::BadSuffix));
+        assert_eq!(parse_ordinal("21th"), Err(ParseOrdinalError::BadSuffix));
+        assert_eq!(parse_ordinal("21st"), Ok(21));
+        assert_eq!(parse_ordinal("122nd"), Ok(122));
+    }
+
+    #[test]
+    fn round_trip() {
+        for n in 1..=u16::MAX {
+            let o = O16::from1(n);
+            assert_eq!(o.to_string().parse(), Ok(o));
+        }
+        let max = O128::from0(u128::MAX - 1);
+        assert_eq!(max.to_string().parse(), Ok(max));
+        assert_eq!("256th".parse::<O8>(), Err(ParseOrdinalError::Overflow));
+        assert_eq!(O8::parse_str("255th"), Ok(O8::from1(255)));
+        assert_eq!(Osize::parse_str("3rd"), Ok(Osize::from1(3)));
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn numeric() {
+        assert_eq!(parse_numeric("4"), Ok(4));
+        assert_eq!(parse_numeric("4th"), Ok(4));
+        assert_eq!(parse_numeric("21st"), Ok(21));
+        assert_eq!(parse_numeric("0"), Ok(0));
+        assert_eq!(parse_numeric("4st"), Err(OrdinalError::Invalid));
+        assert_eq!(parse_numeric("th"), Err(OrdinalError::Invalid));
+        assert_eq!(parse_numeric("-4"), Err(OrdinalError::Invalid));
+        assert_eq!(parse_numeric(""), Err(OrdinalError::Invalid));
+        assert_eq!(
+            parse_numeric("340282366920938463463374607431768211456"),
+            Err(OrdinalError::Overflow)
+        );
+    }
+}
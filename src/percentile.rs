@@ -0,0 +1,153 @@
+use core::fmt;
+
+use crate::{float, Ordinal, Rounding, O8};
+
+/// Rounding mode used by [O8::percentile_of]. This is an alias of [Rounding].
+pub type PercentileRounding = Rounding;
+
+/// Error returned by [O8::percentile_of]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PercentileError {
+    /// The fraction is NaN
+    NaN,
+    /// The fraction is not in the range `0.0..=1.0`
+    OutOfRange,
+}
+
+impl fmt::Display for PercentileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PercentileError::NaN => write!(f, "fraction is NaN"),
+            PercentileError::OutOfRange => write!(f, "fraction is not between 0 and 1"),
+        }
+    }
+}
+
+impl core::error::Error for PercentileError {}
+
+impl O8 {
+    /// Returns the percentile of a fraction in the range `0.0..=1.0`, from the
+    /// 1st to the 100th percentile.
+    ///
+    /// The fraction is multiplied by 100 and then rounded with the given
+    /// rounding mode. Since there is no 0th percentile, results below the 1st
+    /// percentile (e.g. `0.0`) are the 1st percentile. Products that are within
+    /// `1e-9` of an integer are treated as that integer, so that `0.29` is the
+    /// 29th percentile even though `0.29 * 100.0` is slightly smaller than 29.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, O8, PercentileRounding};
+    ///
+    /// let p = O8::percentile_of(0.99, PercentileRounding::Nearest).unwrap();
+    /// assert_eq!(p, ordinal!(99-th O8));
+    /// ```
+    pub fn percentile_of(
+        fraction: f64,
+        rounding: PercentileRounding,
+    ) -> Result<O8, PercentileError> {
+        if fraction.is_nan() {
+            return Err(PercentileError::NaN);
+        }
+        if !(0.0..=1.0).contains(&fraction) {
+            return Err(PercentileError::OutOfRange);
+        }
+
+        let scaled = fraction * 100.0;
+        let nearest = float::round(scaled);
+        let rounded = if float::abs(scaled - nearest) < 1e-9 {
+            nearest
+        } else {
+            match rounding {
+                Rounding::Nearest => nearest,
+                Rounding::Floor => float::floor(scaled),
+                Rounding::Ceil => float::ceil(scaled),
+            }
+        };
+        Ok(O8::from1((rounded as u8).max(1)))
+    }
+
+    /// Interprets this ordinal as a percentile and returns it as a fraction,
+    /// e.g. `0.99` for the 99th percentile.
+    ///
+    /// Returns [None] if the ordinal is greater than the 100th.
+    pub fn as_fraction(self) -> Option<f64> {
+        match self.into1() {
+            n @ 1..=100 => Some(n as f64 / 100.0),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn percentile(fraction: f64, rounding: PercentileRounding) -> u8 {
+        O8::percentile_of(fraction, rounding).unwrap().into1()
+    }
+
+    #[test]
+    fn boundaries() {
+        use Rounding::*;
+
+        assert_eq!(percentile(0.0, Nearest), 1);
+        assert_eq!(percentile(0.0, Floor), 1);
+        assert_eq!(percentile(0.0, Ceil), 1);
+
+        assert_eq!(percentile(0.005, Nearest), 1);
+        assert_eq!(percentile(0.005, Floor), 1);
+        assert_eq!(percentile(0.005, Ceil), 1);
+
+        assert_eq!(percentile(0.995, Nearest), 100);
+        assert_eq!(percentile(0.995, Floor), 99);
+        assert_eq!(percentile(0.995, Ceil), 100);
+
+        assert_eq!(percentile(1.0, Nearest), 100);
+        assert_eq!(percentile(1.0, Floor), 100);
+        assert_eq!(percentile(1.0, Ceil), 100);
+    }
+
+    #[test]
+    fn imprecise_products() {
+        use Rounding::*;
+
+        for &rounding in &[Nearest, Floor, Ceil] {
+            assert_eq!(percentile(0.29, rounding), 29);
+            assert_eq!(percentile(0.57, rounding), 57);
+        }
+        assert_eq!(percentile(0.2901, Floor), 29);
+        assert_eq!(percentile(0.2901, Ceil), 30);
+    }
+
+    #[test]
+    fn invalid_fractions() {
+        let r = PercentileRounding::Nearest;
+        assert_eq!(O8::percentile_of(f64::NAN, r), Err(PercentileError::NaN));
+        assert_eq!(
+            O8::percentile_of(-0.01, r),
+            Err(PercentileError::OutOfRange)
+        );
+        assert_eq!(O8::percentile_of(1.01, r), Err(PercentileError::OutOfRange));
+        assert_eq!(
+            O8::percentile_of(f64::INFINITY, r),
+            Err(PercentileError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn as_fraction() {
+        assert_eq!(O8::from1(1).as_fraction(), Some(0.01));
+        assert_eq!(O8::from1(99).as_fraction(), Some(0.99));
+        assert_eq!(O8::from1(100).as_fraction(), Some(1.0));
+        assert_eq!(O8::from1(101).as_fraction(), None);
+
+        for n in 1..=100 {
+            let o = O8::from1(n);
+            let fraction = o.as_fraction().unwrap();
+            assert_eq!(
+                O8::percentile_of(fraction, PercentileRounding::Floor),
+                Ok(o)
+            );
+        }
+    }
+}
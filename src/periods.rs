@@ -0,0 +1,285 @@
+//! Quarters of calendar and fiscal years
+//!
+//! Months are ordinal numbers from the 1st (January) to the 12th (December).
+//! Quarters are ordinal numbers from the 1st to the 4th.
+//!
+//! ```
+//! use num_ordinal::{ordinal, periods, Ordinal};
+//!
+//! assert_eq!(periods::quarter_of_month(ordinal!(8-th O8)), ordinal!(3-rd O8));
+//!
+//! let quarter = ordinal!(3-rd O8).as_quarter();
+//! assert_eq!(quarter.to_string(), "Q3");
+//! assert_eq!(quarter.words().fiscal_year(2025).to_string(), "3rd quarter of FY2025");
+//! ```
+
+use core::fmt;
+
+use crate::{Ordinal, O8};
+
+fn month_index(month: O8) -> u8 {
+    assert!(month.into0() < 12, "{} is not a valid month", month.into1());
+    month.into0()
+}
+
+/// Returns the calendar quarter of a month, e.g. the 3rd quarter for the 8th month (August).
+///
+/// ### Panics
+///
+/// Panics if the month is greater than 12.
+pub fn quarter_of_month(month: O8) -> O8 {
+    FiscalYear::CALENDAR.quarter_of_month(month)
+}
+
+/// Returns the position of a month within its calendar quarter, e.g. the 2nd month
+/// for the 8th month (August).
+///
+/// ### Panics
+///
+/// Panics if the month is greater than 12.
+pub fn month_in_quarter(month: O8) -> O8 {
+    FiscalYear::CALENDAR.month_in_quarter(month)
+}
+
+/// A fiscal year, which starts in a configurable month.
+///
+/// The 1st quarter of a fiscal year starts with its first month. For example, the
+/// fiscal year of the US government starts in October, so October is in Q1 and
+/// January is in Q2:
+///
+/// ```
+/// use num_ordinal::{ordinal, periods::FiscalYear};
+///
+/// let us_government = FiscalYear::starting_in(ordinal!(10-th O8));
+/// assert_eq!(us_government.quarter_of_month(ordinal!(10-th O8)), ordinal!(first O8));
+/// assert_eq!(us_government.quarter_of_month(ordinal!(first O8)), ordinal!(second O8));
+/// assert_eq!(us_government.month_of_year(ordinal!(first O8)), ordinal!(4-th O8));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiscalYear {
+    start: u8,
+}
+
+impl FiscalYear {
+    /// The fiscal year that coincides with the calendar year, starting in January
+    pub const CALENDAR: FiscalYear = FiscalYear { start: 0 };
+
+    /// Creates a fiscal year starting in the given month
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the month is greater than 12.
+    pub fn starting_in(month: O8) -> Self {
+        FiscalYear {
+            start: month_index(month),
+        }
+    }
+
+    /// Returns the month in which the fiscal year starts
+    pub fn start_month(self) -> O8 {
+        O8::from0(self.start)
+    }
+
+    /// Returns the position of a calendar month within the fiscal year, e.g. the 4th
+    /// month for January if the fiscal year starts in October.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the month is greater than 12.
+    pub fn month_of_year(self, month: O8) -> O8 {
+        O8::from0((month_index(month) + 12 - self.start) % 12)
+    }
+
+    /// Returns the fiscal quarter of a calendar month
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the month is greater than 12.
+    pub fn quarter_of_month(self, month: O8) -> O8 {
+        O8::from0(self.month_of_year(month).into0() / 3)
+    }
+
+    /// Returns the position of a calendar month within its fiscal quarter
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the month is greater than 12.
+    pub fn month_in_quarter(self, month: O8) -> O8 {
+        O8::from0(self.month_of_year(month).into0() % 3)
+    }
+
+    /// Returns the calendar month in which a fiscal quarter starts
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the quarter is greater than 4.
+    pub fn first_month_of_quarter(self, quarter: O8) -> O8 {
+        assert!(
+            quarter.into0() < 4,
+            "{} is not a valid quarter",
+            quarter.into1()
+        );
+        O8::from0((self.start + quarter.into0() * 3) % 12)
+    }
+}
+
+impl Default for FiscalYear {
+    fn default() -> Self {
+        FiscalYear::CALENDAR
+    }
+}
+
+/// How a [Quarter] is displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum QuarterStyle {
+    /// `Q3`, or `Q3 FY2025` with a fiscal year
+    #[default]
+    Compact,
+    /// `3rd quarter`, or `3rd quarter of FY2025` with a fiscal year
+    Words,
+}
+
+/// Display adapter for an ordinal number interpreted as a quarter.
+///
+/// It is returned by [as_quarter](crate::Ordinal::as_quarter).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Quarter {
+    n: u128,
+    style: QuarterStyle,
+    fiscal_year: Option<i32>,
+}
+
+impl Quarter {
+    pub(crate) fn new(n: u128) -> Self {
+        Quarter {
+            n,
+            style: QuarterStyle::Compact,
+            fiscal_year: None,
+        }
+    }
+
+    /// Sets the display style
+    pub fn style(mut self, style: QuarterStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Displays the quarter like `Q3`
+    pub fn compact(self) -> Self {
+        self.style(QuarterStyle::Compact)
+    }
+
+    /// Displays the quarter like `3rd quarter`
+    pub fn words(self) -> Self {
+        self.style(QuarterStyle::Words)
+    }
+
+    /// Adds the fiscal year to the output, e.g. `Q3 FY2025`
+    pub fn fiscal_year(mut self, year: i32) -> Self {
+        self.fiscal_year = Some(year);
+        self
+    }
+}
+
+impl fmt::Display for Quarter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.style {
+            QuarterStyle::Compact => write!(f, "Q{}", self.n)?,
+            QuarterStyle::Words => write!(f, "{}{} quarter", self.n, crate::suffix(self.n))?,
+        }
+        match (self.fiscal_year, self.style) {
+            (None, _) => Ok(()),
+            (Some(year), QuarterStyle::Compact) => write!(f, " FY{}", year),
+            (Some(year), QuarterStyle::Words) => write!(f, " of FY{}", year),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::O32;
+
+    fn month(n: u8) -> O8 {
+        O8::from1(n)
+    }
+
+    #[test]
+    fn calendar_quarters() {
+        let quarters: Vec<u8> = (1..=12)
+            .map(|m| quarter_of_month(month(m)).into1())
+            .collect();
+        assert_eq!(quarters, [1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4]);
+        let positions: Vec<u8> = (1..=12)
+            .map(|m| month_in_quarter(month(m)).into1())
+            .collect();
+        assert_eq!(positions, [1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3]);
+    }
+
+    #[test]
+    fn fiscal_quarters() {
+        let quarters = |start| {
+            let fy = FiscalYear::starting_in(month(start));
+            (1..=12)
+                .map(|m| fy.quarter_of_month(month(m)).into1())
+                .collect::<Vec<u8>>()
+        };
+        assert_eq!(quarters(1), [1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4]);
+        assert_eq!(quarters(4), [4, 4, 4, 1, 1, 1, 2, 2, 2, 3, 3, 3]);
+        assert_eq!(quarters(7), [3, 3, 3, 4, 4, 4, 1, 1, 1, 2, 2, 2]);
+        assert_eq!(quarters(10), [2, 2, 2, 3, 3, 3, 4, 4, 4, 1, 1, 1]);
+        assert_eq!(quarters(2), [4, 1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4]);
+        assert_eq!(quarters(12), [1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4, 1]);
+
+        let fy = FiscalYear::starting_in(month(2));
+        let positions: Vec<u8> = (1..=12)
+            .map(|m| fy.month_in_quarter(month(m)).into1())
+            .collect();
+        assert_eq!(positions, [3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2]);
+        assert_eq!(fy.month_of_year(month(1)), month(12));
+        assert_eq!(fy.start_month(), month(2));
+    }
+
+    #[test]
+    fn first_month_of_quarter() {
+        for start in 1..=12 {
+            let fy = FiscalYear::starting_in(month(start));
+            for q in 1..=4 {
+                let first = fy.first_month_of_quarter(O8::from1(q));
+                assert_eq!(fy.quarter_of_month(first), O8::from1(q));
+                assert_eq!(fy.month_in_quarter(first), O8::first());
+            }
+        }
+        assert_eq!(FiscalYear::default(), FiscalYear::CALENDAR);
+    }
+
+    #[test]
+    #[should_panic(expected = "13 is not a valid month")]
+    fn invalid_month() {
+        quarter_of_month(month(13));
+    }
+
+    #[test]
+    fn display() {
+        let q = O8::from1(1).as_quarter();
+        assert_eq!(q.to_string(), "Q1");
+        assert_eq!(q.words().to_string(), "1st quarter");
+        assert_eq!(q.fiscal_year(2025).to_string(), "Q1 FY2025");
+        assert_eq!(
+            O32::from1(2)
+                .as_quarter()
+                .words()
+                .fiscal_year(2024)
+                .to_string(),
+            "2nd quarter of FY2024"
+        );
+        assert_eq!(
+            O32::from1(3)
+                .as_quarter()
+                .style(QuarterStyle::Words)
+                .compact()
+                .to_string(),
+            "Q3"
+        );
+    }
+}
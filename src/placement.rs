@@ -0,0 +1,103 @@
+use core::fmt;
+
+use crate::pad::pad;
+
+/// Notation used to mark a tied [Placement]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TieStyle {
+    /// A `T-` prefix, e.g. `T-3rd`
+    #[default]
+    Prefix,
+    /// An `=` prefix, e.g. `=3rd`
+    Equals,
+    /// A ` (tied)` suffix, e.g. `3rd (tied)`
+    Suffix,
+}
+
+/// A placement in a ranking, which may be tied with other placements.
+///
+/// The ordinal can be anything that implements [Display](fmt::Display), so it
+/// can be an ordinal number or a display adapter wrapping one. Width, fill and
+/// alignment flags apply to the whole placement including the tie marker:
+///
+/// ```
+/// use num_ordinal::{ordinal, Ordinal, TieStyle};
+///
+/// let o = ordinal!(4-th O32);
+/// assert_eq!(o.tied().to_string(), "T-4th");
+/// assert_eq!(o.tied().style(TieStyle::Equals).to_string(), "=4th");
+/// assert_eq!(format!("{:>7}", o.tied()), "  T-4th");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Placement<O> {
+    /// The position in the ranking
+    pub ordinal: O,
+    /// Whether the position is shared with other entries
+    pub tied: bool,
+    /// The notation used when the placement is tied
+    pub style: TieStyle,
+}
+
+impl<O> Placement<O> {
+    /// Creates a placement with the default [TieStyle]
+    pub fn new(ordinal: O, tied: bool) -> Self {
+        Placement {
+            ordinal,
+            tied,
+            style: TieStyle::default(),
+        }
+    }
+
+    /// Sets the notation used when the placement is tied
+    pub fn style(self, style: TieStyle) -> Self {
+        Placement { style, ..self }
+    }
+}
+
+impl<O: fmt::Display> fmt::Display for Placement<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.tied, self.style) {
+            (false, _) => pad(f, format_args!("{}", self.ordinal)),
+            (true, TieStyle::Prefix) => pad(f, format_args!("T-{}", self.ordinal)),
+            (true, TieStyle::Equals) => pad(f, format_args!("={}", self.ordinal)),
+            (true, TieStyle::Suffix) => pad(f, format_args!("{} (tied)", self.ordinal)),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn notations() {
+        let o = Osize::from1(3);
+        assert_eq!(o.tied().to_string(), "T-third");
+        assert_eq!(o.tied().style(TieStyle::Prefix).to_string(), "T-third");
+        assert_eq!(o.tied().style(TieStyle::Equals).to_string(), "=third");
+        assert_eq!(o.tied().style(TieStyle::Suffix).to_string(), "third (tied)");
+
+        let o = Osize::from1(12);
+        assert_eq!(o.tied().style(TieStyle::Suffix).to_string(), "12th (tied)");
+    }
+
+    #[test]
+    fn untied() {
+        let o = Osize::from1(3);
+        for &style in &[TieStyle::Prefix, TieStyle::Equals, TieStyle::Suffix] {
+            assert_eq!(Placement::new(o, false).style(style).to_string(), "third");
+        }
+    }
+
+    #[test]
+    fn padding() {
+        let o = O32::from1(4);
+        assert_eq!(format!("{:>6}", o.tied()), " T-4th");
+        assert_eq!(format!("{:<6}|", o.tied()), "T-4th |");
+        assert_eq!(
+            format!("{:^7}", o.tied().style(TieStyle::Equals)),
+            " =4th  "
+        );
+        assert_eq!(format!("{:*>6}", Placement::new(o, false)), "***4th");
+    }
+}
@@ -0,0 +1,52 @@
+use core::ops::RangeInclusive;
+
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{Map, Strategy};
+
+use crate::{Ordinal, OrdinalInteger, O};
+
+/// Generates any ordinal number from the [first](Ordinal::first) to the
+/// [last](Ordinal::last), so `any::<O32>()` can be used in `proptest!` tests:
+///
+/// ```
+/// use num_ordinal::{Ordinal, O32};
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     fn round_trip(o in any::<O32>()) {
+///         prop_assert_eq!(O32::from1(o.into1()), o);
+///     }
+/// }
+/// # round_trip();
+/// ```
+impl<T> Arbitrary for O<T>
+where
+    T: OrdinalInteger,
+    RangeInclusive<T>: Strategy<Value = T>,
+{
+    type Parameters = ();
+    type Strategy = Map<RangeInclusive<T>, fn(T) -> Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        let range = O::<T>::first().into0()..=O::<T>::last().into0();
+        range.prop_map(<O<T> as Ordinal>::from0 as fn(T) -> Self)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::*;
+
+    proptest! {
+        #[test]
+        fn valid(a in any::<O8>(), b in any::<O64>(), c in any::<Osize>()) {
+            prop_assert!(O8::try_from0(a.into0()).is_some());
+            prop_assert!(O64::try_from0(b.into0()).is_some());
+            prop_assert!(Osize::try_from0(c.into0()).is_some());
+            prop_assert_eq!(O8::from1(a.into1()), a);
+            prop_assert_eq!(O64::from1(b.into1()), b);
+        }
+    }
+}
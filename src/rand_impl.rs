@@ -0,0 +1,117 @@
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::{Ordinal, OrdinalInteger, O};
+
+/// Samples any ordinal number from the [first](Ordinal::first) to the
+/// [last](Ordinal::last) with equal probability
+impl<T: OrdinalInteger + SampleUniform> Distribution<O<T>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> O<T> {
+        rng.gen_range(O::<T>::first()..=O::last())
+    }
+}
+
+impl<T: OrdinalInteger + SampleUniform> SampleUniform for O<T> {
+    type Sampler = UniformOrdinal<T>;
+}
+
+/// Samples ordinal numbers uniformly from a range, e.g. with
+/// `rng.gen_range(ordinal!(first O32)..=ordinal!(10-th))`. It samples the 0-based
+/// integers, so the highest number of the integer type is never produced.
+///
+/// ```
+/// use num_ordinal::{ordinal, O32};
+/// use rand::{rngs::StdRng, Rng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+/// let o: O32 = rng.gen_range(ordinal!(first O32)..=ordinal!(10-th));
+/// assert!(o <= 10);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UniformOrdinal<T: SampleUniform>(T::Sampler);
+
+impl<T: OrdinalInteger + SampleUniform> UniformSampler for UniformOrdinal<T> {
+    type X = O<T>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformOrdinal(T::Sampler::new(low.borrow().into0(), high.borrow().into0()))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformOrdinal(T::Sampler::new_inclusive(
+            low.borrow().into0(),
+            high.borrow().into0(),
+        ))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        O::from0(self.0.sample(rng))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::*;
+
+    #[test]
+    fn standard() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut seen = [false; 255];
+        for _ in 0..10_000 {
+            let o: O8 = rng.gen();
+            seen[o.into0() as usize] = true;
+        }
+        assert!(seen.iter().all(|&seen| seen));
+
+        let _: (O16, O32, O64, O128, Osize) = rng.gen();
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..1000 {
+            let o = rng.gen_range(ordinal!(first O32)..=ordinal!(10 - th));
+            assert!((1..=10).contains(&o.into1()));
+            let o = rng.gen_range(ordinal!(first O32)..ordinal!(10 - th));
+            assert!((1..10).contains(&o.into1()));
+        }
+
+        let first = O8::first();
+        assert_eq!(rng.gen_range(first..=first), first);
+        assert_eq!(rng.gen_range(first..first.next()), first);
+
+        let mut seen_last = false;
+        for _ in 0..1000 {
+            let o = rng.gen_range(O8::from1(250)..=O8::last());
+            assert!(o >= 250);
+            seen_last |= o == O8::last();
+        }
+        assert!(seen_last);
+        assert_eq!(rng.gen_range(O128::last()..=O128::last()), O128::last());
+    }
+
+    #[test]
+    fn reproducible() {
+        let sample = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let range = Osize::from1(1)..=Osize::from1(1_000_000);
+            (0..20)
+                .map(|_| rng.gen_range(range.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(sample(42), sample(42));
+        assert_ne!(sample(42), sample(43));
+    }
+}
@@ -0,0 +1,798 @@
+use core::convert::TryFrom;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::{Range, RangeInclusive};
+
+use crate::{Integer, Ordinal, Osize, O16, O32, O8};
+
+/// A range of ordinal numbers from `start` (inclusive) to `end` (exclusive)
+///
+/// ```
+/// use num_ordinal::{ordinal, OrdinalRange, O32};
+///
+/// let range = OrdinalRange::from(ordinal!(first O32)..ordinal!(4-th));
+/// assert_eq!(range.len(), 3);
+/// assert!(range.contains(ordinal!(third)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OrdinalRange<O> {
+    /// The first ordinal in the range
+    pub start: O,
+    /// The ordinal after the last ordinal in the range
+    pub end: O,
+}
+
+/// A range of ordinal numbers from `start` to `end`, both inclusive
+///
+/// ```
+/// use num_ordinal::{ordinal, OrdinalRangeInclusive, O32};
+///
+/// let range = OrdinalRangeInclusive::from(ordinal!(first O32)..=ordinal!(4-th));
+/// assert_eq!(range.len(), 4);
+/// assert!(range.contains(ordinal!(4-th)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OrdinalRangeInclusive<O> {
+    /// The first ordinal in the range
+    pub start: O,
+    /// The last ordinal in the range
+    pub end: O,
+}
+
+fn from0_u128<O: Ordinal>(n: u128) -> O {
+    O::IntegerType::from_u128(n)
+        .and_then(O::try_from0)
+        .expect("ordinal is in range")
+}
+
+impl<O: Ordinal> OrdinalRange<O> {
+    /// Creates a range from `start` (inclusive) to `end` (exclusive)
+    pub fn new(start: O, end: O) -> Self {
+        OrdinalRange { start, end }
+    }
+
+    /// Returns the number of ordinals in the range
+    pub fn len(&self) -> O::IntegerType {
+        from_len::<O>(self.len_u128())
+    }
+
+    /// Returns `true` if the range contains no ordinals
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Returns `true` if the ordinal is contained in the range
+    pub fn contains(&self, o: O) -> bool {
+        self.start <= o && o < self.end
+    }
+
+    /// Splits the range into at most `n` contiguous sub-ranges, whose lengths
+    /// differ by at most one. See [OrdinalRangeInclusive::split_into].
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn split_into(&self, n: usize) -> SplitInto<O> {
+        SplitInto::new(self.start.into0().to_u128(), self.len_u128(), n)
+    }
+
+    /// Splits the range into contiguous sub-ranges of `chunk_size` ordinals. See
+    /// [OrdinalRangeInclusive::split_chunks].
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    pub fn split_chunks(&self, chunk_size: O::IntegerType) -> SplitChunks<O> {
+        SplitChunks::new(self.start.into0().to_u128(), self.len_u128(), chunk_size)
+    }
+
+    fn len_u128(&self) -> u128 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.into0().to_u128() - self.start.into0().to_u128()
+        }
+    }
+}
+
+impl<O: Ordinal> OrdinalRangeInclusive<O> {
+    /// Creates a range from `start` to `end`, both inclusive
+    pub fn new(start: O, end: O) -> Self {
+        OrdinalRangeInclusive { start, end }
+    }
+
+    /// Returns the number of ordinals in the range
+    pub fn len(&self) -> O::IntegerType {
+        from_len::<O>(self.len_u128())
+    }
+
+    /// Returns `true` if the range contains no ordinals
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Returns `true` if the ordinal is contained in the range
+    pub fn contains(&self, o: O) -> bool {
+        self.start <= o && o <= self.end
+    }
+
+    /// Splits the range into at most `n` contiguous, non-overlapping sub-ranges
+    /// that cover the whole range. The lengths of the sub-ranges differ by at most
+    /// one; longer sub-ranges come first.
+    ///
+    /// If the range has fewer than `n` ordinals, every sub-range contains one
+    /// ordinal, so fewer than `n` sub-ranges are returned. Empty sub-ranges are
+    /// never returned.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalRangeInclusive, O32};
+    ///
+    /// let range = OrdinalRangeInclusive::from(ordinal!(first O32)..=ordinal!(10-th));
+    /// let parts: Vec<_> = range.split_into(3).collect();
+    /// assert_eq!(parts, [
+    ///     OrdinalRangeInclusive::from(ordinal!(first)..=ordinal!(4-th)),
+    ///     OrdinalRangeInclusive::from(ordinal!(5-th)..=ordinal!(7-th)),
+    ///     OrdinalRangeInclusive::from(ordinal!(8-th)..=ordinal!(10-th)),
+    /// ]);
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn split_into(&self, n: usize) -> SplitInto<O> {
+        SplitInto::new(self.start.into0().to_u128(), self.len_u128(), n)
+    }
+
+    /// Splits the range into contiguous sub-ranges of `chunk_size` ordinals. The
+    /// last sub-range is shorter if the length isn't divisible by `chunk_size`.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalRangeInclusive, O32};
+    ///
+    /// let range = OrdinalRangeInclusive::from(ordinal!(first O32)..=ordinal!(5-th));
+    /// let parts: Vec<_> = range.split_chunks(2).map(|r| r.len()).collect();
+    /// assert_eq!(parts, [2, 2, 1]);
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    pub fn split_chunks(&self, chunk_size: O::IntegerType) -> SplitChunks<O> {
+        SplitChunks::new(self.start.into0().to_u128(), self.len_u128(), chunk_size)
+    }
+
+    fn len_u128(&self) -> u128 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.into0().to_u128() - self.start.into0().to_u128() + 1
+        }
+    }
+}
+
+/// Converts a 0-based index to an ordinal, if it fits into the ordinal type
+fn from_index<O: Ordinal>(index: usize) -> Option<O> {
+    O::IntegerType::from_u128(index as u128).and_then(O::try_from0)
+}
+
+/// Converts a 0-based start and exclusive end to an index range. Empty ranges
+/// are normalized to `start..start`, so they can be used for slicing.
+fn to_index_range(start: u128, end: u128) -> Option<Range<usize>> {
+    let start = usize::try_from(start).ok()?;
+    let end = usize::try_from(end.max(start as u128)).ok()?;
+    Some(start..end)
+}
+
+impl<O: Ordinal> OrdinalRange<O> {
+    /// Converts a range of 0-based indices to a range of ordinals.
+    ///
+    /// Returns [None] if an index is too big for the ordinal type.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalRange, O8};
+    ///
+    /// let range = OrdinalRange::<O8>::from_index_range(0..3).unwrap();
+    /// assert_eq!(range, OrdinalRange::new(ordinal!(first), ordinal!(4-th)));
+    /// assert_eq!(OrdinalRange::<O8>::from_index_range(0..300), None);
+    /// ```
+    pub fn from_index_range(range: Range<usize>) -> Option<Self> {
+        Some(OrdinalRange::new(
+            from_index(range.start)?,
+            from_index(range.end)?,
+        ))
+    }
+
+    /// Converts the range to a range of 0-based indices, which can be used to
+    /// index a slice. An empty range is converted to `start..start`.
+    ///
+    /// Returns [None] if an index is too big for `usize`.
+    pub fn try_to_index_range(&self) -> Option<Range<usize>> {
+        to_index_range(self.start.into0().to_u128(), self.end.into0().to_u128())
+    }
+}
+
+impl<O: Ordinal> OrdinalRangeInclusive<O> {
+    /// Converts a range of 0-based indices to a range of ordinals. The end of
+    /// the index range is exclusive, so `0..3` is converted to the range from
+    /// the first to the third ordinal.
+    ///
+    /// Returns [None] if an index is too big for the ordinal type, or if the
+    /// index range is empty and starts at 0, since such a range can't be
+    /// represented.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalRangeInclusive, O8};
+    ///
+    /// let range = OrdinalRangeInclusive::<O8>::from_index_range(0..3).unwrap();
+    /// assert_eq!(range, OrdinalRangeInclusive::new(ordinal!(first), ordinal!(third)));
+    /// ```
+    pub fn from_index_range(range: Range<usize>) -> Option<Self> {
+        let end = range.end.max(range.start).checked_sub(1)?;
+        Some(OrdinalRangeInclusive::new(
+            from_index(range.start)?,
+            from_index(end)?,
+        ))
+    }
+
+    /// Converts the range to a range of 0-based indices, which can be used to
+    /// index a slice. The end of the index range is exclusive. An empty range is
+    /// converted to `start..start`.
+    ///
+    /// Returns [None] if an index is too big for `usize`.
+    pub fn try_to_index_range(&self) -> Option<Range<usize>> {
+        to_index_range(self.start.into0().to_u128(), self.end.into0().to_u128() + 1)
+    }
+}
+
+impl OrdinalRange<Osize> {
+    /// Converts the range to a range of 0-based indices, which can be used to
+    /// index a slice. An empty range is converted to `start..start`.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalRange};
+    ///
+    /// let data = ["a", "b", "c", "d"];
+    /// let range = OrdinalRange::new(ordinal!(second), ordinal!(4-th));
+    /// assert_eq!(&data[range.to_index_range()], ["b", "c"]);
+    /// ```
+    pub fn to_index_range(&self) -> Range<usize> {
+        self.try_to_index_range()
+            .expect("usize indices fit into usize")
+    }
+}
+
+impl OrdinalRangeInclusive<Osize> {
+    /// Converts the range to a range of 0-based indices, which can be used to
+    /// index a slice. The end of the index range is exclusive. An empty range is
+    /// converted to `start..start`.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalRangeInclusive};
+    ///
+    /// let data = ["a", "b", "c", "d"];
+    /// let range = OrdinalRangeInclusive::new(ordinal!(second), ordinal!(4-th));
+    /// assert_eq!(&data[range.to_index_range()], ["b", "c", "d"]);
+    /// ```
+    pub fn to_index_range(&self) -> Range<usize> {
+        self.try_to_index_range()
+            .expect("usize indices fit into usize")
+    }
+}
+
+/// Converts a length to the integer type. This can't fail, because the largest
+/// integer isn't a valid 0-based ordinal, so ranges are shorter than it.
+fn from_len<O: Ordinal>(len: u128) -> O::IntegerType {
+    O::IntegerType::from_u128(len).expect("length is in range")
+}
+
+impl<O> From<Range<O>> for OrdinalRange<O> {
+    fn from(range: Range<O>) -> Self {
+        OrdinalRange {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+impl<O: Copy> From<RangeInclusive<O>> for OrdinalRangeInclusive<O> {
+    fn from(range: RangeInclusive<O>) -> Self {
+        OrdinalRangeInclusive {
+            start: *range.start(),
+            end: *range.end(),
+        }
+    }
+}
+
+/// Creates a range from `start` (inclusive) to `end` (exclusive), which can be
+/// iterated over.
+///
+/// ```
+/// use num_ordinal::{ordinal, range, O32};
+///
+/// let v: Vec<_> = range(ordinal!(first O32), ordinal!(4-th)).into_iter().collect();
+/// assert_eq!(v, [ordinal!(first O32), ordinal!(second), ordinal!(third)]);
+/// ```
+pub fn range<O: Ordinal>(start: O, end: O) -> OrdinalRange<O> {
+    OrdinalRange::new(start, end)
+}
+
+/// Creates a range from `start` to `end`, both inclusive, which can be iterated over.
+///
+/// ```
+/// use num_ordinal::{ordinal, range_inclusive, O32};
+///
+/// let mut words = vec![];
+/// for o in range_inclusive(ordinal!(first O32), ordinal!(5-th)) {
+///     words.push(o.to_string());
+/// }
+/// assert_eq!(words, ["first", "second", "third", "4th", "5th"]);
+/// ```
+pub fn range_inclusive<O: Ordinal>(start: O, end: O) -> OrdinalRangeInclusive<O> {
+    OrdinalRangeInclusive::new(start, end)
+}
+
+impl<O: Ordinal> OrdinalRange<O> {
+    /// Returns an iterator over the ordinals in the range
+    pub fn iter(&self) -> RangeIter<O> {
+        RangeIter::new(self.start.into0().to_u128(), self.len_u128())
+    }
+}
+
+impl<O: Ordinal> OrdinalRangeInclusive<O> {
+    /// Returns an iterator over the ordinals in the range
+    pub fn iter(&self) -> RangeIter<O> {
+        RangeIter::new(self.start.into0().to_u128(), self.len_u128())
+    }
+}
+
+impl<O: Ordinal> IntoIterator for OrdinalRange<O> {
+    type Item = O;
+    type IntoIter = RangeIter<O>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<O: Ordinal> IntoIterator for OrdinalRangeInclusive<O> {
+    type Item = O;
+    type IntoIter = RangeIter<O>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the ordinals in an [OrdinalRange] or [OrdinalRangeInclusive]
+///
+/// Like the iterators over integer ranges in the standard library, it only implements
+/// [ExactSizeIterator] for ordinal types whose ranges always fit in a `usize`: [O8],
+/// [O16], [O32] and [Osize]. If a range of [O64](crate::O64) or [O128](crate::O128)
+/// contains more than `usize::MAX` ordinals, [Iterator::size_hint] returns
+/// `(usize::MAX, None)`.
+#[derive(Debug, Clone)]
+pub struct RangeIter<O> {
+    /// 0-based value of the next ordinal from the front
+    front: u128,
+    /// 0-based value after the next ordinal from the back
+    back: u128,
+    _marker: PhantomData<O>,
+}
+
+impl<O> RangeIter<O> {
+    fn new(start: u128, len: u128) -> Self {
+        RangeIter {
+            front: start,
+            back: start + len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<O: Ordinal> Iterator for RangeIter<O> {
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(from0_u128(self.front - 1))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<O> {
+        self.front = self.front.saturating_add(n as u128).min(self.back);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.back - self.front) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    fn last(mut self) -> Option<O> {
+        self.next_back()
+    }
+}
+
+impl<O: Ordinal> DoubleEndedIterator for RangeIter<O> {
+    fn next_back(&mut self) -> Option<O> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(from0_u128(self.back))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<O> {
+        self.back = self.back.saturating_sub(n as u128).max(self.front);
+        self.next_back()
+    }
+}
+
+impl ExactSizeIterator for RangeIter<O8> {}
+impl ExactSizeIterator for RangeIter<O16> {}
+impl ExactSizeIterator for RangeIter<O32> {}
+impl ExactSizeIterator for RangeIter<Osize> {}
+
+impl<O: Ordinal> FusedIterator for RangeIter<O> {}
+
+/// Iterator returned by [OrdinalRangeInclusive::split_into]
+#[derive(Debug, Clone)]
+pub struct SplitInto<O> {
+    /// 0-based start of the next sub-range
+    next: u128,
+    /// Number of sub-ranges that haven't been returned yet
+    parts: u128,
+    /// Minimum length of each sub-range
+    base: u128,
+    /// Number of remaining sub-ranges that are one longer than `base`
+    extra: u128,
+    _marker: PhantomData<O>,
+}
+
+impl<O> SplitInto<O> {
+    fn new(start: u128, len: u128, n: usize) -> Self {
+        assert!(n != 0, "can't split a range into 0 parts");
+        let n = n as u128;
+        SplitInto {
+            next: start,
+            parts: n.min(len),
+            base: len / n,
+            extra: len % n,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<O: Ordinal> Iterator for SplitInto<O> {
+    type Item = OrdinalRangeInclusive<O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.parts == 0 {
+            return None;
+        }
+        let len = if self.extra > 0 {
+            self.extra -= 1;
+            self.base + 1
+        } else {
+            self.base
+        };
+        let start = self.next;
+        let end = start + (len - 1);
+        self.parts -= 1;
+        if self.parts > 0 {
+            self.next = end + 1;
+        }
+        Some(OrdinalRangeInclusive::new(
+            from0_u128(start),
+            from0_u128(end),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.parts) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl<O: Ordinal> FusedIterator for SplitInto<O> {}
+
+/// Iterator returned by [OrdinalRangeInclusive::split_chunks]
+#[derive(Debug, Clone)]
+pub struct SplitChunks<O> {
+    /// 0-based start of the next sub-range
+    next: u128,
+    /// Number of ordinals that haven't been returned yet
+    remaining: u128,
+    chunk_size: u128,
+    _marker: PhantomData<O>,
+}
+
+impl<O> SplitChunks<O> {
+    fn new<I: Integer>(start: u128, len: u128, chunk_size: I) -> Self {
+        let chunk_size = chunk_size.to_u128();
+        assert!(chunk_size != 0, "chunk size must not be 0");
+        SplitChunks {
+            next: start,
+            remaining: len,
+            chunk_size,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<O: Ordinal> Iterator for SplitChunks<O> {
+    type Item = OrdinalRangeInclusive<O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let len = self.chunk_size.min(self.remaining);
+        let start = self.next;
+        let end = start + (len - 1);
+        self.remaining -= len;
+        if self.remaining > 0 {
+            self.next = end + 1;
+        }
+        Some(OrdinalRangeInclusive::new(
+            from0_u128(start),
+            from0_u128(end),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.remaining / self.chunk_size + (self.remaining % self.chunk_size != 0) as u128;
+        match usize::try_from(n) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl<O: Ordinal> FusedIterator for SplitChunks<O> {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    fn lens(parts: &[OrdinalRangeInclusive<O8>]) -> Vec<u8> {
+        parts.iter().map(|r| r.len()).collect()
+    }
+
+    #[test]
+    fn iterate() {
+        let v: Vec<u8> = range(O8::from1(2), O8::from1(5))
+            .into_iter()
+            .map(|o| o.into1())
+            .collect();
+        assert_eq!(v, [2, 3, 4]);
+        let v: Vec<u8> = range_inclusive(O8::from1(2), O8::from1(5))
+            .into_iter()
+            .rev()
+            .map(|o| o.into1())
+            .collect();
+        assert_eq!(v, [5, 4, 3, 2]);
+
+        let mut iter = range_inclusive(O8::from1(1), O8::from1(10)).iter();
+        assert_eq!(iter.len(), 10);
+        assert_eq!(iter.nth(2), Some(O8::from1(3)));
+        assert_eq!(iter.nth_back(2), Some(O8::from1(8)));
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.nth(10), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iterate_empty() {
+        assert_eq!(range(O8::from1(5), O8::from1(5)).iter().next(), None);
+        assert_eq!(range(O8::from1(5), O8::from1(2)).iter().len(), 0);
+        assert_eq!(range_inclusive(O8::from1(5), O8::from1(4)).iter().len(), 0);
+        assert_eq!(
+            range_inclusive(O8::from1(5), O8::from1(2))
+                .iter()
+                .next_back(),
+            None
+        );
+        assert_eq!(
+            range_inclusive(O8::from1(5), O8::from1(5))
+                .iter()
+                .collect::<Vec<_>>(),
+            [O8::from1(5)]
+        );
+    }
+
+    #[test]
+    fn iterate_to_last() {
+        let iter = range_inclusive(O8::from1(250), O8::last()).iter();
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.last(), Some(O8::last()));
+        assert_eq!(range_inclusive(O8::first(), O8::last()).iter().count(), 255);
+
+        let mut iter = range_inclusive(O128::last().prev(), O128::last()).iter();
+        assert_eq!(iter.next_back(), Some(O128::last()));
+        assert_eq!(iter.next(), Some(O128::last().prev()));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = range_inclusive(O128::first(), O128::last()).iter();
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+        iter.next();
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+        let mut iter = range_inclusive(O128::last().prev(), O128::last()).iter();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        let iter = range_inclusive(Osize::first(), Osize::last()).iter();
+        assert_eq!(iter.len(), usize::MAX);
+        assert_eq!(O32::first().to(O32::from1(3)).iter().count(), 3);
+    }
+
+    #[test]
+    fn split_into() {
+        let range = OrdinalRange::from(O8::from1(1)..O8::from1(11));
+        let parts: Vec<_> = range.split_into(4).collect();
+        assert_eq!(lens(&parts), [3, 3, 2, 2]);
+
+        let parts: Vec<_> = range.split_into(20).collect();
+        assert_eq!(lens(&parts), [1; 10]);
+
+        let empty = OrdinalRange::new(O8::from1(5), O8::from1(5));
+        assert_eq!(empty.split_into(3).next(), None);
+
+        let full = OrdinalRangeInclusive::new(O8::first(), O8::from0(254));
+        assert_eq!(full.len(), 255);
+        let parts: Vec<_> = full.split_into(2).collect();
+        assert_eq!(lens(&parts), [128, 127]);
+        assert_eq!(parts[1].end, O8::from0(254));
+    }
+
+    #[test]
+    fn split_chunks() {
+        let range = OrdinalRangeInclusive::new(O8::from1(3), O8::from1(12));
+        let parts: Vec<_> = range.split_chunks(4).collect();
+        assert_eq!(lens(&parts), [4, 4, 2]);
+        assert_eq!(range.split_chunks(4).size_hint(), (3, Some(3)));
+        assert_eq!(
+            parts[2],
+            OrdinalRangeInclusive::new(O8::from1(11), O8::from1(12))
+        );
+
+        let full = OrdinalRangeInclusive::new(O128::first(), O128::from0(u128::MAX - 1));
+        assert_eq!(full.len(), u128::MAX);
+        let parts: Vec<_> = full.split_chunks(u128::MAX / 2).collect();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[2].len(), 1);
+        assert_eq!(parts[2].end, O128::from0(u128::MAX - 1));
+    }
+
+    #[test]
+    fn index_ranges() {
+        let o = Osize::from1;
+        let data = [1, 2, 3, 4, 5];
+
+        let range = OrdinalRange::new(o(2), o(4));
+        assert_eq!(range.to_index_range(), 1..3);
+        assert_eq!(data[range.to_index_range()], [2, 3]);
+        let range = OrdinalRangeInclusive::new(o(2), o(4));
+        assert_eq!(range.to_index_range(), 1..4);
+        assert_eq!(data[range.to_index_range()], [2, 3, 4]);
+        let range = OrdinalRangeInclusive::new(o(1), o(5));
+        assert_eq!(data[range.to_index_range()], data);
+
+        // empty ranges
+        assert_eq!(OrdinalRange::new(o(4), o(2)).to_index_range(), 3..3);
+        assert_eq!(
+            OrdinalRangeInclusive::new(o(4), o(2)).to_index_range(),
+            3..3
+        );
+        assert_eq!(
+            OrdinalRangeInclusive::new(o(4), o(3)).to_index_range(),
+            3..3
+        );
+
+        let max = Osize::from0(usize::MAX - 1);
+        let range = OrdinalRangeInclusive::new(max, max);
+        assert_eq!(range.to_index_range(), usize::MAX - 1..usize::MAX);
+    }
+
+    #[test]
+    fn from_index_ranges() {
+        for start in 0..6 {
+            for end in start..6 {
+                let range = OrdinalRange::<O8>::from_index_range(start..end).unwrap();
+                assert_eq!(range.try_to_index_range(), Some(start..end));
+                assert_eq!(range.len() as usize, end - start);
+
+                let range = OrdinalRangeInclusive::<O8>::from_index_range(start..end);
+                if start == end && start == 0 {
+                    assert_eq!(range, None);
+                } else {
+                    let range = range.unwrap();
+                    assert_eq!(range.try_to_index_range(), Some(start..end));
+                    assert_eq!(range.len() as usize, end - start);
+                }
+            }
+        }
+
+        assert_eq!(
+            OrdinalRange::<O8>::from_index_range(0..254),
+            Some(OrdinalRange::new(O8::first(), O8::from0(254)))
+        );
+        // the exclusive end would be the 256th, which doesn't fit into `O8`
+        assert_eq!(OrdinalRange::<O8>::from_index_range(0..255), None);
+        assert_eq!(OrdinalRange::<Osize>::from_index_range(0..usize::MAX), None);
+        assert_eq!(
+            OrdinalRangeInclusive::<O8>::from_index_range(0..255),
+            Some(OrdinalRangeInclusive::new(O8::first(), O8::from0(254)))
+        );
+        assert_eq!(OrdinalRangeInclusive::<O8>::from_index_range(0..256), None);
+        assert_eq!(
+            OrdinalRangeInclusive::<Osize>::from_index_range(0..usize::MAX)
+                .map(|r| r.to_index_range()),
+            Some(0..usize::MAX)
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn wide_index_ranges() {
+        let range = OrdinalRange::new(O128::first(), O128::from0(u128::MAX - 1));
+        assert_eq!(range.try_to_index_range(), None);
+        let range = OrdinalRangeInclusive::new(O64::from0(3), O64::from0(u64::MAX - 1));
+        assert_eq!(range.try_to_index_range(), Some(3..usize::MAX));
+    }
+
+    /// Checks that the parts are contiguous and cover the whole range
+    fn check_coverage(range: OrdinalRangeInclusive<O8>, parts: &[OrdinalRangeInclusive<O8>]) {
+        if range.is_empty() {
+            assert!(parts.is_empty());
+            return;
+        }
+        assert_eq!(parts.first().unwrap().start, range.start);
+        assert_eq!(parts.last().unwrap().end, range.end);
+        for pair in parts.windows(2) {
+            assert_eq!(pair[0].end.next(), pair[1].start);
+        }
+        assert!(parts.iter().all(|p| !p.is_empty()));
+    }
+
+    #[test]
+    fn random_coverage() {
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut random = move |max: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % max
+        };
+
+        for _ in 0..2000 {
+            let start = O8::from0(random(255) as u8);
+            let end = O8::from0(random(255) as u8);
+            let range = OrdinalRangeInclusive::new(start, end);
+            let n = random(300) as usize + 1;
+
+            let parts: Vec<_> = range.split_into(n).collect();
+            check_coverage(range, &parts);
+            assert!(parts.len() <= n);
+            let part_lens = lens(&parts);
+            if let (Some(max), Some(min)) = (part_lens.iter().max(), part_lens.iter().min()) {
+                assert!(max - min <= 1);
+            }
+
+            let chunk_size = random(255) as u8 + 1;
+            let parts: Vec<_> = range.split_chunks(chunk_size).collect();
+            check_coverage(range, &parts);
+            if let Some((last, rest)) = lens(&parts).split_last() {
+                assert!(rest.iter().all(|&len| len == chunk_size));
+                assert!(*last <= chunk_size);
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Ranking of elements with placements
+//!
+//! ```
+//! use num_ordinal::{ordinal, ranking::{top_k_with_placements, TieStrategy}};
+//!
+//! let scores = [("Ann", 7), ("Bob", 9), ("Cid", 7), ("Dee", 3), ("Eve", 8)];
+//! let top = top_k_with_placements(scores, 3, TieStrategy::Standard, |a, b| a.1.cmp(&b.1));
+//! assert_eq!(top, [
+//!     (ordinal!(first), ("Bob", 9)),
+//!     (ordinal!(second), ("Eve", 8)),
+//!     (ordinal!(third), ("Ann", 7)),
+//! ]);
+//! ```
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{Ordinal, Osize};
+
+/// How placements are assigned to elements that compare equal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TieStrategy {
+    /// Equal elements share a placement, and the following placements are skipped,
+    /// e.g. 1st, 2nd, 2nd, 4th. This is also known as standard competition ranking.
+    #[default]
+    Standard,
+    /// Equal elements share a placement, and no placements are skipped,
+    /// e.g. 1st, 2nd, 2nd, 3rd
+    Dense,
+    /// Every element gets its own placement, and equal elements are placed in the
+    /// order they are encountered, e.g. 1st, 2nd, 3rd, 4th
+    Sequential,
+}
+
+/// Returns the `k` greatest elements according to `compare`, paired with their
+/// placements, from the 1st (the greatest) downwards.
+///
+/// The elements don't need to be sorted; this uses a bounded heap, so it runs in
+/// O(n log k) time and keeps at most `k` elements in memory.
+///
+/// The result is stable: Equal elements are returned in the order they are
+/// encountered, and if equal elements don't all fit into the result, the elements
+/// encountered first are kept.
+pub fn top_k_with_placements<I, F>(
+    iter: I,
+    k: usize,
+    ties: TieStrategy,
+    mut compare: F,
+) -> Vec<(Osize, I::Item)>
+where
+    I: IntoIterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+
+    // `a` ranks higher than `b` if it is greater, or if it is equal and came first
+    let mut higher = |a: &(usize, I::Item), b: &(usize, I::Item)| {
+        compare(&a.1, &b.1).then(b.0.cmp(&a.0)) == Ordering::Greater
+    };
+
+    // min-heap containing the lowest-ranking element at the root
+    let mut heap: Vec<(usize, I::Item)> = Vec::new();
+    for item in iter.into_iter().enumerate() {
+        if heap.len() < k {
+            heap.push(item);
+            sift_up(&mut heap, &mut higher);
+        } else if higher(&item, &heap[0]) {
+            heap[0] = item;
+            sift_down(&mut heap, &mut higher);
+        }
+    }
+
+    heap.sort_by(|a, b| {
+        if higher(a, b) {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    });
+
+    let mut result: Vec<(Osize, I::Item)> = Vec::with_capacity(heap.len());
+    for (i, (_, item)) in heap.into_iter().enumerate() {
+        let placement = match result.last() {
+            Some(&(prev, ref prev_item))
+                if ties != TieStrategy::Sequential
+                    && compare(prev_item, &item) == Ordering::Equal =>
+            {
+                prev
+            }
+            Some(&(prev, _)) if ties == TieStrategy::Dense => prev.next(),
+            _ => Osize::from0(i),
+        };
+        result.push((placement, item));
+    }
+    result
+}
+
+fn sift_up<T>(heap: &mut [T], higher: &mut impl FnMut(&T, &T) -> bool) {
+    let mut i = heap.len() - 1;
+    while i > 0 {
+        let parent = (i - 1) / 2;
+        if !higher(&heap[parent], &heap[i]) {
+            break;
+        }
+        heap.swap(i, parent);
+        i = parent;
+    }
+}
+
+fn sift_down<T>(heap: &mut [T], higher: &mut impl FnMut(&T, &T) -> bool) {
+    let mut i = 0;
+    loop {
+        let mut lowest = i;
+        for child in (2 * i + 1..heap.len()).take(2) {
+            if higher(&heap[lowest], &heap[child]) {
+                lowest = child;
+            }
+        }
+        if lowest == i {
+            break;
+        }
+        heap.swap(i, lowest);
+        i = lowest;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn placements(top: &[(Osize, u32)]) -> Vec<usize> {
+        top.iter().map(|&(o, _)| o.into1()).collect()
+    }
+
+    #[test]
+    fn strategies() {
+        let scores = [5, 9, 7, 9, 3, 7, 7];
+        let top = |k, ties| top_k_with_placements(scores, k, ties, u32::cmp);
+
+        let standard = top(6, TieStrategy::Standard);
+        assert_eq!(
+            standard.iter().map(|x| x.1).collect::<Vec<_>>(),
+            [9, 9, 7, 7, 7, 5]
+        );
+        assert_eq!(placements(&standard), [1, 1, 3, 3, 3, 6]);
+        assert_eq!(placements(&top(6, TieStrategy::Dense)), [1, 1, 2, 2, 2, 3]);
+        assert_eq!(
+            placements(&top(6, TieStrategy::Sequential)),
+            [1, 2, 3, 4, 5, 6]
+        );
+
+        assert_eq!(placements(&top(4, TieStrategy::Standard)), [1, 1, 3, 3]);
+        assert!(top(0, TieStrategy::Standard).is_empty());
+        assert_eq!(top(100, TieStrategy::Standard).len(), scores.len());
+    }
+
+    #[test]
+    fn stability() {
+        let entries = [(1, 'a'), (2, 'b'), (1, 'c'), (2, 'd'), (1, 'e')];
+        let top = top_k_with_placements(entries, 3, TieStrategy::Standard, |a, b| a.0.cmp(&b.0));
+        let names: Vec<char> = top.iter().map(|(_, (_, name))| *name).collect();
+        assert_eq!(names, ['b', 'd', 'a']);
+    }
+
+    /// Compares against sorting all elements and enumerating them
+    #[test]
+    fn random_data() {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut random = move |max: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % max
+        };
+
+        for _ in 0..500 {
+            let len = random(40) as usize;
+            let range = random(10) + 1;
+            let data: Vec<(u64, usize)> = (0..len).map(|i| (random(range), i)).collect();
+            let k = random(45) as usize;
+
+            let mut sorted = data.clone();
+            sorted.sort_by_key(|x| core::cmp::Reverse(x.0));
+            sorted.truncate(k);
+
+            for &ties in &[
+                TieStrategy::Standard,
+                TieStrategy::Dense,
+                TieStrategy::Sequential,
+            ] {
+                let top = top_k_with_placements(data.iter(), k, ties, |a, b| a.0.cmp(&b.0));
+                let items: Vec<_> = top.iter().map(|&(_, &item)| item).collect();
+                assert_eq!(items, sorted);
+
+                for (i, &(placement, item)) in top.iter().enumerate() {
+                    let greater = data.iter().filter(|x| x.0 > item.0);
+                    let expected = match ties {
+                        TieStrategy::Standard => greater.count(),
+                        TieStrategy::Dense => {
+                            let mut keys: Vec<_> = greater.map(|x| x.0).collect();
+                            keys.sort_unstable();
+                            keys.dedup();
+                            keys.len()
+                        }
+                        TieStrategy::Sequential => i,
+                    };
+                    assert_eq!(placement, Osize::from0(expected));
+                }
+            }
+        }
+    }
+}
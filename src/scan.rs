@@ -0,0 +1,305 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::{const_parse, suffix, Integer, Ordinal, Osize};
+
+/// Options for [find_ordinals_with]
+///
+/// ```
+/// use num_ordinal::{find_ordinals_with, FindOptions};
+///
+/// let options = FindOptions::new().words(false).period(true);
+/// let found: Vec<_> = find_ordinals_with("the first and the 2. one", options).collect();
+/// assert_eq!(found.len(), 1);
+/// assert_eq!(found[0].0, 18..19);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FindOptions {
+    numeric: bool,
+    words: bool,
+    period: bool,
+}
+
+impl Default for FindOptions {
+    fn default() -> Self {
+        FindOptions {
+            numeric: true,
+            words: true,
+            period: false,
+        }
+    }
+}
+
+impl FindOptions {
+    /// Creates the default options, which find numeric ordinals with a suffix
+    /// and ordinal words
+    pub fn new() -> Self {
+        FindOptions::default()
+    }
+
+    /// Sets whether numeric ordinals with an English suffix are found, e.g.
+    /// `3rd`. The default is `true`.
+    pub fn numeric(self, numeric: bool) -> Self {
+        FindOptions { numeric, ..self }
+    }
+
+    /// Sets whether English ordinal words are found, e.g. `twenty-first`. The
+    /// default is `true`.
+    pub fn words(self, words: bool) -> Self {
+        FindOptions { words, ..self }
+    }
+
+    /// Sets whether numbers followed by a period are found, e.g. `4.`, as they
+    /// are written in many European languages. The default is `false`, because
+    /// a number at the end of a sentence is indistinguishable from an ordinal.
+    ///
+    /// The period must be followed by whitespace or the end of the text, and
+    /// isn't included in the span.
+    pub fn period(self, period: bool) -> Self {
+        FindOptions { period, ..self }
+    }
+}
+
+/// Finds the ordinal numbers in a text, and returns their byte ranges and
+/// values. See [find_ordinals_with] for details.
+///
+/// ```
+/// use num_ordinal::{find_ordinals, ordinal};
+///
+/// let text = "The 21st runner finished twenty-first, not 3rdparty.";
+/// let found: Vec<_> = find_ordinals(text).collect();
+/// assert_eq!(found, [(4..8, ordinal!(21-st)), (25..37, ordinal!(21-st))]);
+/// ```
+pub fn find_ordinals(text: &str) -> FindOrdinals<'_> {
+    find_ordinals_with(text, FindOptions::default())
+}
+
+/// Finds the ordinal numbers in a text, and returns their byte ranges and
+/// values.
+///
+/// Ordinals must be separate words, i.e. they can't be directly preceded or
+/// followed by a letter or digit. Spelled-out ordinals are matched
+/// case-insensitively, and the longest possible sequence of words is used, so
+/// `one hundred first` is a single ordinal. Ordinals never overlap. Numbers
+/// that are zero or too big for [Osize] are skipped.
+pub fn find_ordinals_with(text: &str, options: FindOptions) -> FindOrdinals<'_> {
+    FindOrdinals {
+        text,
+        pos: 0,
+        options,
+    }
+}
+
+/// Iterator returned by [find_ordinals] and [find_ordinals_with]
+#[derive(Debug, Clone)]
+pub struct FindOrdinals<'a> {
+    text: &'a str,
+    pos: usize,
+    options: FindOptions,
+}
+
+impl FindOrdinals<'_> {
+    /// Returns the end position and value of an ordinal starting at `i`
+    fn ordinal_at(&self, i: usize) -> Option<(usize, u128)> {
+        let s = self.text;
+        let c = s[i..].chars().next()?;
+        if s[..i].ends_with(char::is_alphanumeric) {
+            return None;
+        }
+
+        let (n, end) = if c.is_ascii_digit() {
+            let digits_end = i + digits_len(&s[i..]);
+            let (n, end) = numeric_at(s, i)?;
+            if end > digits_end && self.options.numeric {
+                (n, end)
+            } else if self.options.period
+                && s[digits_end..].starts_with('.')
+                && s[digits_end + 1..]
+                    .chars()
+                    .next()
+                    .map_or(true, char::is_whitespace)
+            {
+                (n, digits_end)
+            } else {
+                return None;
+            }
+        } else if c.is_ascii_alphabetic() && self.options.words {
+            words_at(s, i)?
+        } else {
+            return None;
+        };
+
+        if s[end..].starts_with(char::is_alphanumeric) {
+            return None;
+        }
+        Some((end, n))
+    }
+}
+
+impl Iterator for FindOrdinals<'_> {
+    type Item = (Range<usize>, Osize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(c) = self.text[self.pos..].chars().next() {
+            let start = self.pos;
+            let found = self.ordinal_at(start);
+            if let Some((end, _)) = found {
+                self.pos = end;
+            } else {
+                // skip the rest of the word, so no ordinal is found inside it
+                let word_len = self.text[start..]
+                    .find(|c: char| !c.is_alphanumeric())
+                    .unwrap_or(self.text.len() - start);
+                self.pos += word_len.max(c.len_utf8());
+            }
+
+            let ordinal = found.and_then(|(_, n)| usize::from_u128(n).and_then(Osize::try_from1));
+            if let Some(o) = ordinal {
+                return Some((start..self.pos, o));
+            }
+        }
+        None
+    }
+}
+
+impl FusedIterator for FindOrdinals<'_> {}
+
+pub(crate) fn digits_len(s: &str) -> usize {
+    s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len())
+}
+
+/// Parses a number with digits and an optional suffix at position `i`, and
+/// returns it with the end position
+pub(crate) fn numeric_at(s: &str, i: usize) -> Option<(u128, usize)> {
+    let digits_end = i + digits_len(&s[i..]);
+    let n = s[i..digits_end].parse().ok()?;
+
+    let rest = &s[digits_end..];
+    let letters = rest
+        .find(|c: char| !c.is_alphabetic())
+        .unwrap_or(rest.len());
+    if letters > 0 && rest[..letters].eq_ignore_ascii_case(suffix(n)) {
+        Some((n, digits_end + letters))
+    } else {
+        Some((n, digits_end))
+    }
+}
+
+/// Parses English ordinal words at position `i`, and returns the number with
+/// the end position. The longest sequence of words that is an ordinal number
+/// is used.
+pub(crate) fn words_at(s: &str, i: usize) -> Option<(u128, usize)> {
+    let mut found = None;
+    let mut end = i;
+    loop {
+        let word_len = s[end..]
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(s.len() - end);
+        if word_len == 0 {
+            break;
+        }
+        end += word_len;
+        if s[end..].starts_with(|c: char| c.is_alphabetic()) {
+            // the word continues with non-ASCII letters
+            break;
+        }
+        if let Ok(n) = const_parse::parse_ignore_case(&s[i..end]) {
+            found = Some((n, end));
+        }
+        match s[end..].chars().next() {
+            Some(' ') | Some('-') => end += 1,
+            _ => break,
+        }
+    }
+    found
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn find(text: &str) -> Vec<(&str, usize)> {
+        find_with(text, FindOptions::new())
+    }
+
+    fn find_with(text: &str, options: FindOptions) -> Vec<(&str, usize)> {
+        find_ordinals_with(text, options)
+            .map(|(range, o)| (&text[range], o.into1()))
+            .collect()
+    }
+
+    #[test]
+    fn numeric() {
+        assert_eq!(
+            find("1st, 2nd and 3rd"),
+            [("1st", 1), ("2nd", 2), ("3rd", 3)]
+        );
+        assert_eq!(find("the 21st century"), [("21st", 21)]);
+        assert_eq!(find("the 112TH time"), [("112TH", 112)]);
+        assert_eq!(find("(4th)"), [("4th", 4)]);
+        assert!(find("3rdparty 4thly x5th 21 3th 11st").is_empty());
+        assert!(find("0th").is_empty());
+        assert!(find("99999999999999999999999999999999th").is_empty());
+    }
+
+    #[test]
+    fn words() {
+        assert_eq!(
+            find("First, the twenty-first and the One Hundred Second"),
+            [
+                ("First", 1),
+                ("twenty-first", 21),
+                ("One Hundred Second", 102)
+            ]
+        );
+        assert_eq!(find("a first-class seat"), [("first", 1)]);
+        assert_eq!(
+            find("the third fourth fifth"),
+            [("third", 3), ("fourth", 4), ("fifth", 5)]
+        );
+        assert_eq!(
+            find("one hundred and first"),
+            [("one hundred and first", 101)]
+        );
+        assert_eq!(find("twenty first"), [("twenty first", 21)]);
+        assert!(find("seconds thirds firstly unsecond twenty").is_empty());
+        assert!(find("firstü").is_empty());
+    }
+
+    #[test]
+    fn periods() {
+        let with_period = FindOptions::new().period(true);
+        assert_eq!(
+            find_with("am 4. Mai, 1.5 und 2.", with_period),
+            [("4", 4), ("2", 2)]
+        );
+        assert!(find("am 4. Mai").is_empty());
+        assert_eq!(find_with("4.\t3rd", with_period), [("4", 4), ("3rd", 3)]);
+    }
+
+    #[test]
+    fn options() {
+        let text = "the 2nd and third";
+        assert_eq!(
+            find_with(text, FindOptions::new().words(false)),
+            [("2nd", 2)]
+        );
+        assert_eq!(
+            find_with(text, FindOptions::new().numeric(false)),
+            [("third", 3)]
+        );
+        assert!(find_with(text, FindOptions::new().numeric(false).words(false)).is_empty());
+        // a number with a suffix isn't found with a period
+        assert!(find_with("2nd.", FindOptions::new().numeric(false).period(true)).is_empty());
+    }
+
+    #[test]
+    fn byte_ranges() {
+        let text = "Größe: 3rd – “second”";
+        let found: Vec<_> = find_ordinals(text).collect();
+        assert_eq!(found.len(), 2);
+        for (range, _) in found {
+            assert!(text.is_char_boundary(range.start) && text.is_char_boundary(range.end));
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use alloc::borrow::Cow;
+use alloc::format;
+#[cfg(not(feature = "serde-human-readable"))]
+use core::convert::TryFrom;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+
+use crate::{OrdinalInteger, O};
+
+/// The schema of the serialized ordinal. By default, this is a 0-based integer
+/// whose maximum is one below the highest number of the integer type. With the
+/// `serde-human-readable` feature, it is a string like `"4th"`.
+impl<T: OrdinalInteger + JsonSchema> JsonSchema for O<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("Ordinal_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("num_ordinal::O<{}>", T::schema_id()).into()
+    }
+
+    #[cfg(not(feature = "serde-human-readable"))]
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mut schema = T::json_schema(generator);
+        match u64::try_from(T::MAX.to_u128() - 1) {
+            Ok(max) => schema.insert("maximum".into(), max.into()),
+            Err(_) => schema.remove("maximum"),
+        };
+        schema.insert(
+            "description".into(),
+            "0-based ordinal number, i.e. 0 is the first and 3 is the 4th".into(),
+        );
+        schema
+    }
+
+    #[cfg(feature = "serde-human-readable")]
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": "^(first|second|third|[1-9][0-9]*(st|nd|rd|th))$",
+            "description": "1-based ordinal number with an English suffix, e.g. \"4th\". \
+                The first three are spelled out: \"first\", \"second\" and \"third\"",
+        })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use schemars::schema_for;
+    use serde_json::json;
+
+    use crate::*;
+
+    #[test]
+    #[cfg(not(feature = "serde-human-readable"))]
+    fn integer_schema() {
+        let schema = schema_for!(O32);
+        assert_eq!(schema.get("type"), Some(&json!("integer")));
+        assert_eq!(schema.get("minimum"), Some(&json!(0)));
+        assert_eq!(schema.get("maximum"), Some(&json!(u32::MAX - 1)));
+        let description = schema.get("description").unwrap().as_str().unwrap();
+        assert!(description.starts_with("0-based ordinal number"));
+
+        assert_eq!(schema_for!(O8).get("maximum"), Some(&json!(254)));
+        assert_eq!(schema_for!(O16).get("maximum"), Some(&json!(65534)));
+        assert_eq!(schema_for!(O64).get("maximum"), Some(&json!(u64::MAX - 1)));
+        assert_eq!(schema_for!(O128).get("maximum"), None);
+        assert_eq!(schema_for!(O128).get("minimum"), Some(&json!(0)));
+        assert_eq!(schema_for!(Osize).get("type"), Some(&json!("integer")));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", not(feature = "serde-human-readable")))]
+    fn bounds_match_serde() {
+        assert_eq!(serde_json::to_value(O8::first()).unwrap(), json!(0));
+        assert_eq!(serde_json::to_value(O8::last()).unwrap(), json!(254));
+    }
+
+    #[test]
+    #[cfg(feature = "serde-human-readable")]
+    fn string_schema() {
+        let schema = schema_for!(O32);
+        assert_eq!(schema.get("type"), Some(&json!("string")));
+        assert!(schema.get("pattern").is_some());
+        assert_eq!(serde_json::to_value(O32::from1(4)).unwrap(), json!("4th"));
+    }
+
+    #[test]
+    fn in_struct() {
+        #[allow(dead_code)]
+        struct Request {
+            position: O32,
+        }
+
+        impl schemars::JsonSchema for Request {
+            fn schema_name() -> std::borrow::Cow<'static, str> {
+                "Request".into()
+            }
+
+            fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                schemars::json_schema!({
+                    "type": "object",
+                    "properties": { "position": generator.subschema_for::<O32>() },
+                })
+            }
+        }
+
+        let schema = schema_for!(Request);
+        let position = schema.pointer("/properties/position").unwrap();
+        assert_eq!(
+            position.get("description"),
+            schema_for!(O32).get("description")
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Serializes ordinals as 1-based numbers, for use with `#[serde(with = "...")]`
+//!
+//! The `Serialize` and `Deserialize` impls of the ordinal types use the 0-based
+//! value, so the first ordinal is serialized as `0`. This module serializes it as `1`
+//! instead, which is easier to read for humans:
+//!
+//! ```
+//! use num_ordinal::{ordinal, O32};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Entry {
+//!     #[serde(with = "num_ordinal::serde1")]
+//!     position: O32,
+//!     #[serde(with = "num_ordinal::serde1::option")]
+//!     previous: Option<O32>,
+//! }
+//!
+//! let entry = Entry {
+//!     position: ordinal!(first O32),
+//!     previous: None,
+//! };
+//! let json = serde_json::to_string(&entry).unwrap();
+//! assert_eq!(json, r#"{"position":1,"previous":null}"#);
+//! assert_eq!(serde_json::from_str::<Entry>(&json).unwrap(), entry);
+//! ```
+//!
+//! Deserializing `0` returns an error.
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Ordinal;
+
+/// Serializes an ordinal as its 1-based value
+pub fn serialize<O, S>(o: &O, serializer: S) -> Result<S::Ok, S::Error>
+where
+    O: Ordinal,
+    O::IntegerType: Serialize,
+    S: Serializer,
+{
+    o.into1().serialize(serializer)
+}
+
+/// Deserializes an ordinal from its 1-based value
+pub fn deserialize<'de, O, D>(deserializer: D) -> Result<O, D::Error>
+where
+    O: Ordinal,
+    O::IntegerType: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let n = O::IntegerType::deserialize(deserializer)?;
+    O::from1_checked(n).map_err(D::Error::custom)
+}
+
+/// Serializes an optional ordinal as its 1-based value
+pub mod option {
+    use super::*;
+
+    /// Serializes an optional ordinal as its 1-based value
+    pub fn serialize<O, S>(o: &Option<O>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        O: Ordinal,
+        O::IntegerType: Serialize,
+        S: Serializer,
+    {
+        o.map(O::into1).serialize(serializer)
+    }
+
+    /// Deserializes an optional ordinal from its 1-based value
+    pub fn deserialize<'de, O, D>(deserializer: D) -> Result<Option<O>, D::Error>
+    where
+        O: Ordinal,
+        O::IntegerType: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        match Option::<O::IntegerType>::deserialize(deserializer)? {
+            Some(n) => O::from1_checked(n).map(Some).map_err(D::Error::custom),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    fn to_json<O: Ordinal>(o: O) -> String
+    where
+        O::IntegerType: serde::Serialize,
+    {
+        let mut buf = Vec::new();
+        serde1::serialize(&o, &mut serde_json::Serializer::new(&mut buf)).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    fn from_json<O: Ordinal>(s: &str) -> Result<O, serde_json::Error>
+    where
+        O::IntegerType: for<'de> serde::Deserialize<'de>,
+    {
+        serde1::deserialize(&mut serde_json::Deserializer::from_str(s))
+    }
+
+    #[test]
+    #[cfg(not(feature = "serde-human-readable"))]
+    fn default_impl() {
+        assert_eq!(serde_json::to_string(&O32::first()).unwrap(), "0");
+        assert_eq!(serde_json::to_string(&O8::last()).unwrap(), "254");
+        assert_eq!(serde_json::from_str::<O8>("254").unwrap(), O8::last());
+        assert_eq!(
+            serde_json::from_str::<O64>(&serde_json::to_string(&O64::last()).unwrap()).unwrap(),
+            O64::last()
+        );
+    }
+
+    #[test]
+    fn one_based() {
+        assert_eq!(to_json(O32::first()), "1");
+        assert_eq!(to_json(O8::last()), "255");
+        assert_eq!(to_json(Osize::from1(12)), "12");
+        assert_eq!(from_json::<O32>("1").unwrap(), O32::first());
+        assert_eq!(from_json::<O8>("255").unwrap(), O8::last());
+        assert_eq!(
+            from_json::<O64>(&to_json(O64::last())).unwrap(),
+            O64::last()
+        );
+        assert_eq!(
+            from_json::<O128>(&to_json(O128::last())).unwrap(),
+            O128::last()
+        );
+
+        let err = from_json::<O32>("0").unwrap_err();
+        assert!(err.to_string().contains("0 is not a valid 1-based ordinal"));
+        assert!(from_json::<O8>("256").is_err());
+        assert!(from_json::<O8>("-1").is_err());
+    }
+
+    #[test]
+    fn option() {
+        let to_json = |o: Option<O16>| {
+            let mut buf = Vec::new();
+            let mut ser = serde_json::Serializer::new(&mut buf);
+            serde1::option::serialize(&o, &mut ser).unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+        let from_json = |s: &str| {
+            serde1::option::deserialize::<O16, _>(&mut serde_json::Deserializer::from_str(s))
+        };
+        assert_eq!(to_json(Some(O16::first())), "1");
+        assert_eq!(to_json(None), "null");
+        assert_eq!(from_json("1").unwrap(), Some(O16::first()));
+        assert_eq!(from_json("65535").unwrap(), Some(O16::last()));
+        assert_eq!(from_json("null").unwrap(), None);
+        assert!(from_json("0").is_err());
+    }
+}
@@ -1,30 +1,206 @@
 use crate::*;
 
-macro_rules! impl_serde {
-    ($t:ident, $f:ident( $($call:tt)*)) => {
-        impl serde::Serialize for $t {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-            where
-                S: serde::Serializer,
-            {
-                serializer.$f(self $($call)*)
-            }
+/// Ordinals are serialized as 0-based integers, so that the highest number of the
+/// integer type isn't a valid value
+impl<T: OrdinalInteger + serde::Serialize> serde::Serialize for O<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[cfg(feature = "serde-human-readable")]
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+        self.into0().serialize(serializer)
+    }
+}
+
+impl<'de, T: OrdinalInteger + serde::Deserialize<'de>> serde::Deserialize<'de> for O<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[cfg(feature = "serde-human-readable")]
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_any(human::OrdinalVisitor::<Self>::new());
+        }
+        let n = T::deserialize(deserializer)?;
+        O::try_from0(n).ok_or_else(|| too_large(n.to_u128(), bits::<T>()))
+    }
+}
+
+/// Returns the number of bits of an integer type
+fn bits<I: Integer>() -> u32 {
+    128 - I::MAX.to_u128().leading_zeros()
+}
+
+fn too_large<E: serde::de::Error>(n: u128, bits: u32) -> E {
+    let article = if bits == 8 { "an" } else { "a" };
+    E::custom(format_args!(
+        "{} is too large for {} {}-bit ordinal",
+        n, article, bits
+    ))
+}
+
+/// Deserializes ordinals from strings like `"4th"` or from 0-based integers
+#[cfg(feature = "serde-human-readable")]
+mod human {
+    use core::convert::TryFrom;
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    use serde::de::{Error, Unexpected, Visitor};
+
+    use super::{bits, too_large};
+    use crate::{Integer, Ordinal};
+
+    pub(super) struct OrdinalVisitor<O>(PhantomData<O>);
+
+    impl<O> OrdinalVisitor<O> {
+        pub(super) fn new() -> Self {
+            OrdinalVisitor(PhantomData)
+        }
+    }
+
+    impl<O: Ordinal> Visitor<'_> for OrdinalVisitor<O> {
+        type Value = O;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an ordinal number like \"4th\", or a 0-based integer")
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<O, E> {
+            O::parse_str(v).map_err(|e| E::custom(format_args!("invalid ordinal {:?}: {}", v, e)))
         }
 
-        impl<'de> serde::Deserialize<'de> for $t {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-            where
-                D: serde::Deserializer<'de>,
-            {
-                serde::Deserialize::deserialize(deserializer).map($t)
+        fn visit_u64<E: Error>(self, v: u64) -> Result<O, E> {
+            self.visit_u128(v as u128)
+        }
+
+        fn visit_u128<E: Error>(self, v: u128) -> Result<O, E> {
+            O::IntegerType::from_u128(v)
+                .and_then(O::try_from0)
+                .ok_or_else(|| too_large(v, bits::<O::IntegerType>()))
+        }
+
+        fn visit_i64<E: Error>(self, v: i64) -> Result<O, E> {
+            match u64::try_from(v) {
+                Ok(v) => self.visit_u64(v),
+                Err(_) => Err(E::invalid_value(Unexpected::Signed(v), &self)),
             }
         }
-    };
+    }
+}
+
+/// Zeroth-inclusive ordinals are serialized as 0-based integers, where the zeroth is 0
+impl<T: OrdinalInteger + serde::Serialize> serde::Serialize for Oz<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: OrdinalInteger + serde::Deserialize<'de>> serde::Deserialize<'de> for Oz<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Oz)
+    }
 }
 
-impl_serde!(Osize, serialize_u64(.0 as u64));
-impl_serde!(O128, serialize_u128(.0));
-impl_serde!(O64, serialize_u64(.0));
-impl_serde!(O32, serialize_u32(.0));
-impl_serde!(O16, serialize_u16(.0));
-impl_serde!(O8, serialize_u8(.0));
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn deserialize_max() {
+        assert_eq!(serde_json::from_str::<O8>("254").unwrap(), O8::last());
+        let err = serde_json::from_str::<O8>("255").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("255 is too large for an 8-bit ordinal"));
+        let err = serde_json::from_str::<O16>("65535").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("65535 is too large for a 16-bit ordinal"));
+        let max = u64::MAX.to_string();
+        assert!(serde_json::from_str::<O64>(&max).is_err());
+        assert!(serde_json::from_str::<Osize>(&max).is_err());
+
+        // zeroth-inclusive ordinals can hold every integer
+        assert_eq!(serde_json::from_str::<Oz8>("255").unwrap(), Oz8::from0(255));
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        assert!(serde_json::from_str::<O8>("-1").is_err());
+        assert!(serde_json::from_str::<O8>("300").is_err());
+        assert!(serde_json::from_str::<O32>("1.5").is_err());
+        assert!(serde_json::from_str::<O32>("1.0").is_err());
+        #[cfg(not(feature = "serde-human-readable"))]
+        assert!(serde_json::from_str::<O32>("\"1\"").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde-human-readable")]
+    fn human_readable() {
+        assert_eq!(serde_json::to_string(&O32::first()).unwrap(), "\"first\"");
+        assert_eq!(serde_json::to_string(&O32::from1(4)).unwrap(), "\"4th\"");
+        assert_eq!(
+            serde_json::from_str::<O32>("\"4th\"").unwrap(),
+            O32::from1(4)
+        );
+        assert_eq!(
+            serde_json::from_str::<O32>("\"first\"").unwrap(),
+            O32::first()
+        );
+        // integers are still 0-based, so existing data can be read
+        assert_eq!(serde_json::from_str::<O32>("3").unwrap(), O32::from1(4));
+        for o in [O8::first(), O8::from1(22), O8::last()].iter() {
+            let json = serde_json::to_string(o).unwrap();
+            assert_eq!(serde_json::from_str::<O8>(&json).unwrap(), *o);
+        }
+        let max = serde_json::to_string(&O128::last()).unwrap();
+        assert_eq!(serde_json::from_str::<O128>(&max).unwrap(), O128::last());
+
+        assert!(serde_json::from_str::<O32>("\"2st\"").is_err());
+        assert!(serde_json::from_str::<O32>("\"0th\"").is_err());
+        assert!(serde_json::from_str::<O8>("\"256th\"").is_err());
+        assert!(serde_json::from_str::<O8>("-1").is_err());
+        let err = serde_json::from_str::<O8>("255").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("255 is too large for an 8-bit ordinal"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "serde-human-readable"))]
+    fn serialize_0_based() {
+        for n in 0..255u8 {
+            assert_eq!(serde_json::to_string(&O8::from0(n)).unwrap(), n.to_string());
+        }
+        let max = serde_json::to_string(&O64::last()).unwrap();
+        assert_eq!(max, (u64::MAX - 1).to_string());
+        let max = serde_json::to_string(&Osize::last()).unwrap();
+        assert_eq!(max, (usize::MAX - 1).to_string());
+        let max = serde_json::to_string(&O128::last()).unwrap();
+        assert_eq!(max, (u128::MAX - 1).to_string());
+    }
+
+    #[test]
+    fn binary() {
+        // binary formats always use the compact 0-based integer
+        let bytes = bincode::serialize(&O32::from1(4)).unwrap();
+        assert_eq!(bytes, 3u32.to_le_bytes());
+        assert_eq!(bincode::deserialize::<O32>(&bytes).unwrap(), O32::from1(4));
+        let bytes = bincode::serialize(&O8::last()).unwrap();
+        assert_eq!(bincode::deserialize::<O8>(&bytes).unwrap(), O8::last());
+        assert!(bincode::deserialize::<O8>(&[255]).is_err());
+        for n in 0..255u8 {
+            assert_eq!(bincode::serialize(&O8::from0(n)).unwrap(), [n]);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use core::fmt;
+
+use crate::{const_parse, Integer, Ordinal, OrdinalError};
+
+/// Prefix that labels a position, e.g. `#4` or `No. 4`
+///
+/// ```
+/// use num_ordinal::{ordinal, Ordinal, SigilStyle};
+///
+/// let o = ordinal!(4-th O32);
+/// assert_eq!(o.numbered(SigilStyle::Hash).to_string(), "#4");
+/// assert_eq!(o.numbered(SigilStyle::No).to_string(), "No. 4");
+/// assert_eq!(o.numbered(SigilStyle::Numero).spaced(true).to_string(), "№ 4");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SigilStyle {
+    /// `#4`
+    #[default]
+    Hash,
+    /// `No. 4`
+    No,
+    /// `Nr. 4`, common in German and Dutch
+    Nr,
+    /// `№4`, with the Unicode numero sign (U+2116)
+    Numero,
+}
+
+impl SigilStyle {
+    /// Returns the prefix, e.g. `No.`
+    pub const fn sigil(self) -> &'static str {
+        match self {
+            SigilStyle::Hash => "#",
+            SigilStyle::No => "No.",
+            SigilStyle::Nr => "Nr.",
+            SigilStyle::Numero => "№",
+        }
+    }
+
+    /// Returns whether the prefix is separated from the number with a space by default
+    pub const fn is_spaced(self) -> bool {
+        matches!(self, SigilStyle::No | SigilStyle::Nr)
+    }
+
+    /// Parses a 1-based ordinal number with any of the supported prefixes, e.g.
+    /// `#4`, `No. 4`, `nr.4` or `№ 4`. The prefix may be followed by whitespace,
+    /// and the number must be written with digits. Numbers without a prefix,
+    /// e.g. `4` or `4th`, are accepted as well.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalError, O32, SigilStyle};
+    ///
+    /// assert_eq!(SigilStyle::parse::<O32>("No. 4"), Ok(ordinal!(4-th)));
+    /// assert_eq!(SigilStyle::parse::<O32>("#0"), Err(OrdinalError::Zero));
+    /// ```
+    pub fn parse<O: Ordinal>(s: &str) -> Result<O, OrdinalError> {
+        let s = s.trim();
+        let rest = PREFIXES
+            .iter()
+            .find_map(|p| strip_prefix_ignore_case(s, p))
+            .map(str::trim_start)
+            .unwrap_or(s);
+        if !rest.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(OrdinalError::Invalid);
+        }
+        let n = const_parse::parse(rest)?;
+        O::IntegerType::from_u128(n)
+            .and_then(O::try_from1)
+            .ok_or(OrdinalError::Overflow)
+    }
+}
+
+const PREFIXES: &[&str] = &["#", "№", "No.", "Nr.", "No", "Nr"];
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    if head.eq_ignore_ascii_case(prefix) {
+        Some(&s[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+/// Display adapter that writes an ordinal number with a prefix, e.g. `#4`.
+///
+/// It is returned by [numbered](crate::Ordinal::numbered).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Numbered {
+    n: u128,
+    style: SigilStyle,
+    spaced: bool,
+}
+
+impl Numbered {
+    pub(crate) fn new(n: u128, style: SigilStyle) -> Self {
+        Numbered {
+            n,
+            style,
+            spaced: style.is_spaced(),
+        }
+    }
+
+    /// Sets whether the prefix is separated from the number with a space
+    pub fn spaced(mut self, spaced: bool) -> Self {
+        self.spaced = spaced;
+        self
+    }
+}
+
+impl fmt::Display for Numbered {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let space = if self.spaced { " " } else { "" };
+        write!(f, "{}{}{}", self.style.sigil(), space, self.n)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    const STYLES: [SigilStyle; 4] = [
+        SigilStyle::Hash,
+        SigilStyle::No,
+        SigilStyle::Nr,
+        SigilStyle::Numero,
+    ];
+
+    #[test]
+    fn display() {
+        let o = O32::from1(12);
+        let strings: Vec<_> = STYLES.iter().map(|&s| o.numbered(s).to_string()).collect();
+        assert_eq!(strings, ["#12", "No. 12", "Nr. 12", "№12"]);
+        assert_eq!(
+            o.numbered(SigilStyle::No).spaced(false).to_string(),
+            "No.12"
+        );
+        assert_eq!(
+            O128::from0(u128::MAX - 1)
+                .numbered(SigilStyle::Hash)
+                .to_string(),
+            format!("#{}", u128::MAX)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for &style in &STYLES {
+            for &spaced in &[true, false] {
+                for n in [1u16, 4, 23, 65535].iter().copied() {
+                    let s = O16::from1(n).numbered(style).spaced(spaced).to_string();
+                    assert_eq!(SigilStyle::parse(&s), Ok(O16::from1(n)), "{}", s);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let parse = SigilStyle::parse::<O8>;
+        assert_eq!(parse("  no. 7 "), Ok(O8::from1(7)));
+        assert_eq!(parse("NR 7"), Ok(O8::from1(7)));
+        assert_eq!(parse("7th"), Ok(O8::from1(7)));
+        assert_eq!(parse("#7th"), Ok(O8::from1(7)));
+        assert_eq!(parse("#0"), Err(OrdinalError::Zero));
+        assert_eq!(parse("#256"), Err(OrdinalError::Overflow));
+        assert_eq!(parse("#"), Err(OrdinalError::Invalid));
+        assert_eq!(parse("#seventh"), Err(OrdinalError::Invalid));
+        assert_eq!(parse("No -7"), Err(OrdinalError::Invalid));
+        assert_eq!(parse("#7 8"), Err(OrdinalError::Invalid));
+    }
+}
@@ -0,0 +1,236 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+use core::ops::{Index, IndexMut};
+
+#[cfg(feature = "alloc")]
+use crate::ranking::{top_k_with_placements, TieStrategy};
+#[cfg(feature = "alloc")]
+use crate::OrdinalIteratorExt;
+use crate::{Ordinal, Osize};
+
+/// Extension trait for slices
+pub trait OrdinalSliceExt<T> {
+    /// Returns the `o`-th element, or [None] if the slice is too short.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalSliceExt};
+    ///
+    /// let letters = ['a', 'b', 'c'];
+    /// assert_eq!(letters.get_ordinal(ordinal!(first)), Some(&'a'));
+    /// assert_eq!(letters.get_ordinal(ordinal!(4-th)), None);
+    /// ```
+    fn get_ordinal(&self, o: Osize) -> Option<&T>;
+
+    /// Returns a mutable reference to the `o`-th element, or [None] if the slice
+    /// is too short.
+    fn get_ordinal_mut(&mut self, o: Osize) -> Option<&mut T>;
+
+    /// Returns the 1-based positions of the elements that satisfy the predicate.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalSliceExt};
+    ///
+    /// let numbers = [4, 7, 1, 8];
+    /// assert_eq!(numbers.ordinal_positions_of(|&n| n > 5), [ordinal!(second Osize), ordinal!(4-th)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn ordinal_positions_of<P>(&self, predicate: P) -> Vec<Osize>
+    where
+        P: FnMut(&T) -> bool;
+
+    /// Returns references to the `k` greatest elements according to `compare`,
+    /// paired with their placements. See [top_k_with_placements] for details.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalSliceExt, ranking::TieStrategy};
+    ///
+    /// let scores = [7, 9, 7, 3];
+    /// let top = scores.top_k_with_placements(3, TieStrategy::Dense, |a, b| a.cmp(b));
+    /// assert_eq!(top, [(ordinal!(first), &9), (ordinal!(second), &7), (ordinal!(second), &7)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn top_k_with_placements<F>(&self, k: usize, ties: TieStrategy, compare: F) -> Vec<(Osize, &T)>
+    where
+        F: FnMut(&T, &T) -> Ordering;
+}
+
+impl<T> OrdinalSliceExt<T> for [T] {
+    fn get_ordinal(&self, o: Osize) -> Option<&T> {
+        self.get(o.into0())
+    }
+
+    fn get_ordinal_mut(&mut self, o: Osize) -> Option<&mut T> {
+        self.get_mut(o.into0())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ordinal_positions_of<P>(&self, mut predicate: P) -> Vec<Osize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .ordinal_positions(|item| predicate(item))
+            .collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn top_k_with_placements<F>(
+        &self,
+        k: usize,
+        ties: TieStrategy,
+        mut compare: F,
+    ) -> Vec<(Osize, &T)>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        top_k_with_placements(self, k, ties, |a, b| compare(a, b))
+    }
+}
+
+#[cold]
+#[track_caller]
+fn out_of_bounds(o: Osize, len: usize) -> ! {
+    match len {
+        0 => panic!(
+            "index out of bounds: the {} element, but the slice is empty",
+            o
+        ),
+        1 => panic!(
+            "index out of bounds: the {} element, but the slice has only 1 element",
+            o
+        ),
+        _ => panic!(
+            "index out of bounds: the {} element, but the slice has only {} elements",
+            o, len
+        ),
+    }
+}
+
+/// Indexes a slice with a 1-based ordinal, so `slice[ordinal!(first)]` is `slice[0]`
+///
+/// ```
+/// use num_ordinal::ordinal;
+///
+/// let mut v = vec![1, 2, 3];
+/// v[ordinal!(third Osize)] = 4;
+/// assert_eq!(v[ordinal!(first Osize)], 1);
+/// assert_eq!(v[..][ordinal!(third Osize)], 4);
+/// ```
+impl<T> Index<Osize> for [T] {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, o: Osize) -> &T {
+        let len = self.len();
+        match self.get_ordinal(o) {
+            Some(item) => item,
+            None => out_of_bounds(o, len),
+        }
+    }
+}
+
+impl<T> IndexMut<Osize> for [T] {
+    #[track_caller]
+    fn index_mut(&mut self, o: Osize) -> &mut T {
+        let len = self.len();
+        match self.get_ordinal_mut(o) {
+            Some(item) => item,
+            None => out_of_bounds(o, len),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Index<Osize> for Vec<T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, o: Osize) -> &T {
+        &self.as_slice()[o]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> IndexMut<Osize> for Vec<T> {
+    #[track_caller]
+    fn index_mut(&mut self, o: Osize) -> &mut T {
+        &mut self.as_mut_slice()[o]
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn get_ordinal() {
+        let mut v = vec![10, 20, 30];
+        assert_eq!(v.get_ordinal(Osize::first()), Some(&10));
+        assert_eq!(v.get_ordinal(Osize::from1(3)), Some(&30));
+        assert_eq!(v.get_ordinal(Osize::from1(4)), None);
+        assert_eq!(v.get_ordinal(Osize::last()), None);
+
+        *v.get_ordinal_mut(Osize::from1(2)).unwrap() += 1;
+        assert_eq!(v, [10, 21, 30]);
+        assert_eq!(v.get_ordinal_mut(Osize::from1(4)), None);
+
+        let empty: &[i32] = &[];
+        assert_eq!(empty.get_ordinal(Osize::first()), None);
+    }
+
+    #[test]
+    fn index() {
+        let mut v = vec!['a', 'b', 'c'];
+        assert_eq!(v[Osize::first()], 'a');
+        assert_eq!(v[Osize::from1(3)], 'c');
+        v[Osize::from1(3)] = 'd';
+        v[..][Osize::first()] = 'e';
+        assert_eq!(v, ['e', 'b', 'd']);
+        // integer indices still work
+        assert_eq!(v[0], 'e');
+    }
+
+    #[test]
+    #[should_panic(expected = "the 5th element, but the slice has only 3 elements")]
+    fn index_out_of_bounds() {
+        let v = [1, 2, 3];
+        let _ = v[Osize::from1(5)];
+    }
+
+    #[test]
+    #[should_panic(expected = "the first element, but the slice is empty")]
+    fn index_empty() {
+        let mut v: Vec<i32> = vec![];
+        v[Osize::first()] = 1;
+    }
+
+    #[test]
+    fn ordinal_positions_of() {
+        let v: Vec<_> = "a,,b,".split(',').collect();
+        assert_eq!(
+            v.ordinal_positions_of(|s| s.is_empty()),
+            [Osize::from1(2), Osize::from1(4)]
+        );
+
+        let empty: &[i32] = &[];
+        assert!(empty.ordinal_positions_of(|_| true).is_empty());
+    }
+
+    #[test]
+    fn top_k_with_placements() {
+        let words = ["bb", "a", "ccc", "dd"];
+        let top = words.top_k_with_placements(3, ranking::TieStrategy::Standard, |a, b| {
+            a.len().cmp(&b.len())
+        });
+        assert_eq!(
+            top,
+            [
+                (Osize::from1(1), &"ccc"),
+                (Osize::from1(2), &"bb"),
+                (Osize::from1(2), &"dd")
+            ]
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use std::convert::TryFrom;
+use std::format;
+
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Decode, Encode, Type};
+
+use crate::*;
+
+/// Implements the `sqlx` traits for an ordinal type, which is stored as the
+/// 1-based number in a column of the given signed integer type
+macro_rules! impl_sqlx {
+    ($t:ident as $sql:ident) => {
+        impl<DB: Database> Type<DB> for $t
+        where
+            $sql: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <$sql as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$sql as Type<DB>>::compatible(ty)
+            }
+        }
+
+        /// Encodes the 1-based number. Fails if it is too big for the column type.
+        impl<'q, DB: Database> Encode<'q, DB> for $t
+        where
+            $sql: Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+            ) -> Result<IsNull, BoxDynError> {
+                let n = $sql::try_from(self.into1()).map_err(|_| {
+                    format!(
+                        "the {} is too big for a column of type {}",
+                        self,
+                        stringify!($sql)
+                    )
+                })?;
+                n.encode_by_ref(buf)
+            }
+
+            fn size_hint(&self) -> usize {
+                core::mem::size_of::<$sql>()
+            }
+        }
+
+        /// Decodes the 1-based number. Fails if it is 0, negative or too big for
+        /// the ordinal type.
+        impl<'r, DB: Database> Decode<'r, DB> for $t
+        where
+            $sql: Decode<'r, DB>,
+        {
+            fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                let n = <$sql as Decode<DB>>::decode(value)?;
+                $t::try_from1_signed(n)
+                    .map_err(|e| format!("cannot decode {} as an ordinal: {}", n, e).into())
+            }
+        }
+    };
+}
+
+impl_sqlx!(Osize as i64);
+impl_sqlx!(O64 as i64);
+impl_sqlx!(O32 as i32);
+impl_sqlx!(O16 as i32);
+impl_sqlx!(O8 as i16);
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{Connection, Row, SqliteConnection};
+
+    use crate::*;
+
+    async fn connect() -> SqliteConnection {
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE ranks (id INTEGER PRIMARY KEY, rank INTEGER)")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        conn
+    }
+
+    async fn insert<'q, T>(conn: &mut SqliteConnection, id: i64, rank: T)
+    where
+        T: 'q + Send + sqlx::Encode<'q, sqlx::Sqlite> + sqlx::Type<sqlx::Sqlite>,
+    {
+        sqlx::query("INSERT INTO ranks (id, rank) VALUES (?, ?)")
+            .bind(id)
+            .bind(rank)
+            .execute(conn)
+            .await
+            .unwrap();
+    }
+
+    async fn select<T>(conn: &mut SqliteConnection, id: i64) -> Result<T, sqlx::Error>
+    where
+        T: for<'r> sqlx::Decode<'r, sqlx::Sqlite> + sqlx::Type<sqlx::Sqlite>,
+    {
+        sqlx::query("SELECT rank FROM ranks WHERE id = ?")
+            .bind(id)
+            .fetch_one(conn)
+            .await?
+            .try_get("rank")
+    }
+
+    #[tokio::test]
+    async fn round_trip() {
+        let mut conn = connect().await;
+        insert(&mut conn, 1, O32::from1(3)).await;
+        insert(&mut conn, 2, O8::last()).await;
+        insert(&mut conn, 3, Osize::first()).await;
+
+        assert_eq!(select::<O32>(&mut conn, 1).await.unwrap(), O32::from1(3));
+        assert_eq!(select::<O8>(&mut conn, 2).await.unwrap(), O8::last());
+        assert_eq!(select::<Osize>(&mut conn, 3).await.unwrap(), Osize::first());
+        // the 1-based number is stored
+        assert_eq!(select::<i64>(&mut conn, 1).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn null() {
+        let mut conn = connect().await;
+        insert(&mut conn, 1, None::<O32>).await;
+        insert(&mut conn, 2, Some(O32::first())).await;
+
+        assert_eq!(select::<Option<O32>>(&mut conn, 1).await.unwrap(), None);
+        assert_eq!(
+            select::<Option<O32>>(&mut conn, 2).await.unwrap(),
+            Some(O32::first())
+        );
+        assert!(select::<O32>(&mut conn, 1).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn decode_errors() {
+        let mut conn = connect().await;
+        insert(&mut conn, 1, 0i64).await;
+        insert(&mut conn, 2, -4i64).await;
+        insert(&mut conn, 3, 256i64).await;
+
+        let err = select::<O32>(&mut conn, 1).await.unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("cannot decode 0 as an ordinal: 0 is not a valid 1-based ordinal"));
+        let err = select::<O32>(&mut conn, 2).await.unwrap_err();
+        assert!(err.to_string().contains("cannot decode -4 as an ordinal"));
+        assert!(select::<O8>(&mut conn, 3).await.is_err());
+        assert_eq!(select::<O16>(&mut conn, 3).await.unwrap(), O16::from1(256));
+    }
+
+    #[tokio::test]
+    async fn encode_error() {
+        let mut conn = connect().await;
+        let err = sqlx::query("INSERT INTO ranks (id, rank) VALUES (1, ?)")
+            .bind(O32::last())
+            .execute(&mut conn)
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("the 4294967295th is too big for a column of type i32"));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{const_parse, suffix, Integer, Ordinal, OrdinalError};
+
+/// Style of English ordinal suffixes
+///
+/// ```
+/// use num_ordinal::{ordinal, Locale, Ordinal, SuffixStyle};
+///
+/// let o = ordinal!(2-nd O32).display_in(Locale::En);
+/// assert_eq!(o.to_string(), "2nd");
+/// assert_eq!(o.suffix_style(SuffixStyle::Legal).to_string(), "2d");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SuffixStyle {
+    /// The usual suffixes, e.g. `2nd`, `3rd` and `23rd`
+    #[default]
+    Standard,
+    /// The suffixes used in US legal citations (Bluebook style), where second
+    /// and third are abbreviated as `2d` and `3d`, e.g. `F.3d` or `2d Cir.`.
+    /// Numbers ending in 12 or 13 still take `th`.
+    Legal,
+}
+
+impl SuffixStyle {
+    /// Returns the suffix of a 1-based ordinal number in this style, e.g. `d`
+    /// for 22 in the legal style
+    pub const fn suffix(self, n: u128) -> &'static str {
+        match (self, n % 10, n % 100) {
+            (SuffixStyle::Legal, 2 | 3, t) if t != 12 && t != 13 => "d",
+            _ => suffix(n),
+        }
+    }
+
+    /// Parses a 1-based ordinal number written with digits and an optional
+    /// suffix, e.g. `23rd`, or with English words, e.g. `twenty-third`.
+    ///
+    /// The standard suffixes are always accepted. The legal style additionally
+    /// accepts its own suffixes, e.g. `23d`.
+    ///
+    /// ```
+    /// use num_ordinal::{ordinal, OrdinalError, O32, SuffixStyle};
+    ///
+    /// assert_eq!(SuffixStyle::Legal.parse::<O32>("2d"), Ok(ordinal!(second)));
+    /// assert_eq!(SuffixStyle::Legal.parse::<O32>("2nd"), Ok(ordinal!(second)));
+    /// assert_eq!(SuffixStyle::Standard.parse::<O32>("2d"), Err(OrdinalError::Invalid));
+    /// ```
+    pub fn parse<O: Ordinal>(self, s: &str) -> Result<O, OrdinalError> {
+        let n = const_parse::parse_with(s, self)?;
+        O::IntegerType::from_u128(n)
+            .and_then(O::try_from1)
+            .ok_or(OrdinalError::Overflow)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn legal_suffixes() {
+        let legal = |n| SuffixStyle::Legal.suffix(n);
+        assert_eq!(legal(1), "st");
+        assert_eq!(legal(2), "d");
+        assert_eq!(legal(3), "d");
+        assert_eq!(legal(4), "th");
+        assert_eq!(legal(11), "th");
+        assert_eq!(legal(12), "th");
+        assert_eq!(legal(13), "th");
+        assert_eq!(legal(22), "d");
+        assert_eq!(legal(33), "d");
+        assert_eq!(legal(112), "th");
+        assert_eq!(legal(123), "d");
+
+        for n in 1..1000 {
+            if !matches!(n % 10, 2 | 3) || matches!(n % 100, 12 | 13) {
+                assert_eq!(legal(n), SuffixStyle::Standard.suffix(n), "{}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn formatting() {
+        let f = OrdinalFormatter::new().suffix_style(SuffixStyle::Legal);
+        assert_eq!(f.fmt(O32::from1(2)).to_string(), "2d");
+        assert_eq!(f.fmt(O32::from1(43)).to_string(), "43d");
+        assert_eq!(f.fmt(O32::from1(42)).to_string(), "42d");
+        assert_eq!(f.fmt(O32::from1(12)).to_string(), "12th");
+        assert_eq!(f.grouping(',').fmt(O32::from1(1002)).to_string(), "1,002d");
+        // words are not affected
+        assert_eq!(f.words().fmt(O32::from1(2)).to_string(), "second");
+    }
+
+    #[test]
+    fn parsing() {
+        let legal = |s| SuffixStyle::Legal.parse::<O8>(s);
+        let standard = |s| SuffixStyle::Standard.parse::<O8>(s);
+        assert_eq!(legal("3d"), Ok(O8::from1(3)));
+        assert_eq!(legal("3rd"), Ok(O8::from1(3)));
+        assert_eq!(legal("22d"), Ok(O8::from1(22)));
+        assert_eq!(legal("third"), Ok(O8::from1(3)));
+        assert_eq!(legal("12d"), Err(OrdinalError::Invalid));
+        assert_eq!(legal("4d"), Err(OrdinalError::Invalid));
+        assert_eq!(legal("256th"), Err(OrdinalError::Overflow));
+        assert_eq!(legal("0th"), Err(OrdinalError::Zero));
+        assert_eq!(standard("3rd"), Ok(O8::from1(3)));
+        assert_eq!(standard("3d"), Err(OrdinalError::Invalid));
+    }
+}
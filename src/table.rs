@@ -0,0 +1,114 @@
+//! Precomputed strings of small ordinal numbers, as they are printed by `Display`
+
+/// The number of ordinals in the table, from the 1st to the 256th
+pub(crate) const LEN: usize = 256;
+
+const WORDS: [&str; 3] = ["first", "second", "third"];
+
+const fn suffix(n: usize) -> &'static [u8] {
+    crate::suffix(n as u128).as_bytes()
+}
+
+const fn digits(n: usize) -> usize {
+    if n >= 100 {
+        3
+    } else if n >= 10 {
+        2
+    } else {
+        1
+    }
+}
+
+const fn str_len(n: usize) -> usize {
+    if n <= WORDS.len() {
+        WORDS[n - 1].len()
+    } else {
+        digits(n) + 2
+    }
+}
+
+const fn total_len() -> usize {
+    let mut len = 0;
+    let mut n = 1;
+    while n <= LEN {
+        len += str_len(n);
+        n += 1;
+    }
+    len
+}
+
+const TOTAL_LEN: usize = total_len();
+
+/// All strings concatenated
+const BYTES: [u8; TOTAL_LEN] = {
+    let mut bytes = [0; TOTAL_LEN];
+    let mut pos = 0;
+    let mut n = 1;
+    while n <= LEN {
+        if n <= WORDS.len() {
+            let word = WORDS[n - 1].as_bytes();
+            let mut i = 0;
+            while i < word.len() {
+                bytes[pos + i] = word[i];
+                i += 1;
+            }
+        } else {
+            let digits = digits(n);
+            let mut rest = n;
+            let mut i = digits;
+            while i > 0 {
+                i -= 1;
+                bytes[pos + i] = b'0' + (rest % 10) as u8;
+                rest /= 10;
+            }
+            bytes[pos + digits] = suffix(n)[0];
+            bytes[pos + digits + 1] = suffix(n)[1];
+        }
+        pos += str_len(n);
+        n += 1;
+    }
+    bytes
+};
+
+/// The strings of the 1st to the 256th ordinal number
+pub(crate) const STRINGS: [&str; LEN] = {
+    let mut strings = [""; LEN];
+    let mut rest: &[u8] = &BYTES;
+    let mut n = 1;
+    while n <= LEN {
+        let (s, tail) = rest.split_at(str_len(n));
+        strings[n - 1] = match core::str::from_utf8(s) {
+            Ok(s) => s,
+            Err(_) => panic!("invalid UTF-8"),
+        };
+        rest = tail;
+        n += 1;
+    }
+    strings
+};
+
+/// Returns the precomputed string of a 1-based ordinal number, if it is in the table
+pub(crate) fn get(n: u128) -> Option<&'static str> {
+    if (1..=LEN as u128).contains(&n) {
+        Some(STRINGS[n as usize - 1])
+    } else {
+        None
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contents() {
+        assert_eq!(get(0), None);
+        assert_eq!(get(1), Some("first"));
+        assert_eq!(get(3), Some("third"));
+        assert_eq!(get(4), Some("4th"));
+        assert_eq!(get(111), Some("111th"));
+        assert_eq!(get(122), Some("122nd"));
+        assert_eq!(get(256), Some("256th"));
+        assert_eq!(get(257), None);
+    }
+}
@@ -0,0 +1,385 @@
+use core::fmt::{self, Write};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+pub(crate) const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+pub(crate) const ONES_ORDINAL: [&str; 20] = [
+    "zeroth",
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+];
+
+pub(crate) const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+pub(crate) const TENS_ORDINAL: [&str; 10] = [
+    "",
+    "",
+    "twentieth",
+    "thirtieth",
+    "fortieth",
+    "fiftieth",
+    "sixtieth",
+    "seventieth",
+    "eightieth",
+    "ninetieth",
+];
+
+/// Short scale names for powers of 1000, enough for the whole `u128` range
+pub(crate) const SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+/// Style options for English number words, shared by ordinal words (e.g.
+/// [to_words](crate::Ordinal::to_words)) and cardinal words (e.g. [cardinal_words])
+///
+/// ```
+/// use num_ordinal::{cardinal_words, ordinal, Ordinal, WordStyle};
+///
+/// let style = WordStyle::new().and(true);
+/// assert_eq!(ordinal!(123-rd O32).to_words_with(style), "one hundred and twenty-third");
+/// assert_eq!(style.cardinal(123), "one hundred and twenty-three");
+/// assert_eq!(cardinal_words(123), "one hundred twenty-three");
+/// ```
+///
+/// Large numbers use the short scale, where a billion is a thousand millions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WordStyle {
+    hyphenate: bool,
+    and: bool,
+}
+
+impl Default for WordStyle {
+    fn default() -> Self {
+        WordStyle {
+            hyphenate: true,
+            and: false,
+        }
+    }
+}
+
+impl WordStyle {
+    /// Creates the default style, e.g. _one hundred twenty-three_
+    pub fn new() -> Self {
+        WordStyle::default()
+    }
+
+    /// Sets whether tens and ones are joined with a hyphen, e.g. _twenty-three_,
+    /// or with a space, e.g. _twenty three_. The default is `true`.
+    pub fn hyphenate(self, hyphenate: bool) -> Self {
+        WordStyle { hyphenate, ..self }
+    }
+
+    /// Sets whether _and_ is inserted before the tens and ones, as in British
+    /// English, e.g. _one hundred and three_ or _one thousand and three_. The
+    /// default is `false`.
+    pub fn and(self, and: bool) -> Self {
+        WordStyle { and, ..self }
+    }
+
+    /// Returns the cardinal words for a number in this style, e.g. _twenty-three_
+    #[cfg(feature = "alloc")]
+    pub fn cardinal(self, n: u128) -> String {
+        let mut s = String::new();
+        write_words(&mut s, n, Form::Cardinal, self).unwrap();
+        s
+    }
+
+    /// Returns the ordinal words for a 1-based number in this style, e.g. _twenty-third_
+    #[cfg(feature = "alloc")]
+    pub fn ordinal(self, n: u128) -> String {
+        let mut s = String::new();
+        write_words(&mut s, n, Form::Ordinal, self).unwrap();
+        s
+    }
+}
+
+/// Returns the cardinal words for a number in the default [WordStyle], e.g.
+/// _one hundred twenty-three_
+///
+/// ```
+/// use num_ordinal::cardinal_words;
+///
+/// assert_eq!(cardinal_words(0), "zero");
+/// assert_eq!(cardinal_words(1_000_021), "one million twenty-one");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn cardinal_words(n: u128) -> String {
+    WordStyle::default().cardinal(n)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Form {
+    #[cfg(feature = "alloc")]
+    Cardinal,
+    Ordinal,
+}
+
+struct WordWriter<'a, W: ?Sized> {
+    w: &'a mut W,
+    form: Form,
+    style: WordStyle,
+}
+
+impl<W: Write + ?Sized> WordWriter<'_, W> {
+    /// Writes a word from one of the tables. Only the last word of a number is
+    /// written in ordinal form, e.g. _one hundred twenty-**fourth**_.
+    fn word(&mut self, cardinal: &[&str], ordinal: &[&str], i: usize, last: bool) -> fmt::Result {
+        if last && self.form == Form::Ordinal {
+            self.w.write_str(ordinal[i])
+        } else {
+            self.w.write_str(cardinal[i])
+        }
+    }
+
+    fn scale(&mut self, name: &str, last: bool) -> fmt::Result {
+        self.w.write_str(name)?;
+        if last && self.form == Form::Ordinal {
+            self.w.write_str("th")?;
+        }
+        Ok(())
+    }
+
+    /// Writes a number below 1000
+    fn group(&mut self, n: usize, last: bool) -> fmt::Result {
+        let (hundreds, rest) = (n / 100, n % 100);
+        if hundreds > 0 {
+            self.word(&ONES, &ONES, hundreds, false)?;
+            self.w.write_char(' ')?;
+            self.scale("hundred", last && rest == 0)?;
+            if rest > 0 {
+                self.w.write_char(' ')?;
+                self.and()?;
+            }
+        }
+        if rest >= 20 {
+            let (tens, ones) = (rest / 10, rest % 10);
+            self.word(&TENS, &TENS_ORDINAL, tens, last && ones == 0)?;
+            if ones > 0 {
+                self.w
+                    .write_char(if self.style.hyphenate { '-' } else { ' ' })?;
+                self.word(&ONES, &ONES_ORDINAL, ones, last)?;
+            }
+        } else if rest > 0 {
+            self.word(&ONES, &ONES_ORDINAL, rest, last)?;
+        }
+        Ok(())
+    }
+
+    fn and(&mut self) -> fmt::Result {
+        if self.style.and {
+            self.w.write_str("and ")?;
+        }
+        Ok(())
+    }
+
+    fn number(&mut self, n: u128) -> fmt::Result {
+        if n == 0 {
+            return self.word(&ONES, &ONES_ORDINAL, 0, true);
+        }
+
+        let mut groups = [0; SCALES.len()];
+        let mut rest = n;
+        for group in &mut groups {
+            *group = (rest % 1000) as usize;
+            rest /= 1000;
+        }
+
+        let mut first = true;
+        for i in (0..groups.len()).rev() {
+            if groups[i] == 0 {
+                continue;
+            }
+            if !first {
+                self.w.write_char(' ')?;
+                if i == 0 && groups[i] < 100 {
+                    self.and()?;
+                }
+            }
+            first = false;
+
+            let last = groups[..i].iter().all(|&g| g == 0);
+            self.group(groups[i], last && i == 0)?;
+            if i > 0 {
+                self.w.write_char(' ')?;
+                self.scale(SCALES[i], last)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes the English words for a number, e.g. _one hundred twenty-fourth_
+pub(crate) fn write_words<W: Write + ?Sized>(
+    w: &mut W,
+    n: u128,
+    form: Form,
+    style: WordStyle,
+) -> fmt::Result {
+    WordWriter { w, form, style }.number(n)
+}
+
+/// Display adapter that writes the English words for an ordinal number in the
+/// default [WordStyle]
+pub(crate) struct Words(pub(crate) u128);
+
+impl fmt::Display for Words {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_words(f, self.0, Form::Ordinal, WordStyle::default())
+    }
+}
+
+/// Returns the English words for an ordinal number
+#[cfg(feature = "alloc")]
+pub(crate) fn words(n: u128) -> String {
+    WordStyle::default().ordinal(n)
+}
+
+/// Returns the English adverb for an ordinal number, e.g. _firstly_ or _twenty-fourthly_
+#[cfg(feature = "alloc")]
+pub(crate) fn adverb(n: u128) -> String {
+    let mut s = words(n);
+    s.push_str("ly");
+    s
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_numbers() {
+        assert_eq!(words(1), "first");
+        assert_eq!(words(2), "second");
+        assert_eq!(words(3), "third");
+        assert_eq!(words(4), "fourth");
+        assert_eq!(words(12), "twelfth");
+        assert_eq!(words(19), "nineteenth");
+        assert_eq!(words(20), "twentieth");
+        assert_eq!(words(21), "twenty-first");
+        assert_eq!(words(40), "fortieth");
+        assert_eq!(words(99), "ninety-ninth");
+        assert_eq!(words(0), "zeroth");
+    }
+
+    #[test]
+    fn adverbs() {
+        assert_eq!(adverb(1), "firstly");
+        assert_eq!(adverb(2), "secondly");
+        assert_eq!(adverb(3), "thirdly");
+        assert_eq!(adverb(4), "fourthly");
+        assert_eq!(adverb(12), "twelfthly");
+        assert_eq!(adverb(21), "twenty-firstly");
+    }
+
+    #[test]
+    fn cardinals() {
+        assert_eq!(cardinal_words(0), "zero");
+        assert_eq!(cardinal_words(4), "four");
+        assert_eq!(cardinal_words(40), "forty");
+        assert_eq!(cardinal_words(123), "one hundred twenty-three");
+        assert_eq!(cardinal_words(1000), "one thousand");
+        assert_eq!(cardinal_words(2_000_300), "two million three hundred");
+    }
+
+    #[test]
+    fn styles() {
+        let british = WordStyle::new().and(true);
+        assert_eq!(british.cardinal(103), "one hundred and three");
+        assert_eq!(british.ordinal(103), "one hundred and third");
+        assert_eq!(british.cardinal(1003), "one thousand and three");
+        assert_eq!(british.ordinal(1_000_020), "one million and twentieth");
+        assert_eq!(british.cardinal(1100), "one thousand one hundred");
+        assert_eq!(
+            british.cardinal(1123),
+            "one thousand one hundred and twenty-three"
+        );
+        assert_eq!(british.cardinal(100), "one hundred");
+        assert_eq!(british.cardinal(23), "twenty-three");
+
+        let spaced = WordStyle::new().hyphenate(false);
+        assert_eq!(spaced.cardinal(23), "twenty three");
+        assert_eq!(spaced.ordinal(23), "twenty third");
+
+        for n in (0..2000).chain(999_990..1_000_100) {
+            for &style in &[WordStyle::new(), british, spaced] {
+                let (ordinal, cardinal) = (style.ordinal(n), style.cardinal(n));
+                let prefix = |s: &str| s.rsplit_once(' ').map(|(a, _)| a.to_string());
+                assert_eq!(prefix(&ordinal), prefix(&cardinal), "{}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn large_numbers() {
+        assert_eq!(words(100), "one hundredth");
+        assert_eq!(words(123), "one hundred twenty-third");
+        assert_eq!(words(1000), "one thousandth");
+        assert_eq!(words(1001), "one thousand first");
+        assert_eq!(words(2_000_000), "two millionth");
+        assert_eq!(words(1_000_300), "one million three hundredth");
+        assert_eq!(
+            words(u128::MAX),
+            "three hundred forty undecillion two hundred eighty-two decillion \
+             three hundred sixty-six nonillion nine hundred twenty octillion \
+             nine hundred thirty-eight septillion four hundred sixty-three sextillion \
+             four hundred sixty-three quintillion three hundred seventy-four quadrillion \
+             six hundred seven trillion four hundred thirty-one billion \
+             seven hundred sixty-eight million two hundred eleven thousand \
+             four hundred fifty-fifth"
+        );
+    }
+}
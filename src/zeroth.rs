@@ -0,0 +1,284 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Add, Sub};
+
+use crate::{display_ordinal_u128, fmt_ordinal_u128, Ordinal, OrdinalError, OrdinalInteger, O};
+
+/// Zeroth-inclusive ordinal number represented by the integer type `T`
+///
+/// Unlike [O], this type can represent the _zeroth_ position, which precedes
+/// the first one. This is useful in mathematical contexts, or when the
+/// positions are already numbered from zero and displayed as such (e.g. "0th").
+/// When you just need a type-safe position in a list, use [O] instead: Its
+/// first value is the _first_ position, which is how people usually count.
+///
+/// This type is usually used through its aliases [Oz8], [Oz16], [Oz32], [Oz64],
+/// [Oz128] and [Ozsize].
+///
+/// Every `u32` is a valid `Oz32`, including `u32::MAX`. Zeroth-inclusive
+/// ordinals don't implement the [Ordinal] trait, since its `from1`/`into1`
+/// conversions have no meaning for the zeroth position. They can be converted
+/// to and from the standard types, where converting the zeroth position fails:
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use num_ordinal::{ordinal, O32, Oz32};
+///
+/// let z = Oz32::zeroth();
+/// assert_eq!(z.to_string(), "zeroth");
+/// assert_eq!(z.next(), Oz32::first());
+/// assert_eq!(Oz32::from0(4).to_string(), "4th");
+///
+/// assert_eq!(Oz32::from(ordinal!(first O32)), Oz32::first());
+/// assert_eq!(O32::try_from(Oz32::first()), Ok(ordinal!(first O32)));
+/// assert!(O32::try_from(Oz32::zeroth()).is_err());
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct Oz<T: OrdinalInteger>(pub(crate) T);
+
+/// Zeroth-inclusive ordinal number represented by [usize]
+pub type Ozsize = Oz<usize>;
+
+/// Zeroth-inclusive ordinal number represented by [u128]
+pub type Oz128 = Oz<u128>;
+
+/// Zeroth-inclusive ordinal number represented by [u64]
+pub type Oz64 = Oz<u64>;
+
+/// Zeroth-inclusive ordinal number represented by [u32]
+pub type Oz32 = Oz<u32>;
+
+/// Zeroth-inclusive ordinal number represented by [u16]
+pub type Oz16 = Oz<u16>;
+
+/// Zeroth-inclusive ordinal number represented by [u8]
+pub type Oz8 = Oz<u8>;
+
+macro_rules! impl_zeroth {
+    ($int:ident) => {
+        impl Oz<$int> {
+            /// The zeroth ordinal number
+            pub const fn zeroth() -> Self {
+                Oz(0)
+            }
+
+            /// The first ordinal number, which comes after the zeroth
+            pub const fn first() -> Self {
+                Oz(1)
+            }
+
+            /// Converts an integer to an ordinal number, where 0 is the zeroth
+            pub const fn from0(t: $int) -> Self {
+                Oz(t)
+            }
+
+            /// Returns the equivalent integer, where the zeroth is 0
+            pub const fn into0(self) -> $int {
+                self.0
+            }
+        }
+    };
+}
+
+impl_zeroth!(usize);
+impl_zeroth!(u128);
+impl_zeroth!(u64);
+impl_zeroth!(u32);
+impl_zeroth!(u16);
+impl_zeroth!(u8);
+
+impl<T: OrdinalInteger> Oz<T> {
+    /// Computes the ordinal number that comes after this one. Panics if this
+    /// is the last ordinal number, also in release builds.
+    #[track_caller]
+    pub fn next(self) -> Self {
+        match self.try_next() {
+            Some(o) => o,
+            None => panic!("the {} is the last ordinal number of this type", self),
+        }
+    }
+
+    /// Computes the ordinal number that comes after this one, or returns
+    /// [None] if this is the last ordinal number
+    pub fn try_next(self) -> Option<Self> {
+        self.0.checked_inc().map(Oz)
+    }
+
+    /// Adds an integer to this ordinal number, or returns [None] if the
+    /// result is too big for this type
+    pub fn checked_add(self, rhs: T) -> Option<Self> {
+        let n = self.0.to_u128().checked_add(rhs.to_u128())?;
+        T::from_u128(n).map(Oz)
+    }
+
+    /// Subtracts an integer from this ordinal number, or returns [None] if
+    /// the result would be before the zeroth
+    pub fn checked_sub(self, rhs: T) -> Option<Self> {
+        if rhs <= self.0 {
+            Some(Oz(self.0 - rhs))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: OrdinalInteger> fmt::Debug for Oz<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.to_u128() {
+            0 => f.write_str("zeroth"),
+            n => fmt_ordinal_u128(n, f),
+        }
+    }
+}
+
+/// Formats the ordinal like the `Display` impl of [O], including the alternate
+/// flag and padding
+impl<T: OrdinalInteger> fmt::Display for Oz<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.to_u128() {
+            0 => f.pad("zeroth"),
+            n => display_ordinal_u128(n, f),
+        }
+    }
+}
+
+/// Panics if the result is too big for this type, also in release builds
+impl<T: OrdinalInteger> Add<T> for Oz<T> {
+    type Output = Oz<T>;
+
+    #[track_caller]
+    fn add(self, rhs: T) -> Self::Output {
+        match self.checked_add(rhs) {
+            Some(o) => o,
+            None => panic!(
+                "cannot add {} to the {}, the result is too big for this ordinal type",
+                rhs, self
+            ),
+        }
+    }
+}
+
+/// Panics if the result would be before the zeroth, also in release builds
+impl<T: OrdinalInteger> Sub<T> for Oz<T> {
+    type Output = Oz<T>;
+
+    #[track_caller]
+    fn sub(self, rhs: T) -> Self::Output {
+        match self.checked_sub(rhs) {
+            Some(o) => o,
+            None => panic!(
+                "cannot subtract {} from the {}, the result would be before the zeroth",
+                rhs, self
+            ),
+        }
+    }
+}
+
+/// Returns the distance between two ordinal numbers. Panics if `rhs` is
+/// greater than `self`, also in release builds.
+impl<T: OrdinalInteger> Sub<Oz<T>> for Oz<T> {
+    type Output = T;
+
+    #[track_caller]
+    fn sub(self, rhs: Oz<T>) -> Self::Output {
+        if rhs.0 <= self.0 {
+            self.0 - rhs.0
+        } else {
+            panic!(
+                "cannot subtract the {} from the {}, the result would be negative",
+                rhs, self
+            )
+        }
+    }
+}
+
+impl<T: OrdinalInteger> From<O<T>> for Oz<T> {
+    fn from(o: O<T>) -> Self {
+        Oz(o.into1())
+    }
+}
+
+impl<T: OrdinalInteger> TryFrom<Oz<T>> for O<T> {
+    type Error = OrdinalError;
+
+    fn try_from(z: Oz<T>) -> Result<Self, Self::Error> {
+        O::try_from1(z.0).ok_or(OrdinalError::Zero)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::O8;
+
+    #[test]
+    fn display() {
+        assert_eq!(Oz8::zeroth().to_string(), "zeroth");
+        assert_eq!(Oz8::first().to_string(), "first");
+        assert_eq!(Oz8::from0(3).to_string(), "third");
+        assert_eq!(Oz8::from0(11).to_string(), "11th");
+        assert_eq!(Oz8::from0(255).to_string(), "255th");
+
+        assert_eq!(format!("[{:>8}]", Oz8::from0(3)), "[   third]");
+        assert_eq!(format!("[{:<8}]", Oz8::zeroth()), "[zeroth  ]");
+        assert_eq!(format!("[{:^7}]", Oz8::from0(11)), "[ 11th  ]");
+        assert_eq!(format!("{:#}", Oz16::from0(21)), "twenty-first");
+        assert_eq!(format!("{:#}", Oz16::zeroth()), "zeroth");
+        assert_eq!(format!("{:?}", Oz8::zeroth()), "zeroth");
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Oz16::zeroth() + 4, Oz16::from0(4));
+        assert_eq!(Oz16::from0(4) - 4, Oz16::zeroth());
+        assert_eq!(Oz16::from0(4) - Oz16::first(), 3);
+        assert!(Oz16::zeroth() < Oz16::first());
+        assert_eq!(Oz16::default(), Oz16::zeroth());
+
+        assert_eq!(Oz8::from0(254).try_next(), Some(Oz8::from0(255)));
+        assert_eq!(Oz8::from0(255).try_next(), None);
+        assert_eq!(Oz8::from0(250).checked_add(5), Some(Oz8::from0(255)));
+        assert_eq!(Oz8::from0(250).checked_add(6), None);
+        assert_eq!(Oz8::first().checked_sub(1), Some(Oz8::zeroth()));
+        assert_eq!(Oz8::zeroth().checked_sub(1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "the 255th is the last ordinal number of this type")]
+    fn next_overflow() {
+        Oz8::from0(255).next();
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add 6 to the 250th, the result is too big")]
+    fn add_overflow() {
+        let _ = Oz8::from0(250) + 6;
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot subtract 1 from the zeroth")]
+    fn sub_underflow() {
+        let _ = Oz8::zeroth() - 1;
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot subtract the second from the first")]
+    fn sub_ordinal_underflow() {
+        let _ = Oz8::first() - Oz8::from0(2);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Oz8::from(O8::first()), Oz8::first());
+        assert_eq!(Oz8::from(O8::from0(254)), Oz8::from0(255));
+        assert_eq!(O8::try_from(Oz8::from0(255)), Ok(O8::from0(254)));
+        assert_eq!(O8::try_from(Oz8::first()), Ok(O8::first()));
+        assert_eq!(O8::try_from(Oz8::zeroth()), Err(OrdinalError::Zero));
+
+        const FOURTH: Oz32 = Oz32::from0(4);
+        const FOUR: u32 = FOURTH.into0();
+        assert_eq!(FOUR, 4);
+        assert_eq!(Oz128::from0(u128::MAX).into0(), u128::MAX);
+    }
+}
@@ -0,0 +1,52 @@
+/*
+ * Tests the C API of num-ordinal. Build and run it with
+ *
+ *     cargo rustc --release --features ffi --crate-type staticlib
+ *     cc -Iinclude tests/ffi/main.c target/release/libnum_ordinal.a -lpthread -ldl -lm -o target/ffi-test
+ *     ./target/ffi-test
+ */
+
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "num_ordinal.h"
+
+int main(void) {
+    O32 o;
+    uint32_t n;
+    assert(no_ordinal_from1_u32(4, &o) == NO_STATUS_OK);
+    assert(o == 4);
+    assert(no_ordinal_into1_u32(o, &n) == NO_STATUS_OK);
+    assert(n == 4);
+    assert(no_ordinal_into1_u32(0, &n) == NO_STATUS_ZERO);
+    assert(no_ordinal_from1_u32(0, &o) == NO_STATUS_ZERO);
+    assert(no_ordinal_from0_u32(UINT32_MAX, &o) == NO_STATUS_OVERFLOW);
+    assert(no_ordinal_from1_u32(1, NULL) == NO_STATUS_NULL_POINTER);
+
+    assert(strcmp(no_ordinal_suffix(1), "st") == 0);
+    assert(strcmp(no_ordinal_suffix(11), "th") == 0);
+    assert(strcmp(no_ordinal_suffix(22), "nd") == 0);
+
+    char buf[16];
+    size_t len;
+    assert(no_ordinal_from1_u32(1234, &o) == NO_STATUS_OK);
+    assert(no_ordinal_format(o, buf, sizeof buf, &len) == NO_STATUS_OK);
+    assert(len == 6);
+    assert(strcmp(buf, "1234th") == 0);
+
+    assert(no_ordinal_format(o, buf, 4, &len) == NO_STATUS_OK);
+    assert(len == 6);
+    assert(strcmp(buf, "123") == 0);
+
+    assert(no_ordinal_from1_u32(1, &o) == NO_STATUS_OK);
+    assert(no_ordinal_format(o, NULL, 0, &len) == NO_STATUS_OK);
+    assert(len == 5);
+    assert(no_ordinal_format(o, buf, len + 1, &len) == NO_STATUS_OK);
+    assert(strcmp(buf, "first") == 0);
+
+    assert(no_ordinal_format(0, buf, sizeof buf, &len) == NO_STATUS_ZERO);
+
+    puts("ok");
+    return 0;
+}